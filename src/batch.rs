@@ -0,0 +1,119 @@
+use raft::eraftpb::{Entry, Message};
+use std::time::{Duration, Instant};
+
+/// Limits of the group commit, the appends of several readies are synced to
+/// the wal at once. The zero default syncs every ready on its own
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GroupCommit {
+    /// How long the first unsynced ready may wait for others to join it
+    pub max_delay: Duration,
+    /// Unsynced bytes which trigger the sync before the delay elapses
+    pub max_size: u64,
+}
+
+/// The effects of the readies held until their appends are synced: neither
+/// a peer nor a client may learn about an entry this member could lose
+pub struct Batch {
+    options: GroupCommit,
+    // when the oldest ready of the batch was persisted, or the oldest one
+    // left unsynced
+    since: Option<Instant>,
+    messages: Vec<Message>,
+    committed: Vec<Entry>,
+}
+
+impl Batch {
+    pub fn new(options: GroupCommit) -> Batch {
+        Batch {
+            options: options,
+            since: None,
+            messages: vec![],
+            committed: vec![],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.since.is_none()
+    }
+
+    /// Holds the messages and committed entries of a persisted ready, which
+    /// may have left appends unsynced without holding anything
+    pub fn push(
+        &mut self,
+        messages: Vec<Message>,
+        committed: Vec<Entry>,
+        unsynced: bool,
+        now: Instant,
+    ) {
+        if messages.is_empty() && committed.is_empty() && !unsynced {
+            return;
+        }
+        self.since.get_or_insert(now);
+        self.messages.extend(messages);
+        self.committed.extend(committed);
+    }
+
+    /// Whether the batch must be synced now, given the bytes appended to the
+    /// wal since the last sync
+    pub fn is_due(&self, unsynced: u64, now: Instant) -> bool {
+        match self.since {
+            None => false,
+            Some(since) => {
+                unsynced >= self.options.max_size || now >= since + self.options.max_delay
+            }
+        }
+    }
+
+    /// Returns the held messages and committed entries, in the order the
+    /// readies were persisted
+    pub fn take(&mut self) -> (Vec<Message>, Vec<Entry>) {
+        self.since = None;
+        (
+            self.messages.drain(..).collect(),
+            self.committed.drain(..).collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn due_test() {
+        let mut batch = Batch::new(GroupCommit {
+            max_delay: Duration::from_millis(2),
+            max_size: 100,
+        });
+        let now = Instant::now();
+        batch.push(vec![], vec![], false, now);
+        assert!(batch.is_empty());
+        assert!(!batch.is_due(1000, now));
+
+        batch.push(vec![Message::new()], vec![], true, now);
+        batch.push(
+            vec![],
+            vec![Entry::new()],
+            false,
+            now + Duration::from_millis(1),
+        );
+        assert!(!batch.is_due(10, now + Duration::from_millis(1)));
+        assert!(batch.is_due(100, now + Duration::from_millis(1)));
+        assert!(batch.is_due(10, now + Duration::from_millis(2)));
+
+        let (messages, committed) = batch.take();
+        assert_eq!((messages.len(), committed.len()), (1, 1));
+        assert!(batch.is_empty());
+
+        // an append left unsynced is due as well, with nothing held
+        batch.push(vec![], vec![], true, now);
+        assert!(!batch.is_empty());
+        assert!(batch.is_due(10, now + Duration::from_millis(2)));
+        assert_eq!(batch.take(), (vec![], vec![]));
+
+        // without a delay every ready is synced on its own
+        let mut batch = Batch::new(GroupCommit::default());
+        batch.push(vec![Message::new()], vec![], true, now);
+        assert!(batch.is_due(0, now));
+    }
+}
@@ -1,16 +1,27 @@
 extern crate mum;
 #[macro_use]
 extern crate structopt;
+extern crate futures;
 extern crate grpcio;
 #[macro_use]
 extern crate log;
 extern crate env_logger;
+extern crate protobuf;
 extern crate raft;
 
-use grpcio::{ChannelBuilder, EnvBuilder};
-use mum::proto::{mumpb::*, mumpb_grpc::*};
-use raft::eraftpb::{ConfChange, ConfChangeType};
+use futures::{Future, Sink, Stream};
+use grpcio::{ChannelBuilder, EnvBuilder, WriteFlags};
+use mum::prelude::{prefix_end, Keyring};
+use mum::proto::{decode_command, mumpb::*, mumpb_grpc::*};
+use mum::wal_tool::{self, Content};
+use protobuf::parse_from_bytes;
+use raft::eraftpb::{ConfChange, ConfChangeType, Entry, EntryType};
+use std::cmp;
+use std::path::PathBuf;
+use std::process;
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
 use structopt::StructOpt;
 
@@ -28,6 +39,122 @@ enum Opt {
         value: String,
         #[structopt(short = "l", long = "limit")]
         limit: Option<u32>,
+        /// Scan [key, end), to the last key if not given
+        #[structopt(short = "e", long = "end")]
+        end: Option<String>,
+        /// Scan from after the key
+        #[structopt(long = "exclude_start")]
+        exclude_start: bool,
+        /// Scan up to the end included
+        #[structopt(long = "include_end")]
+        include_end: bool,
+        /// Scan the keys starting with key
+        #[structopt(long = "prefix")]
+        prefix: bool,
+        /// Scan from the end of the range
+        #[structopt(long = "reverse")]
+        reverse: bool,
+        /// Scan without the values
+        #[structopt(long = "keys_only")]
+        keys_only: bool,
+        /// Read get/scan at the revision, the newest one if not given, or
+        /// the revision to compact to
+        #[structopt(short = "r", long = "revision")]
+        revision: Option<u64>,
+        /// Read consistency of get/scan: linearizable/stale/lease
+        #[structopt(short = "c", long = "consistency")]
+        consistency: Option<String>,
+        /// Attach the key of set to the lease
+        #[structopt(long = "lease")]
+        lease: Option<u64>,
+        #[structopt(short = "ka", long = "kv_addr")]
+        kv_addr: String,
+    },
+
+    #[structopt(name = "lease")]
+    Lease {
+        #[structopt(short = "op", long = "op")]
+        op: String,
+        /// Lease id, picked by the leader on grant if not given
+        #[structopt(short = "i", long = "id")]
+        id: Option<u64>,
+        /// Time to live in seconds of grant
+        #[structopt(short = "t", long = "ttl")]
+        ttl: Option<u64>,
+        /// List the keys attached to the lease on ttl
+        #[structopt(long = "keys")]
+        keys: bool,
+        #[structopt(short = "ka", long = "kv_addr")]
+        kv_addr: String,
+    },
+
+    /// Set the key only if its value is still the expected one, in a txn
+    #[structopt(name = "cas")]
+    Cas {
+        #[structopt(short = "y", long = "y")]
+        key: String,
+        /// The expected value, the key must not exist if not given
+        #[structopt(long = "expect")]
+        expect: Option<String>,
+        #[structopt(short = "val", long = "value")]
+        value: String,
+        #[structopt(short = "ka", long = "kv_addr")]
+        kv_addr: String,
+    },
+
+    #[structopt(name = "watch")]
+    Watch {
+        #[structopt(short = "y", long = "y")]
+        key: String,
+        /// Watch [key, end), only the key if not given
+        #[structopt(short = "e", long = "end")]
+        end: Option<String>,
+        /// Watch the keys starting with key
+        #[structopt(long = "prefix")]
+        prefix: bool,
+        /// Replay the events since the revision
+        #[structopt(short = "r", long = "revision")]
+        revision: Option<u64>,
+        #[structopt(long = "progress_notify")]
+        progress_notify: bool,
+        #[structopt(short = "ka", long = "kv_addr")]
+        kv_addr: String,
+    },
+
+    #[structopt(name = "lock")]
+    Lock {
+        #[structopt(short = "op", long = "op")]
+        op: String,
+        /// Name of the lock to lock
+        #[structopt(short = "n", long = "name")]
+        name: Option<String>,
+        /// Lease the lock is held under
+        #[structopt(short = "i", long = "lease")]
+        lease: Option<u64>,
+        /// Key returned by lock to unlock
+        #[structopt(short = "y", long = "y")]
+        key: Option<String>,
+        /// Revision returned by lock, required by unlock
+        #[structopt(short = "r", long = "revision")]
+        revision: Option<u64>,
+        #[structopt(short = "ka", long = "kv_addr")]
+        kv_addr: String,
+    },
+
+    #[structopt(name = "election")]
+    Election {
+        #[structopt(short = "op", long = "op")]
+        op: String,
+        #[structopt(short = "n", long = "name")]
+        name: String,
+        /// Lease of the candidate
+        #[structopt(short = "i", long = "lease")]
+        lease: Option<u64>,
+        /// Revision returned by campaign, required by proclaim and resign
+        #[structopt(short = "r", long = "revision")]
+        revision: Option<u64>,
+        #[structopt(short = "val", long = "value")]
+        value: Option<String>,
         #[structopt(short = "ka", long = "kv_addr")]
         kv_addr: String,
     },
@@ -43,6 +170,24 @@ enum Opt {
         #[structopt(short = "ka", long = "kv_addr")]
         kv_addr: String,
     },
+
+    /// Inspect or repair the wal of a stopped member
+    #[structopt(name = "wal")]
+    Wal {
+        #[structopt(short = "op", long = "op")]
+        op: String,
+        #[structopt(short = "wd", long = "wal_dir", parse(from_os_str))]
+        wal_dir: PathBuf,
+        /// Dump from the segment holding the index, or truncate after it
+        #[structopt(short = "i", long = "index")]
+        index: Option<u64>,
+        /// Show what truncate would drop without changing the wal
+        #[structopt(long = "dry_run")]
+        dry_run: bool,
+        /// Key file of an encrypted wal
+        #[structopt(long = "key_file", parse(from_os_str))]
+        key_file: Option<PathBuf>,
+    },
 }
 
 fn main() {
@@ -55,6 +200,15 @@ fn main() {
             key,
             value,
             limit,
+            end,
+            exclude_start,
+            include_end,
+            prefix,
+            reverse,
+            keys_only,
+            revision,
+            consistency,
+            lease,
             kv_addr,
         } => {
             let ch = ChannelBuilder::new(env).connect(&kv_addr);
@@ -64,19 +218,311 @@ fn main() {
                 "get" => Op::Get,
                 "delete" => Op::Del,
                 "scan" => Op::Scan,
-                _ => panic!("unexpect op {}, wanted: set/get/delete/scan", op),
+                "compact" => Op::Compact,
+                _ => panic!("unexpect op {}, wanted: set/get/delete/scan/compact", op),
+            };
+
+            let c = match consistency.as_ref().map(|c| c.as_str()) {
+                None | Some("linearizable") => ReadConsistency::Linearizable,
+                Some("stale") => ReadConsistency::Stale,
+                Some("lease") => ReadConsistency::Lease,
+                Some(c) => panic!(
+                    "unexpect consistency {}, wanted: linearizable/stale/lease",
+                    c
+                ),
             };
 
-            let req = make_kv_request(o, key, value, limit);
+            let mut req = make_kv_request(o, key, value, limit, c);
+            req.set_end_key(end.map(|end| end.into_bytes()).unwrap_or_default());
+            req.set_exclude_start(exclude_start);
+            req.set_include_end(include_end);
+            req.set_prefix(prefix);
+            req.set_reverse(reverse);
+            req.set_keys_only(keys_only);
+            req.set_revision(revision.unwrap_or_default());
+            req.set_lease(lease.unwrap_or_default());
             let reply = client.op(&req).expect("rpc");
+            info!("revision: {}", reply.get_header().get_revision());
+            if reply.has_prev_kv() {
+                info!(
+                    "previous value: {}",
+                    String::from_utf8(reply.get_prev_kv().get_value().to_vec()).unwrap()
+                );
+            }
             for kv in reply.get_kvs() {
                 info!(
-                    "key: {} / value: {}",
+                    "key: {} / value: {} / create revision: {} / mod revision: {} / version: {} / lease: {}",
                     String::from_utf8(kv.get_key().to_vec()).unwrap(),
-                    String::from_utf8(kv.get_value().to_vec()).unwrap()
+                    String::from_utf8(kv.get_value().to_vec()).unwrap(),
+                    kv.get_create_revision(),
+                    kv.get_mod_revision(),
+                    kv.get_version(),
+                    kv.get_lease()
                 );
             }
         }
+        Opt::Cas {
+            key,
+            expect,
+            value,
+            kv_addr,
+        } => {
+            let ch = ChannelBuilder::new(env).connect(&kv_addr);
+            let client = MumClient::new(ch);
+            let mut cmp = Compare::new();
+            cmp.set_key(key.clone().into_bytes());
+            cmp.set_result(CompareResult::Equal);
+            match expect {
+                Some(v) => {
+                    cmp.set_target(CompareTarget::Value);
+                    cmp.set_value(v.into_bytes());
+                }
+                None => {
+                    cmp.set_target(CompareTarget::Exists);
+                    cmp.set_exists(false);
+                }
+            }
+            let mut txn = TxnRequest::new();
+            txn.mut_compares().push(cmp);
+            let set = make_kv_request(
+                Op::Set,
+                key.clone(),
+                value,
+                None,
+                ReadConsistency::Linearizable,
+            );
+            txn.mut_success().push(set);
+            let get = make_kv_request(
+                Op::Get,
+                key,
+                String::new(),
+                None,
+                ReadConsistency::Linearizable,
+            );
+            txn.mut_failure().push(get);
+            let reply = client.txn(&txn).expect("rpc");
+            info!(
+                "succeeded: {} / revision: {}",
+                reply.get_succeeded(),
+                reply.get_header().get_revision()
+            );
+            if !reply.get_succeeded() {
+                for kv in reply.get_responses()[0].get_kvs() {
+                    info!(
+                        "current value: {}",
+                        String::from_utf8(kv.get_value().to_vec()).unwrap()
+                    );
+                }
+            }
+        }
+        Opt::Watch {
+            key,
+            end,
+            prefix,
+            revision,
+            progress_notify,
+            kv_addr,
+        } => {
+            let ch = ChannelBuilder::new(env).connect(&kv_addr);
+            let client = MumClient::new(ch);
+            let end = match end {
+                Some(end) => end.into_bytes(),
+                None if prefix => match prefix_end(key.as_bytes()) {
+                    // no key is greater than the prefix
+                    ref e if e.is_empty() => vec![0],
+                    e => e,
+                },
+                None => vec![],
+            };
+            let mut req = WatchRequest::new();
+            req.set_key(key.into_bytes());
+            req.set_end_key(end);
+            req.set_start_revision(revision.unwrap_or_default());
+            req.set_progress_notify(progress_notify);
+            let stream = client.watch(&req).expect("rpc");
+            for resp in stream.wait() {
+                let resp = resp.expect("watch");
+                if resp.get_compact_revision() > 0 {
+                    error!("compacted to revision {}", resp.get_compact_revision());
+                    break;
+                }
+                info!("revision: {}", resp.get_header().get_revision());
+                for e in resp.get_events() {
+                    info!(
+                        "{:?} key: {} / value: {} / mod revision: {}",
+                        e.get_field_type(),
+                        String::from_utf8(e.get_kv().get_key().to_vec()).unwrap(),
+                        String::from_utf8(e.get_kv().get_value().to_vec()).unwrap(),
+                        e.get_kv().get_mod_revision()
+                    );
+                }
+            }
+        }
+        Opt::Lease {
+            op,
+            id,
+            ttl,
+            keys,
+            kv_addr,
+        } => {
+            let ch = ChannelBuilder::new(env).connect(&kv_addr);
+            let client = MumClient::new(ch);
+            let id = id.unwrap_or_default();
+            match op.as_str() {
+                "grant" => {
+                    let mut req = LeaseGrantRequest::new();
+                    req.set_id(id);
+                    req.set_ttl(ttl.expect("ttl of the lease"));
+                    let reply = client.lease_grant(&req).expect("rpc");
+                    info!("lease: {} / ttl: {}", reply.get_id(), reply.get_ttl());
+                }
+                "revoke" => {
+                    let mut req = LeaseRevokeRequest::new();
+                    req.set_id(id);
+                    let reply = client.lease_revoke(&req).expect("rpc");
+                    info!("revision: {}", reply.get_header().get_revision());
+                }
+                "ttl" => {
+                    let mut req = LeaseTimeToLiveRequest::new();
+                    req.set_id(id);
+                    req.set_keys(keys);
+                    let reply = client.lease_time_to_live(&req).expect("rpc");
+                    info!(
+                        "lease: {} / ttl: {} / granted ttl: {}",
+                        reply.get_id(),
+                        reply.get_ttl(),
+                        reply.get_granted_ttl()
+                    );
+                    for key in reply.get_keys() {
+                        info!("key: {}", String::from_utf8(key.to_vec()).unwrap());
+                    }
+                }
+                "keepalive" => {
+                    let mut req = LeaseTimeToLiveRequest::new();
+                    req.set_id(id);
+                    let ttl = client
+                        .lease_time_to_live(&req)
+                        .expect("rpc")
+                        .get_granted_ttl();
+                    // renews the lease every third of its time to live
+                    let interval = Duration::from_secs(cmp::max(ttl / 3, 1));
+                    let (mut sender, receiver) = client.lease_keep_alive().expect("rpc");
+                    thread::spawn(move || loop {
+                        let mut req = LeaseKeepAliveRequest::new();
+                        req.set_id(id);
+                        sender = match sender.send((req, WriteFlags::default())).wait() {
+                            Ok(sender) => sender,
+                            Err(_) => return,
+                        };
+                        thread::sleep(interval);
+                    });
+                    for resp in receiver.wait() {
+                        let resp = resp.expect("keepalive");
+                        if resp.get_ttl() == 0 {
+                            error!("lease {} not found", id);
+                            break;
+                        }
+                        info!("lease: {} / ttl: {}", resp.get_id(), resp.get_ttl());
+                    }
+                }
+                _ => panic!("unexpect op {}, wanted: grant/revoke/ttl/keepalive", op),
+            }
+        }
+        Opt::Lock {
+            op,
+            name,
+            lease,
+            key,
+            revision,
+            kv_addr,
+        } => {
+            let ch = ChannelBuilder::new(env).connect(&kv_addr);
+            let client = LockClient::new(ch);
+            match op.as_str() {
+                "lock" => {
+                    let mut req = LockRequest::new();
+                    req.set_name(name.expect("name of the lock").into_bytes());
+                    req.set_lease(lease.expect("lease of the lock"));
+                    let reply = client.lock(&req).expect("rpc");
+                    info!(
+                        "key: {} / revision: {}",
+                        String::from_utf8(reply.get_key().to_vec()).unwrap(),
+                        reply.get_header().get_revision()
+                    );
+                }
+                "unlock" => {
+                    let mut req = UnlockRequest::new();
+                    req.set_key(key.expect("key of the lock").into_bytes());
+                    req.set_revision(revision.expect("revision of the lock"));
+                    let reply = client.unlock(&req).expect("rpc");
+                    info!("revision: {}", reply.get_header().get_revision());
+                }
+                _ => panic!("unexpect op {}, wanted: lock/unlock", op),
+            }
+        }
+        Opt::Election {
+            op,
+            name,
+            lease,
+            revision,
+            value,
+            kv_addr,
+        } => {
+            let ch = ChannelBuilder::new(env).connect(&kv_addr);
+            let client = ElectionClient::new(ch);
+            let lease = lease.unwrap_or_default();
+            let mut leader = LeaderKey::new();
+            leader.set_name(name.clone().into_bytes());
+            leader.set_key(format!("{}/{:x}", name, lease).into_bytes());
+            leader.set_revision(revision.unwrap_or_default());
+            leader.set_lease(lease);
+            let value = value.unwrap_or_default().into_bytes();
+            let print_leader = |resp: &LeaderResponse| {
+                info!(
+                    "leader: {} / value: {} / revision: {}",
+                    String::from_utf8(resp.get_kv().get_key().to_vec()).unwrap(),
+                    String::from_utf8(resp.get_kv().get_value().to_vec()).unwrap(),
+                    resp.get_kv().get_create_revision()
+                )
+            };
+            match op.as_str() {
+                "campaign" => {
+                    let mut req = CampaignRequest::new();
+                    req.set_name(name.into_bytes());
+                    req.set_lease(lease);
+                    req.set_value(value);
+                    let reply = client.campaign(&req).expect("rpc");
+                    info!("elected at revision {}", reply.get_leader().get_revision());
+                }
+                "proclaim" => {
+                    let mut req = ProclaimRequest::new();
+                    req.set_leader(leader);
+                    req.set_value(value);
+                    client.proclaim(&req).expect("rpc");
+                }
+                "resign" => {
+                    let mut req = ResignRequest::new();
+                    req.set_leader(leader);
+                    client.resign(&req).expect("rpc");
+                }
+                "leader" => {
+                    let mut req = LeaderRequest::new();
+                    req.set_name(name.into_bytes());
+                    print_leader(&client.leader(&req).expect("rpc"));
+                }
+                "observe" => {
+                    let mut req = LeaderRequest::new();
+                    req.set_name(name.into_bytes());
+                    for resp in client.observe(&req).expect("rpc").wait() {
+                        print_leader(&resp.expect("observe"));
+                    }
+                }
+                _ => panic!(
+                    "unexpect op {}, wanted: campaign/proclaim/resign/leader/observe",
+                    op
+                ),
+            }
+        }
         Opt::Conf {
             op,
             node_id,
@@ -94,6 +540,130 @@ fn main() {
             let req = make_conf_request(o, node_id, url);
             client.conf(&req).expect("rpc");
         }
+        Opt::Wal {
+            op,
+            wal_dir,
+            index,
+            dry_run,
+            key_file,
+        } => match op.as_str() {
+            "list" => {
+                for segment in wal_tool::segments(&wal_dir).expect("list") {
+                    match segment.header {
+                        Ok(None) => info!(
+                            "{:?} / seq: {} / first index: {} / size: {} / version: 0, no header",
+                            segment.path, segment.seq, segment.first_index, segment.size
+                        ),
+                        Ok(Some(h)) => info!(
+                            "{:?} / seq: {} / first index: {} / size: {} / version: {} / node: {} / cluster: {} / key: {}",
+                            segment.path,
+                            segment.seq,
+                            segment.first_index,
+                            segment.size,
+                            h.version,
+                            h.node_id,
+                            h.cluster_id,
+                            h.key_id
+                        ),
+                        Err(e) => error!(
+                            "{:?} / seq: {} / first index: {} / size: {} / {}",
+                            segment.path, segment.seq, segment.first_index, segment.size, e
+                        ),
+                    }
+                }
+            }
+            "dump" => {
+                for r in wal_tool::records(&wal_dir, index, keyring(&key_file)).expect("dump") {
+                    match r {
+                        Ok(dump) => print_record(&dump),
+                        Err(e) => error!("{}", e),
+                    }
+                }
+            }
+            "verify" => {
+                let mut n = 0;
+                for r in wal_tool::records(&wal_dir, None, keyring(&key_file)).expect("verify") {
+                    if let Err(e) = r {
+                        error!("{} records verified, then: {}", n, e);
+                        process::exit(1);
+                    }
+                    n += 1;
+                }
+                info!("{} records verified", n);
+            }
+            "truncate" => {
+                let index = index.expect("truncate needs --index");
+                match wal_tool::truncate_after(&wal_dir, index, dry_run, keyring(&key_file)).expect("truncate") {
+                    None => info!("no entry after index {}", index),
+                    Some(t) => info!(
+                        "{}{} entries dropped from {:?} at offset {} / segments removed: {:?} / hard state: {:?}",
+                        if dry_run { "dry run: " } else { "" },
+                        t.entries,
+                        t.path,
+                        t.offset,
+                        t.removed,
+                        t.state
+                    ),
+                }
+            }
+            _ => panic!("unexpect op {}, wanted: list/dump/verify/truncate", op),
+        },
+    }
+}
+
+fn keyring(key_file: &Option<PathBuf>) -> Option<Arc<Keyring>> {
+    key_file
+        .as_ref()
+        .map(|path| Arc::new(Keyring::load(path).expect("key file")))
+}
+
+fn print_record(dump: &wal_tool::RecordDump) {
+    let p = &dump.position;
+    match dump.content {
+        Content::Entry(ref entry) => info!(
+            "seq: {} / offset: {} / crc: {:08x} / entry / term: {} / index: {} / {}",
+            p.seq,
+            p.offset,
+            dump.crc,
+            entry.get_term(),
+            entry.get_index(),
+            describe_entry(entry)
+        ),
+        Content::State(ref hs) => info!(
+            "seq: {} / offset: {} / crc: {:08x} / state / term: {} / vote: {} / commit: {}",
+            p.seq,
+            p.offset,
+            dump.crc,
+            hs.get_term(),
+            hs.get_vote(),
+            hs.get_commit()
+        ),
+        Content::Crc => info!(
+            "seq: {} / offset: {} / crc: {:08x} / crc",
+            p.seq, p.offset, dump.crc
+        ),
+        Content::Snapshot(term, index) => info!(
+            "seq: {} / offset: {} / crc: {:08x} / snapshot / term: {} / index: {}",
+            p.seq, p.offset, dump.crc, term, index
+        ),
+    }
+}
+
+/// The command or conf change the entry carries
+fn describe_entry(entry: &Entry) -> String {
+    // the new leader appends an empty entry on election
+    if entry.get_data().is_empty() {
+        return "empty".to_owned();
+    }
+    match entry.get_entry_type() {
+        EntryType::EntryNormal => match decode_command(entry.get_data()) {
+            Ok(cmd) => format!("{:?}", cmd),
+            Err(e) => format!("undecodable command: {}", e),
+        },
+        EntryType::EntryConfChange => match parse_from_bytes::<ConfChange>(entry.get_data()) {
+            Ok(change) => format!("{:?}", change),
+            Err(e) => format!("undecodable conf change: {}", e),
+        },
     }
 }
 
@@ -107,7 +677,13 @@ fn make_conf_request(t: ConfChangeType, node_id: u64, url: String) -> ConfReques
     req
 }
 
-fn make_kv_request(op: Op, key: String, value: String, limit: Option<u32>) -> OpRequest {
+fn make_kv_request(
+    op: Op,
+    key: String,
+    value: String,
+    limit: Option<u32>,
+    consistency: ReadConsistency,
+) -> OpRequest {
     let mut req = OpRequest::new();
     req.set_field_type(op);
     req.set_key(key.into_bytes());
@@ -115,5 +691,6 @@ fn make_kv_request(op: Op, key: String, value: String, limit: Option<u32>) -> Op
     if let Some(v) = limit {
         req.set_limit(v);
     }
+    req.set_consistency(consistency);
     req
 }
@@ -11,6 +11,7 @@ use grpcio::Environment;
 use mum::prelude::*;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use std::path::PathBuf;
 use structopt::StructOpt;
@@ -31,6 +32,67 @@ struct Opt {
 
     #[structopt(long = "id")]
     id: u64,
+
+    /// Shared by the members of the cluster, the wal of another cluster is
+    /// refused
+    #[structopt(long = "cluster_id", default_value = "0")]
+    cluster_id: u64,
+
+    /// Serve lease reads on the leader without a heartbeat round-trip
+    #[structopt(long = "lease_read")]
+    lease_read: bool,
+
+    /// Compact the history periodically: revision/periodic
+    #[structopt(long = "auto_compaction_mode")]
+    auto_compaction_mode: Option<String>,
+
+    /// Revisions to keep in revision mode, or seconds of history to keep in
+    /// periodic mode
+    #[structopt(long = "auto_compaction_retention", default_value = "1000")]
+    auto_compaction_retention: u64,
+
+    /// Delete the wal segments covered by the snapshots, keeping n extra ones
+    #[structopt(long = "max_wals")]
+    max_wals: Option<usize>,
+
+    /// Snapshots the purged wal must still be able to replay from, the older
+    /// ones are deleted
+    #[structopt(long = "max_snapshots", default_value = "5")]
+    max_snapshots: usize,
+
+    /// When the wal appends are synced: always/batch/interval
+    #[structopt(long = "wal_sync", default_value = "always")]
+    wal_sync: String,
+
+    /// Milliseconds between the wal syncs in interval mode
+    #[structopt(long = "wal_sync_interval", default_value = "100")]
+    wal_sync_interval: u64,
+
+    /// Bytes the wal segments are preallocated to and cut at
+    #[structopt(long = "wal_segment_size", default_value = "32000000")]
+    wal_segment_size: u64,
+
+    /// Bytes buffered before they are written to the wal segment
+    #[structopt(long = "wal_buffer_size", default_value = "65536")]
+    wal_buffer_size: usize,
+
+    /// Compress the wal records of this many bytes or more
+    #[structopt(long = "wal_compress_above")]
+    wal_compress_above: Option<usize>,
+
+    /// Encrypt the wal records and the snapshots with the last key of the
+    /// file, one "id hex-key" line per key
+    #[structopt(long = "key_file", parse(from_os_str))]
+    key_file: Option<PathBuf>,
+
+    /// Milliseconds a write may wait for others to share its wal fsync in
+    /// batch mode
+    #[structopt(long = "max_batch_delay", default_value = "1")]
+    max_batch_delay: u64,
+
+    /// Unsynced bytes which trigger the wal fsync before the batch delay
+    #[structopt(long = "max_batch_size", default_value = "1048576")]
+    max_batch_size: u64,
 }
 
 fn main() {
@@ -41,6 +103,48 @@ fn main() {
     let kv_store = Store::new();
 
     let (mum_grpc, rx) = MumServer::new(kv_store.clone());
+    let retention = opt.auto_compaction_retention;
+    let auto_compaction = match opt.auto_compaction_mode.as_ref().map(|m| m.as_str()) {
+        None => None,
+        Some("revision") => Some(AutoCompaction::Revision(retention)),
+        Some("periodic") => Some(AutoCompaction::Periodic(Duration::from_secs(retention))),
+        Some(m) => panic!(
+            "unexpect auto compaction mode {}, wanted: revision/periodic",
+            m
+        ),
+    };
+    let sync = match opt.wal_sync.as_str() {
+        "always" => SyncMode::Always,
+        "batch" => SyncMode::Batch(GroupCommit {
+            max_delay: Duration::from_millis(opt.max_batch_delay),
+            max_size: opt.max_batch_size,
+        }),
+        "interval" => SyncMode::Interval(Duration::from_millis(opt.wal_sync_interval)),
+        m => panic!(
+            "unexpect wal sync mode {}, wanted: always/batch/interval",
+            m
+        ),
+    };
+    let keyring = opt
+        .key_file
+        .as_ref()
+        .map(|path| Arc::new(Keyring::load(path).unwrap()));
+    let options = NodeOptions {
+        lease_read: opt.lease_read,
+        auto_compaction: auto_compaction,
+        purge: opt.max_wals.map(|max_wals| PurgeOptions {
+            max_wals: max_wals,
+            max_snapshots: opt.max_snapshots,
+        }),
+        wal: WalOptions {
+            segment_size: opt.wal_segment_size,
+            sync: sync,
+            buffer_size: opt.wal_buffer_size,
+            compress_above: opt.wal_compress_above,
+            keyring: keyring,
+        },
+        cluster_id: opt.cluster_id,
+    };
 
     let node = Node::new(
         opt.id,
@@ -50,7 +154,9 @@ fn main() {
         opt.wal_dir,
         opt.snap_dir,
         rx,
-    ).unwrap();
+        options,
+    )
+    .unwrap();
 
     let mum_addr: Vec<&str> = opt
         .addrs
@@ -0,0 +1,95 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+// how often the leader checks whether to compact
+const CHECK_INTERVAL_SECS: u64 = 10;
+
+/// Mode of the periodic compaction of the MVCC history
+#[derive(Clone, Debug, PartialEq)]
+pub enum AutoCompaction {
+    /// Keeps the newest n revisions
+    Revision(u64),
+    /// Keeps the revisions written within the duration
+    Periodic(Duration),
+}
+
+/// Decides the revision to compact to, driven by the raft loop
+pub struct Compactor {
+    mode: AutoCompaction,
+    last_check: Option<Instant>,
+    // store revisions observed at each check, oldest first
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl Compactor {
+    pub fn new(mode: AutoCompaction) -> Compactor {
+        Compactor {
+            mode: mode,
+            last_check: None,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Returns the revision to compact to, if the history grew beyond the
+    /// retention since `compacted`
+    pub fn poll(&mut self, now: Instant, revision: u64, compacted: u64) -> Option<u64> {
+        let interval = Duration::from_secs(CHECK_INTERVAL_SECS);
+        match self.last_check {
+            Some(t) if now < t + interval => return None,
+            _ => self.last_check = Some(now),
+        }
+        let target = match self.mode {
+            AutoCompaction::Revision(retention) => revision.saturating_sub(retention),
+            AutoCompaction::Periodic(retention) => {
+                self.samples.push_back((now, revision));
+                // the newest revision which has been kept for the retention
+                let mut target = 0;
+                while let Some(&(t, rev)) = self.samples.front() {
+                    if t + retention > now {
+                        break;
+                    }
+                    target = rev;
+                    self.samples.pop_front();
+                }
+                target
+            }
+        };
+        if target > compacted {
+            Some(target)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revision_test() {
+        let mut c = Compactor::new(AutoCompaction::Revision(10));
+        let now = Instant::now();
+        assert_eq!(c.poll(now, 5, 0), None);
+        // not time to check yet
+        assert_eq!(c.poll(now + Duration::from_secs(1), 100, 0), None);
+        let now = now + Duration::from_secs(CHECK_INTERVAL_SECS);
+        assert_eq!(c.poll(now, 100, 0), Some(90));
+        let now = now + Duration::from_secs(CHECK_INTERVAL_SECS);
+        assert_eq!(c.poll(now, 100, 90), None);
+    }
+
+    #[test]
+    fn periodic_test() {
+        let interval = Duration::from_secs(CHECK_INTERVAL_SECS);
+        let mut c = Compactor::new(AutoCompaction::Periodic(interval * 3));
+        let start = Instant::now();
+        assert_eq!(c.poll(start, 10, 0), None);
+        assert_eq!(c.poll(start + interval, 20, 0), None);
+        assert_eq!(c.poll(start + interval * 2, 30, 0), None);
+        // revision 10 was observed a retention ago
+        assert_eq!(c.poll(start + interval * 3, 40, 0), Some(10));
+        assert_eq!(c.poll(start + interval * 4, 50, 10), Some(20));
+        assert_eq!(c.poll(start + interval * 5, 50, 20), Some(30));
+    }
+}
@@ -0,0 +1,183 @@
+use errors::*;
+use ring::aead::{self, OpeningKey, SealingKey, AES_256_GCM};
+use ring::rand::{SecureRandom, SystemRandom};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+const KEY_LEN: usize = 32;
+// random, a key should be rotated long before 2^32 seals
+const NONCE_LEN: usize = 12;
+
+struct Key {
+    sealing: SealingKey,
+    opening: OpeningKey,
+}
+
+/// The AES-256-GCM keys of a key file. Every line holds a key id and the key
+/// in hex, `#` starts a comment. The last key encrypts the new files, the
+/// others still decrypt the files written before it was added. Id 0 stands
+/// for plaintext in the file headers
+pub struct Keyring {
+    keys: HashMap<u32, Key>,
+    active: u32,
+    rng: SystemRandom,
+}
+
+impl fmt::Debug for Keyring {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut ids: Vec<&u32> = self.keys.keys().collect();
+        ids.sort();
+        write!(f, "Keyring {{ ids: {:?}, active: {} }}", ids, self.active)
+    }
+}
+
+impl Keyring {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Keyring> {
+        let invalid =
+            |reason: String| Error::KeyFile(path.as_ref().to_string_lossy().into_owned(), reason);
+        let text = fs::read_to_string(&path)?;
+        let mut keys = HashMap::new();
+        let mut active = 0;
+        for (n, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let (id, hex) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(id), Some(hex), None) => (id, hex),
+                _ => return Err(invalid(format!("line {}: want an id and a key", n + 1))),
+            };
+            let id = match id.parse::<u32>() {
+                Ok(id) if id != 0 => id,
+                _ => return Err(invalid(format!("line {}: id must be positive", n + 1))),
+            };
+            let bytes = from_hex(hex)
+                .filter(|bytes| bytes.len() == KEY_LEN)
+                .ok_or_else(|| {
+                    invalid(format!("line {}: want a {}-byte hex key", n + 1, KEY_LEN))
+                })?;
+            let key = Key {
+                sealing: SealingKey::new(&AES_256_GCM, &bytes).unwrap(),
+                opening: OpeningKey::new(&AES_256_GCM, &bytes).unwrap(),
+            };
+            if keys.insert(id, key).is_some() {
+                return Err(invalid(format!("line {}: duplicate id {}", n + 1, id)));
+            }
+            active = id;
+        }
+        if active == 0 {
+            return Err(invalid("no key".to_owned()));
+        }
+        Ok(Keyring {
+            keys: keys,
+            active: active,
+            rng: SystemRandom::new(),
+        })
+    }
+
+    /// Id of the key the new files are encrypted with
+    pub fn active_id(&self) -> u32 {
+        self.active
+    }
+
+    pub fn has_key(&self, id: u32) -> bool {
+        self.keys.contains_key(&id)
+    }
+
+    /// Encrypts `data` and authenticates it along with `ad`, returns the
+    /// nonce followed by the ciphertext and the tag
+    pub fn seal(&self, key_id: u32, ad: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+        let key = self.key(key_id)?;
+        let tag_len = AES_256_GCM.tag_len();
+        let mut sealed = vec![0; NONCE_LEN + data.len() + tag_len];
+        self.rng
+            .fill(&mut sealed[..NONCE_LEN])
+            .map_err(|_| Error::Crypto("no randomness for the nonce".to_owned()))?;
+        sealed[NONCE_LEN..NONCE_LEN + data.len()].copy_from_slice(data);
+        let (nonce, in_out) = sealed.split_at_mut(NONCE_LEN);
+        aead::seal_in_place(&key.sealing, nonce, ad, in_out, tag_len)
+            .map_err(|_| Error::Crypto("sealing failed".to_owned()))?;
+        Ok(sealed)
+    }
+
+    /// Decrypts what `seal` returned, fails if it or `ad` was tampered with
+    pub fn open(&self, key_id: u32, ad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+        let key = self.key(key_id)?;
+        if sealed.len() < NONCE_LEN + AES_256_GCM.tag_len() {
+            return Err(Error::Crypto(format!("{} bytes, too short", sealed.len())));
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let mut data = ciphertext.to_vec();
+        let len = aead::open_in_place(&key.opening, nonce, ad, 0, &mut data)
+            .map_err(|_| Error::Crypto("authentication failed".to_owned()))?
+            .len();
+        data.truncate(len);
+        Ok(data)
+    }
+
+    fn key(&self, id: u32) -> Result<&Key> {
+        self.keys.get(&id).ok_or(Error::UnknownKey(id))
+    }
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use util::create_dir;
+
+    #[test]
+    fn keyring_test() {
+        let dir = "./file_tests/keyring";
+        create_dir(dir).unwrap();
+        let path = Path::new(dir).join("keys");
+        let key = |b: &str| b.repeat(KEY_LEN);
+        fs::write(
+            &path,
+            format!("# rotated\n1 {}\n\n2 {}\n", key("01"), key("a2")),
+        )
+        .unwrap();
+        let keyring = Keyring::load(&path).unwrap();
+        assert_eq!(keyring.active_id(), 2);
+
+        let sealed = keyring.seal(1, b"ad", b"secret").unwrap();
+        assert_eq!(keyring.open(1, b"ad", &sealed).unwrap(), b"secret".to_vec());
+        assert!(keyring.open(1, b"da", &sealed).is_err());
+        assert!(keyring.open(2, b"ad", &sealed).is_err());
+        let mut tampered = sealed.clone();
+        tampered[NONCE_LEN] ^= 1;
+        assert!(keyring.open(1, b"ad", &tampered).is_err());
+        match keyring.seal(3, b"", b"") {
+            Err(Error::UnknownKey(3)) => (),
+            _ => panic!("expected unknown key"),
+        }
+
+        let bad = vec![
+            "0 00".to_owned(),
+            "1 0g".to_owned(),
+            "1".to_owned(),
+            String::new(),
+            format!("1 {}\n1 {}", key("01"), key("01")),
+        ];
+        for bad in &bad {
+            fs::write(&path, bad).unwrap();
+            match Keyring::load(&path) {
+                Err(Error::KeyFile(..)) => (),
+                _ => panic!("expected invalid key file {:?}", bad),
+            }
+        }
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -19,6 +19,22 @@ pub enum Error {
     Io(#[cause] ::std::io::Error),
     #[fail(display = "crc miss match")]
     CrcMissMatch,
+    #[fail(display = "wal segment {} is corrupted at offset {}: {}", _0, _1, _2)]
+    WalCorrupted(String, u64, String),
+    #[fail(display = "torn write in wal segment {} at offset {}: {}", _0, _1, _2)]
+    WalTornWrite(String, u64, String),
+    #[fail(display = "invalid header of wal segment {}: {}", _0, _1)]
+    WalHeader(String, String),
+    #[fail(display = "wal segment {} belongs to another member: {}", _0, _1)]
+    WalForeignSegment(String, String),
+    #[fail(display = "invalid snapshot header: {}", _0)]
+    SnapHeader(String),
+    #[fail(display = "invalid key file {}: {}", _0, _1)]
+    KeyFile(String, String),
+    #[fail(display = "key {} is not in the key file", _0)]
+    UnknownKey(u32),
+    #[fail(display = "crypto error: {}", _0)]
+    Crypto(String),
     #[fail(display = "snapshot miss match")]
     SnapMissMatch,
     #[fail(display = "file path {} already exists", _0)]
@@ -39,6 +55,38 @@ pub enum Error {
     UnboundReceiverError(String),
     #[fail(display = "{}", _0)]
     TokioTimer(#[cause] TokioTimerError),
+    #[fail(display = "proposal dropped")]
+    ProposalDropped,
+    #[fail(display = "proposal timeout")]
+    ProposalTimeout,
+    #[fail(display = "not leader, leader id: {}, leader addr: {}", leader_id, addr)]
+    NotLeader { leader_id: u64, addr: String },
+    #[fail(display = "revision {} is newer than the store revision {}", _0, _1)]
+    FutureRevision(u64, u64),
+    #[fail(display = "revision {} has been compacted to {}", _0, _1)]
+    Compacted(u64, u64),
+    #[fail(display = "invalid txn: {}", _0)]
+    InvalidTxn(String),
+    #[fail(display = "raft command format version {}, only up to {} is supported", _0, _1)]
+    CommandVersion(u8, u8),
+    #[fail(display = "store snapshot format version {}, only up to {} is supported", _0, _1)]
+    StoreVersion(u8, u8),
+    #[fail(display = "lease {} not found", _0)]
+    LeaseNotFound(u64),
+    #[fail(display = "lease {} already exists", _0)]
+    LeaseExists(u64),
+    #[fail(display = "a lease is required")]
+    LeaseRequired,
+    #[fail(display = "the time to live of a lease must be positive")]
+    ZeroLeaseTtl,
+    #[fail(display = "election {} has no leader", _0)]
+    NoElectionLeader(String),
+    #[fail(display = "not the leader of election {}", _0)]
+    NotElectionLeader(String),
+    #[fail(display = "lock key {} is not held at revision {}", _0, _1)]
+    LockNotHeld(String, u64),
+    #[fail(display = "key {} was deleted while waiting", _0)]
+    KeyDeleted(String),
 }
 
 impl From<TokioTimerError> for Error {
@@ -55,7 +103,10 @@ impl From<Utf8Error> for Error {
 
 impl From<RaftError> for Error {
     fn from(e: RaftError) -> Error {
-        Error::Raft(e)
+        match e {
+            RaftError::ProposalDropped => Error::ProposalDropped,
+            e => Error::Raft(e),
+        }
     }
 }
 
@@ -1,96 +1,961 @@
 use bincode::{deserialize, serialize};
 use errors::*;
-use indexmap::IndexMap;
+use proto::mumpb::{
+    Compare, CompareResult, CompareTarget, Event, EventType, KvPair, Op, OpRequest, OpResponse,
+    TxnRequest, TxnResponse,
+};
 use raft::eraftpb::Snapshot;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound;
 use std::sync::Arc;
 use std::sync::RwLock;
 use util::HandyRwLock;
 
-/// Simple implement of KV Storage by IndexMap
+// the snapshots of the store start with these, the older ones hold a bare
+// map of the values, which would have more pairs than fit in memory if its
+// length started with them
+const SNAPSHOT_MAGIC: &'static [u8] = b"MVCC";
+const SNAPSHOT_VERSION: u8 = 1;
+
+/// One write to a key
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Version {
+    create_revision: u64,
+    mod_revision: u64,
+    version: u64,
+    lease: u64,
+    // None marks a deletion
+    value: Option<Vec<u8>>,
+}
+
+impl Version {
+    fn to_kv(&self, key: &[u8], keys_only: bool) -> Option<KvPair> {
+        self.value.as_ref().map(|v| {
+            let mut kv = KvPair::new();
+            kv.set_key(key.to_vec());
+            if !keys_only {
+                kv.set_value(v.clone());
+            }
+            kv.set_create_revision(self.create_revision);
+            kv.set_mod_revision(self.mod_revision);
+            kv.set_version(self.version);
+            kv.set_lease(self.lease);
+            kv
+        })
+    }
+
+    fn to_event(&self, key: &[u8]) -> Event {
+        let mut event = Event::new();
+        match self.to_kv(key, false) {
+            Some(kv) => event.set_kv(kv),
+            None => {
+                event.set_field_type(EventType::Delete);
+                let kv = event.mut_kv();
+                kv.set_key(key.to_vec());
+                kv.set_mod_revision(self.mod_revision);
+            }
+        }
+        event
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Lease {
+    // time to live in seconds
+    ttl: u64,
+    keys: BTreeSet<Vec<u8>>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Mvcc {
+    // revision of the last write
+    revision: u64,
+    // versions older than it are dropped
+    compacted: u64,
+    // key -> versions in the order of revision
+    keys: BTreeMap<Vec<u8>, Vec<Version>>,
+    leases: BTreeMap<u64, Lease>,
+}
+
+impl Mvcc {
+    /// Restores the values of a snapshot taken before the history was kept,
+    /// as written at revision 0
+    fn from_values(values: BTreeMap<Vec<u8>, Vec<u8>>) -> Mvcc {
+        let keys = values
+            .into_iter()
+            .map(|(k, v)| {
+                let version = Version {
+                    create_revision: 0,
+                    mod_revision: 0,
+                    version: 1,
+                    lease: 0,
+                    value: Some(v),
+                };
+                (k, vec![version])
+            })
+            .collect();
+        Mvcc {
+            keys: keys,
+            ..Mvcc::default()
+        }
+    }
+
+    fn check_revision(&self, revision: u64) -> Result<u64> {
+        match revision {
+            0 => Ok(self.revision),
+            r if r > self.revision => Err(Error::FutureRevision(r, self.revision)),
+            r if r < self.compacted => Err(Error::Compacted(r, self.compacted)),
+            r => Ok(r),
+        }
+    }
+
+    fn latest(&self, k: &[u8]) -> Option<KvPair> {
+        self.keys
+            .get(k)
+            .and_then(|versions| versions.last())
+            .and_then(|ver| ver.to_kv(k, false))
+    }
+
+    fn check_lease(&self, lease: u64) -> Result<()> {
+        if lease != 0 && !self.leases.contains_key(&lease) {
+            return Err(Error::LeaseNotFound(lease));
+        }
+        Ok(())
+    }
+
+    // the lease must exist
+    fn write(
+        &mut self,
+        k: Vec<u8>,
+        v: Option<Vec<u8>>,
+        lease: u64,
+        revision: u64,
+    ) -> Option<KvPair> {
+        let prev = self.latest(&k);
+        if prev.is_none() && v.is_none() {
+            // nothing to delete
+            return None;
+        }
+        if let Some(l) = prev
+            .as_ref()
+            .and_then(|p| self.leases.get_mut(&p.get_lease()))
+        {
+            l.keys.remove(&k);
+        }
+        let lease = if v.is_some() { lease } else { 0 };
+        if let Some(l) = self.leases.get_mut(&lease) {
+            l.keys.insert(k.clone());
+        }
+        let (create_revision, version) = match (&prev, &v) {
+            (Some(p), Some(_)) => (p.get_create_revision(), p.get_version() + 1),
+            (None, Some(_)) => (revision, 1),
+            (_, None) => (0, 0),
+        };
+        self.keys.entry(k).or_insert_with(Vec::new).push(Version {
+            create_revision: create_revision,
+            mod_revision: revision,
+            version: version,
+            lease: lease,
+            value: v,
+        });
+        self.revision = revision;
+        prev
+    }
+
+    fn compact(&mut self, revision: u64) -> Result<()> {
+        if revision <= self.compacted {
+            return Err(Error::Compacted(revision, self.compacted));
+        }
+        if revision > self.revision {
+            return Err(Error::FutureRevision(revision, self.revision));
+        }
+        let mut deleted = vec![];
+        for (k, versions) in self.keys.iter_mut() {
+            // the newest version at the revision is still visible there
+            let keep = match versions.iter().rposition(|v| v.mod_revision <= revision) {
+                Some(i) if versions[i].value.is_none() => i + 1,
+                Some(i) => i,
+                None => 0,
+            };
+            versions.drain(..keep);
+            if versions.is_empty() {
+                deleted.push(k.clone());
+            }
+        }
+        for k in deleted {
+            self.keys.remove(&k);
+        }
+        self.compacted = revision;
+        Ok(())
+    }
+
+    fn grant(&mut self, id: u64, ttl: u64) -> Result<()> {
+        if id == 0 || self.leases.contains_key(&id) {
+            return Err(Error::LeaseExists(id));
+        }
+        self.leases.insert(
+            id,
+            Lease {
+                ttl: ttl,
+                keys: BTreeSet::new(),
+            },
+        );
+        Ok(())
+    }
+
+    fn revoke(&mut self, id: u64, revision: u64) -> Result<Vec<Vec<u8>>> {
+        let lease = self.leases.remove(&id).ok_or(Error::LeaseNotFound(id))?;
+        let keys: Vec<Vec<u8>> = lease.keys.into_iter().collect();
+        for k in &keys {
+            self.write(k.clone(), None, 0, revision);
+        }
+        Ok(keys)
+    }
+
+    /// Returns at most `limit` pairs within the range at `revision` in key
+    /// order, or in reverse order, 0 means no limit. The values are left
+    /// empty if `keys_only` is set
+    fn scan(
+        &self,
+        range: (Bound<&[u8]>, Bound<&[u8]>),
+        revision: u64,
+        limit: usize,
+        reverse: bool,
+        keys_only: bool,
+    ) -> Result<Vec<KvPair>> {
+        let revision = self.check_revision(revision)?;
+        if is_empty_range(range.0, range.1) {
+            return Ok(vec![]);
+        }
+        let limit = if limit == 0 {
+            usize::max_value()
+        } else {
+            limit
+        };
+        let visible = |(k, versions): (&Vec<u8>, &Vec<Version>)| {
+            versions
+                .iter()
+                .rev()
+                .find(|v| v.mod_revision <= revision)
+                .and_then(|v| v.to_kv(k, keys_only))
+        };
+        let keys = self.keys.range::<[u8], _>(range);
+        let kvs = if reverse {
+            keys.rev().filter_map(visible).take(limit).collect()
+        } else {
+            keys.filter_map(visible).take(limit).collect()
+        };
+        Ok(kvs)
+    }
+
+    /// Serves a Get/Scan op from the local data
+    fn read(&self, op: &OpRequest) -> Result<OpResponse> {
+        let kvs = match op.get_field_type() {
+            Op::Get => {
+                let key = Bound::Included(op.get_key());
+                self.scan((key, key), op.get_revision(), 0, false, false)?
+            }
+            Op::Scan => {
+                let key = op.get_key();
+                let start = if op.get_exclude_start() {
+                    Bound::Excluded(key)
+                } else {
+                    Bound::Included(key)
+                };
+                let end_of_prefix = if op.get_prefix() {
+                    prefix_end(key)
+                } else {
+                    vec![]
+                };
+                let end = if op.get_prefix() {
+                    match end_of_prefix.as_slice() {
+                        [] => Bound::Unbounded,
+                        end => Bound::Excluded(end),
+                    }
+                } else {
+                    match op.get_end_key() {
+                        [] => Bound::Unbounded,
+                        end if op.get_include_end() => Bound::Included(end),
+                        end => Bound::Excluded(end),
+                    }
+                };
+                self.scan(
+                    (start, end),
+                    op.get_revision(),
+                    op.get_limit() as usize,
+                    op.get_reverse(),
+                    op.get_keys_only(),
+                )?
+            }
+            _ => vec![],
+        };
+        let mut resp = OpResponse::new();
+        resp.set_kvs(kvs.into());
+        resp.mut_header().set_revision(self.revision);
+        Ok(resp)
+    }
+
+    fn compare(&self, c: &Compare) -> bool {
+        let kv = self.latest(c.get_key());
+        let number = |f: fn(&KvPair) -> u64| kv.as_ref().map_or(0, f).cmp(&c.get_number());
+        let ord = match c.get_target() {
+            CompareTarget::Value => match kv {
+                Some(ref kv) => kv.get_value().cmp(c.get_value()),
+                None => return false,
+            },
+            CompareTarget::Version => number(KvPair::get_version),
+            CompareTarget::CreateRevision => number(KvPair::get_create_revision),
+            CompareTarget::ModRevision => number(KvPair::get_mod_revision),
+            CompareTarget::Exists => {
+                let exists = kv.is_some() == c.get_exists();
+                return match c.get_result() {
+                    CompareResult::Equal => exists,
+                    CompareResult::NotEqual => !exists,
+                    _ => false,
+                };
+            }
+        };
+        match c.get_result() {
+            CompareResult::Equal => ord == Ordering::Equal,
+            CompareResult::Greater => ord == Ordering::Greater,
+            CompareResult::Less => ord == Ordering::Less,
+            CompareResult::NotEqual => ord != Ordering::Equal,
+        }
+    }
+
+    fn txn(&mut self, txn: &TxnRequest, revision: u64) -> Result<TxnResponse> {
+        // the result would depend on the order of the writes
+        for ops in &[txn.get_success(), txn.get_failure()] {
+            let mut written = BTreeSet::new();
+            for op in ops.iter() {
+                let writes = match op.get_field_type() {
+                    Op::Set | Op::Del => true,
+                    _ => false,
+                };
+                if writes && !written.insert(op.get_key()) {
+                    return Err(Error::InvalidTxn(format!(
+                        "key {:?} written twice",
+                        String::from_utf8_lossy(op.get_key())
+                    )));
+                }
+            }
+        }
+        let succeeded = txn.get_compares().iter().all(|c| self.compare(c));
+        let ops = if succeeded {
+            txn.get_success()
+        } else {
+            txn.get_failure()
+        };
+        // nothing may fail once the first op is applied, the revisions valid
+        // now stay valid after the writes
+        for op in ops {
+            match op.get_field_type() {
+                Op::Compact => return Err(Error::InvalidTxn("compact in txn".to_owned())),
+                Op::Get | Op::Scan => {
+                    self.check_revision(op.get_revision())?;
+                }
+                Op::Set => self.check_lease(op.get_lease())?,
+                Op::Del => (),
+            }
+        }
+        let mut responses = Vec::with_capacity(ops.len());
+        for op in ops {
+            let prev = match op.get_field_type() {
+                Op::Set => self.write(
+                    op.get_key().to_vec(),
+                    Some(op.get_value().to_vec()),
+                    op.get_lease(),
+                    revision,
+                ),
+                Op::Del => self.write(op.get_key().to_vec(), None, 0, revision),
+                _ => {
+                    responses.push(self.read(op)?);
+                    continue;
+                }
+            };
+            let mut resp = OpResponse::new();
+            if let Some(kv) = prev {
+                resp.set_prev_kv(kv);
+            }
+            resp.mut_header().set_revision(self.revision);
+            responses.push(resp);
+        }
+        let mut resp = TxnResponse::new();
+        resp.mut_header().set_revision(self.revision);
+        resp.set_succeeded(succeeded);
+        resp.set_responses(responses.into());
+        Ok(resp)
+    }
+}
+
+/// Simple implement of MVCC KV Storage by BTreeMap, keys are kept in order
+/// and every write is kept as a version of its key. The revision of a write
+/// is given by the caller and must increase
 #[derive(Clone)]
 pub struct Store {
-    inner: Arc<RwLock<IndexMap<Vec<u8>, Vec<u8>>>>,
+    inner: Arc<RwLock<Mvcc>>,
 }
 
 impl Store {
-    fn from_map(m: IndexMap<Vec<u8>, Vec<u8>>) -> Store {
+    pub fn new() -> Store {
         Store {
-            inner: Arc::new(RwLock::new(m)),
+            inner: Arc::new(RwLock::new(Mvcc::default())),
         }
     }
 
-    pub fn new() -> Store {
-        Store::from_map(IndexMap::new())
-    }
-
     pub fn from_snapshot(&mut self, snap: &Snapshot) -> Result<()> {
         debug!("snap data {:?}", snap.get_data());
-        let idx_map = deserialize(snap.get_data())?;
-        debug!("{:?}", idx_map);
-        *self.inner.wl() = idx_map;
+        let data = snap.get_data();
+        let mvcc = if data.starts_with(SNAPSHOT_MAGIC) {
+            match data[SNAPSHOT_MAGIC.len()..].split_first() {
+                Some((&SNAPSHOT_VERSION, data)) => deserialize(data)?,
+                Some((&version, _)) => {
+                    return Err(Error::StoreVersion(version, SNAPSHOT_VERSION));
+                }
+                None => return Err(Error::StoreVersion(0, SNAPSHOT_VERSION)),
+            }
+        } else {
+            Mvcc::from_values(deserialize(data)?)
+        };
+        *self.inner.wl() = mvcc;
         Ok(())
     }
 
     pub fn get_snapshot(&self) -> Result<Vec<u8>> {
-        serialize(&*self.inner.rl()).map_err(|err| err.into())
+        let mut data = SNAPSHOT_MAGIC.to_vec();
+        data.push(SNAPSHOT_VERSION);
+        data.extend(serialize(&*self.inner.rl())?);
+        Ok(data)
+    }
+
+    /// Revision of the last write
+    pub fn revision(&self) -> u64 {
+        self.inner.rl().revision
+    }
+
+    /// Revision the history has been compacted to
+    pub fn compacted(&self) -> u64 {
+        self.inner.rl().compacted
+    }
+
+    /// Drops the versions older than `revision`, except the newest one of
+    /// each key at `revision`
+    pub fn compact(&mut self, revision: u64) -> Result<()> {
+        self.inner.wl().compact(revision)
+    }
+
+    /// Sets the value at `revision`, returns the previous pair
+    pub fn set(&mut self, k: Vec<u8>, v: Vec<u8>, revision: u64) -> Option<KvPair> {
+        self.inner.wl().write(k, Some(v), 0, revision)
     }
 
-    pub fn set(&mut self, k: Vec<u8>, v: Vec<u8>) -> Option<Vec<u8>> {
-        self.inner.wl().insert(k, v)
+    /// Sets the value attached to the lease at `revision`, returns the
+    /// previous pair
+    pub fn set_with_lease(
+        &mut self,
+        k: Vec<u8>,
+        v: Vec<u8>,
+        lease: u64,
+        revision: u64,
+    ) -> Result<Option<KvPair>> {
+        let mut mvcc = self.inner.wl();
+        mvcc.check_lease(lease)?;
+        Ok(mvcc.write(k, Some(v), lease, revision))
     }
 
-    pub fn get(&self, k: &[u8]) -> Option<Vec<u8>> {
-        self.inner.rl().get(k).map(|v| v.clone())
+    /// Adds a lease with the time to live in seconds
+    pub fn grant(&mut self, id: u64, ttl: u64) -> Result<()> {
+        self.inner.wl().grant(id, ttl)
     }
 
-    pub fn delete(&mut self, k: &[u8]) -> Option<Vec<u8>> {
-        self.inner.wl().remove(k)
+    /// Removes the lease and deletes its keys at `revision`, returns the
+    /// deleted keys
+    pub fn revoke(&mut self, id: u64, revision: u64) -> Result<Vec<Vec<u8>>> {
+        self.inner.wl().revoke(id, revision)
     }
 
-    pub fn scan(&self, start_key: &[u8], limit: u32) -> Vec<(Vec<u8>, Vec<u8>)> {
+    /// Returns the time to live and the keys of the lease
+    pub fn lease(&self, id: u64) -> Option<(u64, Vec<Vec<u8>>)> {
         self.inner
             .rl()
+            .leases
+            .get(&id)
+            .map(|l| (l.ttl, l.keys.iter().cloned().collect()))
+    }
+
+    /// Returns the id and time to live of every lease
+    pub fn leases(&self) -> Vec<(u64, u64)> {
+        self.inner
+            .rl()
+            .leases
             .iter()
-            .skip_while(|(k, _)| {
-                if k.as_slice() == start_key {
-                    return false;
-                }
-                true
-            })
-            .take(limit as usize)
-            .map(|(k, v)| (k.clone(), v.clone()))
+            .map(|(id, l)| (*id, l.ttl))
             .collect()
     }
+
+    /// Gets the pair at `revision`, 0 means the newest one
+    pub fn get(&self, k: &[u8], revision: u64) -> Result<Option<KvPair>> {
+        let key = Bound::Included(k);
+        let kvs = self
+            .inner
+            .rl()
+            .scan((key, key), revision, 0, false, false)?;
+        Ok(kvs.into_iter().next())
+    }
+
+    /// Deletes the key at `revision`, returns the previous pair
+    pub fn delete(&mut self, k: &[u8], revision: u64) -> Option<KvPair> {
+        self.inner.wl().write(k.to_vec(), None, 0, revision)
+    }
+
+    /// Returns at most `limit` pairs within the range at `revision` in key
+    /// order, or in reverse order, 0 means no limit. The values are left
+    /// empty if `keys_only` is set
+    pub fn scan(
+        &self,
+        range: (Bound<&[u8]>, Bound<&[u8]>),
+        revision: u64,
+        limit: usize,
+        reverse: bool,
+        keys_only: bool,
+    ) -> Result<Vec<KvPair>> {
+        self.inner
+            .rl()
+            .scan(range, revision, limit, reverse, keys_only)
+    }
+
+    /// Returns the writes within the range since `revision` in the order of
+    /// revision. The deletions at the compacted revision are gone, so it
+    /// must be newer than that
+    pub fn events(&self, range: (Bound<&[u8]>, Bound<&[u8]>), revision: u64) -> Result<Vec<Event>> {
+        let mvcc = self.inner.rl();
+        if mvcc.compacted > 0 && revision <= mvcc.compacted {
+            return Err(Error::Compacted(revision, mvcc.compacted));
+        }
+        if is_empty_range(range.0, range.1) {
+            return Ok(vec![]);
+        }
+        let mut events: Vec<(u64, Event)> = vec![];
+        for (k, versions) in mvcc.keys.range::<[u8], _>(range) {
+            events.extend(
+                versions
+                    .iter()
+                    .filter(|v| v.mod_revision >= revision)
+                    .map(|v| (v.mod_revision, v.to_event(k))),
+            );
+        }
+        events.sort_by_key(|&(rev, _)| rev);
+        Ok(events.into_iter().map(|(_, e)| e).collect())
+    }
+
+    /// Serves a Get/Scan op from the local data
+    pub fn read(&self, op: &OpRequest) -> Result<OpResponse> {
+        self.inner.rl().read(op)
+    }
+
+    /// Applies the ops of the txn atomically at `revision`, the success ops
+    /// if every compare holds, otherwise the failure ops
+    pub fn txn(&mut self, txn: &TxnRequest, revision: u64) -> Result<TxnResponse> {
+        self.inner.wl().txn(txn, revision)
+    }
+}
+
+/// Returns the smallest key greater than every key starting with `prefix`,
+/// an empty key if there is no such key
+pub fn prefix_end(prefix: &[u8]) -> Vec<u8> {
+    let mut end = prefix.to_vec();
+    while let Some(b) = end.pop() {
+        if b < 0xff {
+            end.push(b + 1);
+            return end;
+        }
+    }
+    end
+}
+
+// BTreeMap::range panics on such bounds
+fn is_empty_range(start: Bound<&[u8]>, end: Bound<&[u8]>) -> bool {
+    match (start, end) {
+        (Bound::Included(s), Bound::Included(e)) => s > e,
+        (Bound::Included(s), Bound::Excluded(e))
+        | (Bound::Excluded(s), Bound::Included(e))
+        | (Bound::Excluded(s), Bound::Excluded(e)) => s >= e,
+        _ => false,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn keys(kvs: Vec<KvPair>) -> Vec<Vec<u8>> {
+        kvs.into_iter().map(|mut kv| kv.take_key()).collect()
+    }
+
     #[test]
     fn set_get_delete_test() {
         let k = vec![1u8, 2u8, 3u8];
         let v = vec![4u8, 5u8, 6u8];
         let mut kv = Store::new();
-        kv.set(k.clone(), v.clone());
-        assert_eq!(kv.get(&k), Some(v.clone()));
-        assert_eq!(kv.delete(&k), Some(v.clone()));
-        assert_eq!(kv.get(&k), None);
+        assert!(kv.set(k.clone(), v.clone(), 1).is_none());
+        assert_eq!(kv.get(&k, 0).unwrap().unwrap().get_value(), &v[..]);
+        assert_eq!(kv.delete(&k, 2).unwrap().get_value(), &v[..]);
+        assert_eq!(kv.get(&k, 0).unwrap(), None);
+        assert!(kv.delete(&k, 3).is_none());
+        assert_eq!(kv.revision(), 2);
+    }
+
+    #[test]
+    fn revision_test() {
+        let k = b"k".to_vec();
+        let mut kv = Store::new();
+        kv.set(k.clone(), b"v1".to_vec(), 2);
+        kv.set(k.clone(), b"v2".to_vec(), 4);
+        kv.delete(&k, 6);
+        kv.set(k.clone(), b"v3".to_vec(), 8);
+
+        assert_eq!(kv.get(&k, 1).unwrap(), None);
+        let kv2 = kv.get(&k, 3).unwrap().unwrap();
+        assert_eq!(kv2.get_value(), b"v1");
+        let kv4 = kv.get(&k, 5).unwrap().unwrap();
+        assert_eq!(kv4.get_value(), b"v2");
+        assert_eq!(kv4.get_create_revision(), 2);
+        assert_eq!(kv4.get_mod_revision(), 4);
+        assert_eq!(kv4.get_version(), 2);
+        assert_eq!(kv.get(&k, 7).unwrap(), None);
+        // a deleted key is created again
+        let kv8 = kv.get(&k, 0).unwrap().unwrap();
+        assert_eq!(kv8.get_create_revision(), 8);
+        assert_eq!(kv8.get_version(), 1);
+        match kv.get(&k, 9) {
+            Err(Error::FutureRevision(9, 8)) => (),
+            _ => panic!("expected future revision"),
+        }
+    }
+
+    #[test]
+    fn compact_test() {
+        let (k1, k2) = (b"k1".to_vec(), b"k2".to_vec());
+        let mut kv = Store::new();
+        kv.set(k1.clone(), b"v1".to_vec(), 1);
+        kv.set(k2.clone(), b"v2".to_vec(), 2);
+        kv.set(k1.clone(), b"v3".to_vec(), 3);
+        kv.delete(&k2, 4);
+        kv.set(k1.clone(), b"v5".to_vec(), 5);
+
+        kv.compact(4).unwrap();
+        match kv.get(&k1, 3) {
+            Err(Error::Compacted(3, 4)) => (),
+            _ => panic!("expected compacted"),
+        }
+        assert_eq!(kv.get(&k1, 4).unwrap().unwrap().get_value(), b"v3");
+        assert_eq!(kv.get(&k1, 0).unwrap().unwrap().get_value(), b"v5");
+        // the deleted key is gone with its history
+        assert_eq!(kv.get(&k2, 4).unwrap(), None);
+        assert!(kv.inner.rl().keys.get(&k2).is_none());
+        assert_eq!(kv.inner.rl().keys[&k1].len(), 2);
+
+        assert!(kv.compact(4).is_err());
+        assert!(kv.compact(6).is_err());
+        assert_eq!(kv.compacted(), 4);
+    }
+
+    #[test]
+    fn events_test() {
+        let (k1, k2) = (b"k1".to_vec(), b"k2".to_vec());
+        let mut kv = Store::new();
+        kv.set(k2.clone(), b"v1".to_vec(), 1);
+        kv.set(k1.clone(), b"v2".to_vec(), 2);
+        kv.delete(&k2, 3);
+        kv.set(k2.clone(), b"v4".to_vec(), 4);
+
+        let range = (Bound::Unbounded, Bound::Unbounded);
+        let events = kv.events(range, 2).unwrap();
+        let revs: Vec<u64> = events
+            .iter()
+            .map(|e| e.get_kv().get_mod_revision())
+            .collect();
+        assert_eq!(revs, vec![2, 3, 4]);
+        assert_eq!(events[0].get_field_type(), EventType::Put);
+        assert_eq!(events[0].get_kv().get_value(), b"v2");
+        assert_eq!(events[1].get_field_type(), EventType::Delete);
+        assert_eq!(events[1].get_kv().get_key(), &k2[..]);
+
+        let events = kv
+            .events((Bound::Included(&k2[..]), Bound::Included(&k2[..])), 4)
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].get_kv().get_version(), 1);
+
+        kv.compact(3).unwrap();
+        assert!(kv.events(range, 3).is_err());
+        assert_eq!(kv.events(range, 4).unwrap().len(), 1);
+    }
+
+    fn op(ty: Op, key: &[u8], value: &[u8]) -> OpRequest {
+        let mut op = OpRequest::new();
+        op.set_field_type(ty);
+        op.set_key(key.to_vec());
+        op.set_value(value.to_vec());
+        op
+    }
+
+    fn compare(target: CompareTarget, result: CompareResult, key: &[u8]) -> Compare {
+        let mut c = Compare::new();
+        c.set_target(target);
+        c.set_result(result);
+        c.set_key(key.to_vec());
+        c
+    }
+
+    #[test]
+    fn txn_test() {
+        let mut kv = Store::new();
+        kv.set(b"a".to_vec(), b"1".to_vec(), 1);
+
+        // swaps a only if it is still 1
+        let mut c = compare(CompareTarget::Value, CompareResult::Equal, b"a");
+        c.set_value(b"1".to_vec());
+        let mut txn = TxnRequest::new();
+        txn.mut_compares().push(c);
+        txn.mut_success().push(op(Op::Set, b"a", b"2"));
+        txn.mut_success().push(op(Op::Set, b"b", b"2"));
+        txn.mut_success().push(op(Op::Get, b"a", b""));
+        txn.mut_failure().push(op(Op::Get, b"a", b""));
+
+        let resp = kv.txn(&txn, 2).unwrap();
+        assert!(resp.get_succeeded());
+        assert_eq!(resp.get_header().get_revision(), 2);
+        assert_eq!(resp.get_responses().len(), 3);
+        assert_eq!(resp.get_responses()[0].get_prev_kv().get_value(), b"1");
+        assert_eq!(resp.get_responses()[2].get_kvs()[0].get_value(), b"2");
+        assert_eq!(kv.get(b"b", 0).unwrap().unwrap().get_mod_revision(), 2);
+
+        let resp = kv.txn(&txn, 3).unwrap();
+        assert!(!resp.get_succeeded());
+        assert_eq!(resp.get_responses()[0].get_kvs()[0].get_value(), b"2");
+        assert_eq!(kv.revision(), 2);
+    }
+
+    #[test]
+    fn compare_test() {
+        let mut kv = Store::new();
+        kv.set(b"a".to_vec(), b"1".to_vec(), 1);
+        kv.set(b"a".to_vec(), b"2".to_vec(), 2);
+        let check = |c: Compare| kv.inner.rl().compare(&c);
+
+        let mut c = compare(CompareTarget::Version, CompareResult::Equal, b"a");
+        c.set_number(2);
+        assert!(check(c));
+        let mut c = compare(CompareTarget::ModRevision, CompareResult::Greater, b"a");
+        c.set_number(1);
+        assert!(check(c));
+        let mut c = compare(CompareTarget::CreateRevision, CompareResult::Less, b"a");
+        c.set_number(1);
+        assert!(!check(c));
+        // a missing key has version 0 but no value
+        let c = compare(CompareTarget::Version, CompareResult::Equal, b"b");
+        assert!(check(c));
+        let c = compare(CompareTarget::Value, CompareResult::NotEqual, b"b");
+        assert!(!check(c));
+        let mut c = compare(CompareTarget::Exists, CompareResult::Equal, b"a");
+        c.set_exists(true);
+        assert!(check(c));
+        let c = compare(CompareTarget::Exists, CompareResult::NotEqual, b"b");
+        assert!(!check(c));
+    }
+
+    #[test]
+    fn invalid_txn_test() {
+        let mut kv = Store::new();
+        let mut txn = TxnRequest::new();
+        txn.mut_success().push(op(Op::Set, b"a", b"1"));
+        let mut get = op(Op::Get, b"a", b"");
+        get.set_revision(5);
+        txn.mut_success().push(get);
+        assert!(kv.txn(&txn, 1).is_err());
+        // nothing is applied
+        assert_eq!(kv.get(b"a", 0).unwrap(), None);
+
+        let mut txn = TxnRequest::new();
+        txn.mut_success().push(op(Op::Set, b"a", b"1"));
+        txn.mut_failure().push(op(Op::Set, b"a", b"1"));
+        txn.mut_failure().push(op(Op::Del, b"a", b""));
+        match kv.txn(&txn, 1) {
+            Err(Error::InvalidTxn(reason)) => assert!(reason.contains("twice")),
+            _ => panic!("expected invalid txn"),
+        }
+    }
+
+    #[test]
+    fn lease_test() {
+        let (k1, k2) = (b"k1".to_vec(), b"k2".to_vec());
+        let mut kv = Store::new();
+        kv.grant(7, 10).unwrap();
+        assert!(kv.grant(7, 10).is_err());
+        kv.set_with_lease(k1.clone(), b"v".to_vec(), 7, 1).unwrap();
+        kv.set_with_lease(k2.clone(), b"v".to_vec(), 7, 2).unwrap();
+        assert!(kv.set_with_lease(k1.clone(), b"v".to_vec(), 8, 3).is_err());
+        assert_eq!(kv.get(&k1, 0).unwrap().unwrap().get_lease(), 7);
+        // a set without the lease detaches the key
+        kv.set(k2.clone(), b"v".to_vec(), 3);
+        assert_eq!(kv.lease(7), Some((10, vec![k1.clone()])));
+        assert_eq!(kv.leases(), vec![(7, 10)]);
+
+        assert_eq!(kv.revoke(7, 4).unwrap(), vec![k1.clone()]);
+        assert_eq!(kv.get(&k1, 0).unwrap(), None);
+        assert!(kv.get(&k2, 0).unwrap().is_some());
+        assert_eq!(kv.lease(7), None);
+        assert!(kv.revoke(7, 5).is_err());
     }
 
     #[test]
     fn scan_test() {
-        let k1 = vec![1u8, 2u8, 3u8];
-        let v1 = vec![4u8, 5u8, 6u8];
-        let k2 = vec![11u8, 12u8, 13u8];
-        let v2 = vec![4u8, 5u8, 6u8];
-        let k3 = vec![21u8, 22u8, 23u8];
-        let v3 = vec![4u8, 5u8, 6u8];
+        let mut kv = Store::new();
+        for (rev, k) in [b"b", b"a", b"d", b"c"].iter().enumerate() {
+            kv.set(k.to_vec(), k.to_vec(), rev as u64 + 1);
+        }
+        // the start key needs not exist
+        let kvs = kv
+            .scan(
+                (Bound::Included(b"bb"), Bound::Unbounded),
+                0,
+                0,
+                false,
+                false,
+            )
+            .unwrap();
+        assert_eq!(kvs[0].get_value(), b"c");
+        assert_eq!(keys(kvs), vec![b"c".to_vec(), b"d".to_vec()]);
+        let kvs = kv
+            .scan(
+                (Bound::Included(b"a"), Bound::Excluded(b"c")),
+                0,
+                0,
+                false,
+                false,
+            )
+            .unwrap();
+        assert_eq!(keys(kvs), vec![b"a".to_vec(), b"b".to_vec()]);
+        let kvs = kv
+            .scan(
+                (Bound::Excluded(b"a"), Bound::Included(b"c")),
+                0,
+                0,
+                false,
+                false,
+            )
+            .unwrap();
+        assert_eq!(keys(kvs), vec![b"b".to_vec(), b"c".to_vec()]);
+        let kvs = kv
+            .scan((Bound::Unbounded, Bound::Unbounded), 0, 3, true, false)
+            .unwrap();
+        assert_eq!(keys(kvs), vec![b"d".to_vec(), b"c".to_vec(), b"b".to_vec()]);
+        let kvs = kv
+            .scan((Bound::Included(b"a"), Bound::Unbounded), 0, 1, false, true)
+            .unwrap();
+        assert_eq!(kvs[0].get_value(), b"");
+        assert!(kv
+            .scan(
+                (Bound::Included(b"c"), Bound::Excluded(b"a")),
+                0,
+                0,
+                false,
+                false
+            )
+            .unwrap()
+            .is_empty());
+        // only b & a exist at revision 2
+        let kvs = kv
+            .scan((Bound::Unbounded, Bound::Unbounded), 2, 0, false, false)
+            .unwrap();
+        assert_eq!(keys(kvs), vec![b"a".to_vec(), b"b".to_vec()]);
+    }
 
+    #[test]
+    fn prefix_scan_test() {
         let mut kv = Store::new();
-        kv.set(k1.clone(), v1.clone());
-        kv.set(k2.clone(), v2.clone());
-        kv.set(k3.clone(), v3.clone());
-        assert_eq!(kv.scan(&k2, 3), vec![(k2, v2), (k3, v3)])
+        for (rev, k) in [vec![1u8], vec![1, 2], vec![1, 0xff], vec![2], vec![0xff]]
+            .iter()
+            .enumerate()
+        {
+            kv.set(k.clone(), vec![], rev as u64 + 1);
+        }
+        assert_eq!(prefix_end(&[1]), vec![2]);
+        assert_eq!(prefix_end(&[1, 0xff]), vec![2]);
+        assert_eq!(prefix_end(&[0xff]), Vec::<u8>::new());
+        let end = prefix_end(&[1]);
+        let kvs = kv
+            .scan(
+                (Bound::Included(&[1]), Bound::Excluded(&end[..])),
+                0,
+                0,
+                false,
+                true,
+            )
+            .unwrap();
+        assert_eq!(kvs.len(), 3);
+        let kvs = kv
+            .scan(
+                (Bound::Included(&[0xff]), Bound::Unbounded),
+                0,
+                0,
+                false,
+                true,
+            )
+            .unwrap();
+        assert_eq!(keys(kvs), vec![vec![0xff]]);
+
+        let mut op = OpRequest::new();
+        op.set_field_type(Op::Scan);
+        op.set_key(vec![1]);
+        op.set_prefix(true);
+        assert_eq!(kv.read(&op).unwrap().get_kvs().len(), 3);
+        op.set_exclude_start(true);
+        assert_eq!(kv.read(&op).unwrap().get_kvs().len(), 2);
+        op.set_key(vec![0xff]);
+        op.set_exclude_start(false);
+        assert_eq!(kv.read(&op).unwrap().get_kvs().len(), 1);
+        op.set_prefix(false);
+        op.set_key(vec![1, 2]);
+        op.set_end_key(vec![2]);
+        op.set_include_end(true);
+        let kvs = kv.read(&op).unwrap().take_kvs().into_vec();
+        assert_eq!(keys(kvs), vec![vec![1, 2], vec![1, 0xff], vec![2]]);
+    }
+
+    #[test]
+    fn snapshot_test() {
+        let mut kv = Store::new();
+        kv.set(b"a".to_vec(), b"1".to_vec(), 1);
+        kv.set(b"a".to_vec(), b"2".to_vec(), 2);
+        let mut snap = Snapshot::new();
+        snap.set_data(kv.get_snapshot().unwrap());
+        let mut restored = Store::new();
+        restored.from_snapshot(&snap).unwrap();
+        assert_eq!(restored.revision(), 2);
+        assert_eq!(restored.get(b"a", 1).unwrap().unwrap().get_value(), b"1");
+
+        // taken before the history was kept
+        let mut values = BTreeMap::new();
+        values.insert(b"a".to_vec(), b"1".to_vec());
+        values.insert(b"b".to_vec(), b"2".to_vec());
+        snap.set_data(serialize(&values).unwrap());
+        restored.from_snapshot(&snap).unwrap();
+        assert_eq!(restored.revision(), 0);
+        let kv = restored.get(b"b", 0).unwrap().unwrap();
+        assert_eq!(kv.get_value(), b"2");
+        assert_eq!(kv.get_version(), 1);
+        restored.set(b"b".to_vec(), b"3".to_vec(), 1);
+        assert_eq!(restored.get(b"b", 1).unwrap().unwrap().get_version(), 2);
+
+        let mut data = SNAPSHOT_MAGIC.to_vec();
+        data.push(SNAPSHOT_VERSION + 1);
+        snap.set_data(data);
+        match restored.from_snapshot(&snap) {
+            Err(Error::StoreVersion(2, 1)) => (),
+            r => panic!("unexpected {:?}", r),
+        }
     }
 }
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+// how long the leader waits before proposing the revocation of an expired
+// lease again
+const REVOKE_RETRY_SECS: u64 = 3;
+
+struct Expiry {
+    // time to live in seconds
+    ttl: u64,
+    deadline: Instant,
+}
+
+/// Tracks when the leases expire. The leases themselves are replicated in the
+/// store, the deadlines are local to the node and only matter on the leader,
+/// which proposes the revocation of the expired ones
+pub struct Lessor {
+    leases: HashMap<u64, Expiry>,
+    next_id: u64,
+}
+
+impl Lessor {
+    pub fn new(node_id: u64) -> Lessor {
+        Lessor {
+            leases: HashMap::new(),
+            // ids picked by different leaders won't collide
+            next_id: node_id << 48,
+        }
+    }
+
+    /// Returns an id which is not taken by any lease
+    pub fn next_id(&mut self) -> u64 {
+        loop {
+            self.next_id += 1;
+            if !self.leases.contains_key(&self.next_id) {
+                return self.next_id;
+            }
+        }
+    }
+
+    pub fn grant(&mut self, id: u64, ttl: u64, now: Instant) {
+        self.leases.insert(
+            id,
+            Expiry {
+                ttl: ttl,
+                deadline: now + Duration::from_secs(ttl),
+            },
+        );
+    }
+
+    pub fn revoke(&mut self, id: u64) {
+        self.leases.remove(&id);
+    }
+
+    /// Renews the lease, returns its time to live
+    pub fn keep_alive(&mut self, id: u64, now: Instant) -> Option<u64> {
+        self.leases.get_mut(&id).map(|e| {
+            e.deadline = now + Duration::from_secs(e.ttl);
+            e.ttl
+        })
+    }
+
+    /// Returns the seconds left before the lease expires
+    pub fn remaining(&self, id: u64, now: Instant) -> Option<u64> {
+        self.leases.get(&id).map(|e| {
+            if e.deadline > now {
+                (e.deadline - now).as_secs()
+            } else {
+                0
+            }
+        })
+    }
+
+    /// Renews all the leases, called when the node becomes the leader since
+    /// the keepalives were sent to the previous one
+    pub fn promote(&mut self, now: Instant) {
+        for e in self.leases.values_mut() {
+            e.deadline = now + Duration::from_secs(e.ttl);
+        }
+    }
+
+    /// Returns the ids of the expired leases, they won't be returned again
+    /// until the retry interval elapses
+    pub fn expired(&mut self, now: Instant) -> Vec<u64> {
+        let mut ids = vec![];
+        for (id, e) in &mut self.leases {
+            if e.deadline <= now {
+                ids.push(*id);
+                e.deadline = now + Duration::from_secs(REVOKE_RETRY_SECS);
+            }
+        }
+        ids
+    }
+
+    /// Replaces the leases with the ones restored from a snapshot
+    pub fn reset(&mut self, leases: Vec<(u64, u64)>, now: Instant) {
+        self.leases.clear();
+        for (id, ttl) in leases {
+            self.grant(id, ttl, now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expire_test() {
+        let mut lessor = Lessor::new(1);
+        let now = Instant::now();
+        let id = lessor.next_id();
+        assert_eq!(id, (1 << 48) + 1);
+        lessor.grant(id, 10, now);
+        lessor.grant(2, 20, now);
+        assert_eq!(lessor.remaining(id, now), Some(10));
+        assert!(lessor.expired(now + Duration::from_secs(5)).is_empty());
+
+        let now = now + Duration::from_secs(5);
+        assert_eq!(lessor.keep_alive(id, now), Some(10));
+        assert_eq!(lessor.expired(now + Duration::from_secs(10)), vec![id]);
+        // retried later on
+        assert!(lessor.expired(now + Duration::from_secs(11)).is_empty());
+        assert_eq!(lessor.expired(now + Duration::from_secs(14)), vec![id]);
+
+        lessor.revoke(id);
+        assert_eq!(lessor.keep_alive(id, now), None);
+        assert_eq!(lessor.remaining(id, now), None);
+    }
+
+    #[test]
+    fn promote_test() {
+        let mut lessor = Lessor::new(1);
+        let now = Instant::now();
+        lessor.reset(vec![(1, 10), (2, 10)], now);
+        let later = now + Duration::from_secs(10);
+        lessor.promote(later);
+        assert!(lessor.expired(later).is_empty());
+        assert_eq!(lessor.remaining(1, later), Some(10));
+    }
+}
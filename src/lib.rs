@@ -6,11 +6,12 @@ extern crate failure;
 extern crate fs2;
 extern crate futures;
 extern crate grpcio;
-extern crate indexmap;
 extern crate protobuf;
 extern crate raft;
+extern crate ring;
 extern crate serde;
 extern crate tokio;
+extern crate zstd;
 #[macro_use]
 extern crate lazy_static;
 #[macro_use]
@@ -23,21 +24,41 @@ extern crate itertools;
 #[macro_use]
 extern crate prometheus;
 
+mod batch;
+mod compactor;
+mod crypto;
 mod kv;
+mod lease;
 mod metrics;
 mod node;
+mod proposal;
+mod purge;
+mod recipe;
 mod server;
 mod snap;
+mod storage;
 mod transport;
 mod util;
 mod wal;
+mod watch;
 
 pub mod errors;
 pub mod proto;
 
+/// Offline inspection and repair of the wal of a stopped member
+pub mod wal_tool {
+    pub use wal::tool::*;
+    pub use wal::Position;
+}
+
 pub mod prelude {
-    pub use kv::Store;
-    pub use node::Node;
+    pub use batch::GroupCommit;
+    pub use compactor::AutoCompaction;
+    pub use crypto::Keyring;
+    pub use kv::{prefix_end, Store};
+    pub use node::{Node, NodeOptions};
+    pub use purge::PurgeOptions;
     pub use server::MumServer;
+    pub use wal::{SyncMode, WalOptions};
     //pub use raft_server::RaftServer;
 }
@@ -42,4 +42,47 @@ lazy_static! {
         "mum_server_raft_message_flush_total",
         "Total number of raft messages flushed"
     ).unwrap();
+    pub static ref READ_COUNTER_VEC: IntCounterVec = register_int_counter_vec!(
+        "mum_server_read_total",
+        "Total number of reads served",
+        &["type"]
+    ).unwrap();
+    pub static ref PURGED_FILES_COUNTER_VEC: IntCounterVec = register_int_counter_vec!(
+        "mum_purged_files_total",
+        "Total number of obsolete files purged",
+        &["type"]
+    ).unwrap();
+    pub static ref PURGED_BYTES_COUNTER_VEC: IntCounterVec = register_int_counter_vec!(
+        "mum_purged_bytes_total",
+        "Total bytes reclaimed by purging obsolete files",
+        &["type"]
+    ).unwrap();
+    pub static ref WAL_FSYNC_HISTOGRAM: Histogram = register_histogram!(
+        "mum_wal_fsync_duration_seconds",
+        "Bucketed histogram of wal fsync duration",
+        exponential_buckets(0.0001, 2.0, 20).unwrap()
+    ).unwrap();
+    pub static ref WAL_SYNC_BATCH_HISTOGRAM: Histogram = register_histogram!(
+        "mum_wal_sync_batch_bytes",
+        "Bucketed histogram of the bytes synced by a wal group commit",
+        exponential_buckets(64.0, 2.0, 20).unwrap()
+    ).unwrap();
+    pub static ref WAL_COMPRESSION_BYTES_COUNTER_VEC: IntCounterVec = register_int_counter_vec!(
+        "mum_wal_compression_bytes_total",
+        "Total bytes of the wal records large enough to compress, raw and stored",
+        &["type"]
+    ).unwrap();
+    pub static ref WAL_COMPRESSION_RATIO_HISTOGRAM: Histogram = register_histogram!(
+        "mum_wal_compression_ratio",
+        "Bucketed histogram of the compressed to raw size of the wal records",
+        linear_buckets(0.05, 0.05, 20).unwrap()
+    ).unwrap();
+    pub static ref SNAP_FILES_GAUGE: IntGauge = register_int_gauge!(
+        "mum_snapshot_files",
+        "Number of snapshot files on disk"
+    ).unwrap();
+    pub static ref SNAP_BYTES_GAUGE: IntGauge = register_int_gauge!(
+        "mum_snapshot_bytes",
+        "Total bytes of the snapshot files on disk"
+    ).unwrap();
 }
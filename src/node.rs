@@ -1,42 +1,99 @@
 use std::time::{Duration, Instant};
 
+use batch::{Batch, GroupCommit};
+use byteorder::{BigEndian, ByteOrder};
+use compactor::{AutoCompaction, Compactor};
 use errors::*;
 use futures::sync::mpsc::UnboundedReceiver;
-use futures::Stream;
+use futures::{stream, Stream};
 use grpcio::Environment;
 use kv::Store;
+use lease::Lessor;
+use metrics::*;
+use proposal::{
+    Callback, ConfCallback, LeaseGrantCallback, LeaseKeepAliveCallback, LeaseRevokeCallback,
+    LeaseTtlCallback, PendingReads, Proposals, TxnCallback,
+};
 use proto::mumpb::*;
+use proto::{decode_command, encode_command};
 use protobuf::Message;
+use purge::{PurgeOptions, Purger};
 use raft::is_empty_snap;
 use raft::prelude::*;
-use raft::storage::MemStorage;
+use raft::{ReadState, StateRole, INVALID_ID};
 use snap::Snapshotter;
-use std::path::{Path, PathBuf};
+use std::cmp;
+use std::collections::HashMap;
+use std::ops::Bound;
+use std::path::Path;
 use std::sync::Arc;
+use storage::WalStorage;
 use tokio::run;
 use tokio::timer::Interval;
 use transport::RaftClients;
 use util::create_dir;
-use wal::{wal_exists, Wal};
+use wal::{SyncMode, WalOptions};
+use watch::{WatchHub, WatchId, WatchSender};
 
 const SNAPSHOT_TRIG_COUNT: u64 = 1;
 const SNAPSHOT_CATCH_UP_ENTRIES_N: u64 = 1;
+const PROPOSAL_TIMEOUT_SECS: u64 = 5;
+
+/// Tunables of a member, set from the server command line
+#[derive(Clone, Debug, Default)]
+pub struct NodeOptions {
+    /// Let the leader serve `ReadConsistency::Lease` reads under its lease,
+    /// this enables raft check quorum
+    pub lease_read: bool,
+    /// Let the leader compact the MVCC history periodically
+    pub auto_compaction: Option<AutoCompaction>,
+    /// Delete the wal segments covered by the snapshots in the background
+    pub purge: Option<PurgeOptions>,
+    /// Segment size, sync mode, buffering and encryption of the wal, its
+    /// keyring encrypts the snapshots as well
+    pub wal: WalOptions,
+    /// Shared by the members, the wal of another cluster is refused
+    pub cluster_id: u64,
+}
+
+/// Messages sent from the grpc server to the raft loop
+pub enum Msg {
+    Raft(RaftMessage),
+    Propose { op: OpRequest, cb: Callback },
+    Read { op: OpRequest, cb: Callback },
+    Conf { conf: ConfRequest, cb: ConfCallback },
+    Txn { txn: TxnRequest, cb: TxnCallback },
+    Watch { id: WatchId, req: WatchRequest, tx: WatchSender },
+    Unwatch(WatchId),
+    LeaseGrant { req: LeaseGrantRequest, cb: LeaseGrantCallback },
+    LeaseRevoke { req: LeaseRevokeRequest, cb: LeaseRevokeCallback },
+    LeaseKeepAlive { req: LeaseKeepAliveRequest, cb: LeaseKeepAliveCallback },
+    LeaseTimeToLive { req: LeaseTimeToLiveRequest, cb: LeaseTtlCallback },
+}
 
 pub struct Node {
     id: u64,
+    options: NodeOptions,
 
     applied_index: u64,
     snapshot_index: u64,
     conf_state: Option<ConfState>,
+    leader_id: u64,
 
-    wal_dir: PathBuf,
-    raft_node: RawNode<MemStorage>,
+    raft_node: RawNode<WalStorage>,
     snapshotter: Snapshotter,
     kv_store: Store,
-    wal: Option<Wal>,
-    last_index: u64,
-    rx: Option<UnboundedReceiver<(Option<RaftMessage>, Option<OpRequest>, Option<ConfRequest>)>>,
+    rx: Option<UnboundedReceiver<Msg>>,
     raft_clients: RaftClients,
+    proposals: Proposals<OpResponse>,
+    txns: Proposals<TxnResponse>,
+    reads: PendingReads,
+    compactor: Option<Compactor>,
+    watchers: WatchHub,
+    lessor: Lessor,
+    grants: Proposals<LeaseGrantResponse>,
+    revokes: Proposals<LeaseRevokeResponse>,
+    batch: Batch,
 }
 
 impl Node {
@@ -44,21 +101,28 @@ impl Node {
         id: u64,
         env: Arc<Environment>,
         addrs: Vec<String>,
-        kv_store: Store,
+        mut kv_store: Store,
         wal_dir: P,
         snap_dir: P,
-        rx: UnboundedReceiver<(Option<RaftMessage>, Option<OpRequest>, Option<ConfRequest>)>,
+        rx: UnboundedReceiver<Msg>,
+        options: NodeOptions,
     ) -> Result<Node> {
         let mut cfg = Config::default();
         cfg.id = id;
         cfg.heartbeat_tick = 10;
         cfg.election_tick = cfg.heartbeat_tick * 10;
         cfg.max_inflight_msgs = 10;
+        // a leader lease is only safe if the leader steps down once it
+        // loses the quorum
+        cfg.check_quorum = options.lease_read;
         cfg.validate()?;
         create_dir(&wal_dir)?;
         create_dir(&snap_dir)?;
+        if let Some(ref purge) = options.purge {
+            Purger::new(&wal_dir, &snap_dir, purge.clone()).spawn()?;
+        }
         // peers
-        let mut raft_clients = RaftClients::new(env);
+        let mut raft_clients = RaftClients::new(env, Duration::from_secs(PROPOSAL_TIMEOUT_SECS));
         let peers: Vec<Peer> = addrs
             .iter()
             .enumerate()
@@ -72,65 +136,113 @@ impl Node {
             })
             .collect();
         // snapshot
-        let snapshotter = Snapshotter::new(&snap_dir);
-        let raft_node = RawNode::new(&cfg, MemStorage::default(), peers)?;
+        let snapshotter = Snapshotter::new(&snap_dir, options.wal.keyring.clone());
+        let snap = snapshotter.load()?;
+        if let Some(ref snap) = snap {
+            kv_store.from_snapshot(snap)?;
+        }
+        info!("replaying wal of member {}", id);
+        let storage =
+            WalStorage::open(&wal_dir, snap, id, options.cluster_id, options.wal.clone())?;
+        let snapshot_index = storage.snapshot()?.get_metadata().get_index();
+        let conf_state = storage.initial_state()?.conf_state;
+        // a restarted member learns its peers from the log
+        let restart = storage.last_index()? > 0;
+        let peers = if restart { vec![] } else { peers };
+        let raft_node = RawNode::new(&cfg, storage, peers)?;
 
-        //
-        let mut n = Node {
+        let compactor = options.auto_compaction.clone().map(Compactor::new);
+        // the leases granted after the snapshot are replayed from the log
+        let mut lessor = Lessor::new(id);
+        lessor.reset(kv_store.leases(), Instant::now());
+        Ok(Node {
             id: id,
-            applied_index: 0,
-            snapshot_index: 0,
-            conf_state: None,
-            wal_dir: wal_dir.as_ref().to_path_buf(),
+            options: options,
+            applied_index: snapshot_index,
+            snapshot_index: snapshot_index,
+            conf_state: Some(conf_state),
+            leader_id: 0,
             raft_node: raft_node,
             raft_clients: raft_clients,
             snapshotter: snapshotter,
             kv_store: kv_store,
-            wal: None,
-            last_index: 0,
             rx: Some(rx),
-        };
-        n.reply_wal();
-        Ok(n)
+            proposals: Proposals::new(Duration::from_secs(PROPOSAL_TIMEOUT_SECS)),
+            txns: Proposals::new(Duration::from_secs(PROPOSAL_TIMEOUT_SECS)),
+            reads: PendingReads::new(),
+            compactor: compactor,
+            watchers: WatchHub::new(),
+            lessor: lessor,
+            grants: Proposals::new(Duration::from_secs(PROPOSAL_TIMEOUT_SECS)),
+            revokes: Proposals::new(Duration::from_secs(PROPOSAL_TIMEOUT_SECS)),
+            batch: Batch::new(group_commit(&options.wal.sync)),
+        })
     }
 
     pub fn run(mut self) {
         let rx = self.rx.take().unwrap();
         let t_rx = Interval::new(Instant::now(), Duration::from_millis(100));
+        // checks whether the unsynced batch is due, only the batch mode holds
+        // one past the ready
+        let b_rx: Box<Stream<Item = (Option<Msg>, Option<()>), Error = ()> + Send> =
+            match self.options.wal.sync {
+                SyncMode::Batch(ref group_commit) => {
+                    // the interval can't be zero, a zero delay is due at once anyway
+                    let delay = cmp::max(group_commit.max_delay, Duration::from_millis(1));
+                    Box::new(
+                        Interval::new(Instant::now(), delay)
+                            .map(|_| (None, None))
+                            .map_err(|_| ()),
+                    )
+                }
+                _ => Box::new(stream::empty()),
+            };
         let mut conf_id = 0;
 
         let f = rx
             .map(|x| (Some(x), None))
             .map_err(|_| ())
             .select(t_rx.map(|_| (None, Some(()))).map_err(|_| ()))
+            .select(b_rx)
             .for_each(move |x| {
                 match x {
-                    (Some(kv), None) => match kv {
-                        (Some(mut raft), None, None) => {
+                    (Some(msg), None) => match msg {
+                        Msg::Raft(mut raft) => {
                             if let Err(e) = self.raft_node.step(raft.take_message()) {
                                 error!("raft step error {}", e);
                             }
                         }
-                        (None, Some(op), None) => {
-                            if let Err(e) =
-                                self.raft_node.propose(vec![], op.write_to_bytes().unwrap())
-                            {
-                                error!("raft propose error {}", e);
-                            }
-                        }
-                        (None, None, Some(mut conf)) => {
-                            let mut change = conf.take_change();
+                        Msg::Propose { op, cb } => self.propose(op, cb),
+                        Msg::Read { op, cb } => self.read_index(op, cb),
+                        Msg::Txn { txn, cb } => self.propose_txn(txn, cb),
+                        Msg::Conf { conf, cb } => {
                             conf_id += 1;
-                            change.set_id(conf_id);
-                            if let Err(e) = self.raft_node.propose_conf_change(vec![], change) {
-                                error!("raft propose conf change error {}", e);
-                            }
+                            self.propose_conf(conf, conf_id, cb);
+                        }
+                        Msg::Watch { id, req, tx } => {
+                            self.watchers.watch(id, req, tx, &self.kv_store)
                         }
-                        _ => (),
+                        Msg::Unwatch(id) => self.watchers.unwatch(id),
+                        Msg::LeaseGrant { req, cb } => self.propose_lease_grant(req, cb),
+                        Msg::LeaseRevoke { req, cb } => self.propose_lease_revoke(req, cb),
+                        Msg::LeaseKeepAlive { req, cb } => self.lease_keep_alive(req, cb),
+                        Msg::LeaseTimeToLive { req, cb } => self.lease_time_to_live(req, cb),
                     },
                     (None, Some(())) => {
                         self.raft_node.tick();
+                        self.raft_node.mut_store().wl().maybe_sync().unwrap();
+                        for id in self.proposals.expire(Instant::now()) {
+                            self.reads.remove(id);
+                        }
+                        self.txns.expire(Instant::now());
+                        self.grants.expire(Instant::now());
+                        self.revokes.expire(Instant::now());
+                        self.maybe_auto_compact();
+                        self.revoke_expired_leases();
+                        self.watchers
+                            .progress(Instant::now(), self.kv_store.revision());
                     }
+                    (None, None) => self.maybe_commit_batch(),
                     _ => (),
                 }
 
@@ -142,12 +254,334 @@ impl Node {
         run(f);
     }
 
-    fn save_snap(&mut self, snap: &Snapshot) -> Result<()> {
-        self.snapshotter.save(&snap)?;
-        self.wal
-            .as_mut()
-            .unwrap()
-            .release_lock_to(snap.get_metadata().get_index())
+    fn is_leader(&self) -> bool {
+        self.raft_node.raft.state == StateRole::Leader
+    }
+
+    /// Returns the leader to forward a request to, or a `NotLeader` error
+    /// which lets the client redirect by itself
+    fn forward_target(&self, forwarded: bool) -> Result<u64> {
+        let leader_id = self.raft_node.raft.leader_id;
+        match self.raft_clients.addr(leader_id) {
+            // never forward twice, the leader may have changed in between
+            Some(_) if !forwarded => Ok(leader_id),
+            _ => Err(self.not_leader()),
+        }
+    }
+
+    fn not_leader(&self) -> Error {
+        let leader_id = self.raft_node.raft.leader_id;
+        Error::NotLeader {
+            leader_id: leader_id,
+            addr: self
+                .raft_clients
+                .addr(leader_id)
+                .unwrap_or_default()
+                .to_owned(),
+        }
+    }
+
+    fn propose(&mut self, mut op: OpRequest, cb: Callback) {
+        if !self.is_leader() {
+            match self.forward_target(op.get_header().get_forwarded()) {
+                Ok(leader_id) => {
+                    op.mut_header().set_forwarded(true);
+                    self.raft_clients.forward_op(leader_id, &op, cb);
+                }
+                Err(e) => {
+                    let _ = cb.send(Err(e));
+                }
+            }
+            return;
+        }
+        let id = self.proposals.register(cb);
+        {
+            let header = op.mut_header();
+            header.set_node_id(self.id);
+            header.set_id(id);
+        }
+        let mut cmd = RaftCommand::new();
+        cmd.set_op(op);
+        match self.propose_command(&cmd) {
+            Ok((term, index)) => self.proposals.proposed(id, term, index),
+            Err(e) => self.proposals.fail(id, e),
+        }
+    }
+
+    fn propose_txn(&mut self, mut txn: TxnRequest, cb: TxnCallback) {
+        if !self.is_leader() {
+            match self.forward_target(txn.get_header().get_forwarded()) {
+                Ok(leader_id) => {
+                    txn.mut_header().set_forwarded(true);
+                    self.raft_clients.forward_txn(leader_id, &txn, cb);
+                }
+                Err(e) => {
+                    let _ = cb.send(Err(e));
+                }
+            }
+            return;
+        }
+        let id = self.txns.register(cb);
+        {
+            let header = txn.mut_header();
+            header.set_node_id(self.id);
+            header.set_id(id);
+        }
+        let mut cmd = RaftCommand::new();
+        cmd.set_txn(txn);
+        match self.propose_command(&cmd) {
+            Ok((term, index)) => self.txns.proposed(id, term, index),
+            Err(e) => self.txns.fail(id, e),
+        }
+    }
+
+    fn propose_lease_grant(&mut self, mut req: LeaseGrantRequest, cb: LeaseGrantCallback) {
+        if req.get_ttl() == 0 {
+            // it would expire at once
+            let _ = cb.send(Err(Error::ZeroLeaseTtl));
+            return;
+        }
+        if !self.is_leader() {
+            match self.forward_target(req.get_header().get_forwarded()) {
+                Ok(leader_id) => {
+                    req.mut_header().set_forwarded(true);
+                    self.raft_clients.forward_lease_grant(leader_id, &req, cb);
+                }
+                Err(e) => {
+                    let _ = cb.send(Err(e));
+                }
+            }
+            return;
+        }
+        if req.get_id() == 0 {
+            let id = self.lessor.next_id();
+            req.set_id(id);
+        }
+        let id = self.grants.register(cb);
+        {
+            let header = req.mut_header();
+            header.set_node_id(self.id);
+            header.set_id(id);
+        }
+        let mut cmd = RaftCommand::new();
+        cmd.set_lease_grant(req);
+        match self.propose_command(&cmd) {
+            Ok((term, index)) => self.grants.proposed(id, term, index),
+            Err(e) => self.grants.fail(id, e),
+        }
+    }
+
+    fn propose_lease_revoke(&mut self, mut req: LeaseRevokeRequest, cb: LeaseRevokeCallback) {
+        if !self.is_leader() {
+            match self.forward_target(req.get_header().get_forwarded()) {
+                Ok(leader_id) => {
+                    req.mut_header().set_forwarded(true);
+                    self.raft_clients.forward_lease_revoke(leader_id, &req, cb);
+                }
+                Err(e) => {
+                    let _ = cb.send(Err(e));
+                }
+            }
+            return;
+        }
+        let id = self.revokes.register(cb);
+        {
+            let header = req.mut_header();
+            header.set_node_id(self.id);
+            header.set_id(id);
+        }
+        let mut cmd = RaftCommand::new();
+        cmd.set_lease_revoke(req);
+        match self.propose_command(&cmd) {
+            Ok((term, index)) => self.revokes.proposed(id, term, index),
+            Err(e) => self.revokes.fail(id, e),
+        }
+    }
+
+    /// Renews the lease, only the leader tracks the deadlines which matter
+    /// Only served by the leader, the stream is not forwarded. The NotLeader
+    /// error tells the client where to keep the lease alive
+    fn lease_keep_alive(&mut self, req: LeaseKeepAliveRequest, cb: LeaseKeepAliveCallback) {
+        if !self.is_leader() {
+            let _ = cb.send(Err(self.not_leader()));
+            return;
+        }
+        let mut resp = LeaseKeepAliveResponse::new();
+        resp.mut_header().set_revision(self.kv_store.revision());
+        resp.set_id(req.get_id());
+        resp.set_ttl(
+            self.lessor
+                .keep_alive(req.get_id(), Instant::now())
+                .unwrap_or(0),
+        );
+        let _ = cb.send(Ok(resp));
+    }
+
+    /// Served by any member, the remaining time to live is only accurate on
+    /// the leader
+    fn lease_time_to_live(&mut self, req: LeaseTimeToLiveRequest, cb: LeaseTtlCallback) {
+        let mut resp = LeaseTimeToLiveResponse::new();
+        resp.mut_header().set_revision(self.kv_store.revision());
+        resp.set_id(req.get_id());
+        match self.kv_store.lease(req.get_id()) {
+            Some((ttl, keys)) => {
+                let remaining = self
+                    .lessor
+                    .remaining(req.get_id(), Instant::now())
+                    .unwrap_or(ttl);
+                resp.set_ttl(remaining as i64);
+                resp.set_granted_ttl(ttl);
+                if req.get_keys() {
+                    resp.set_keys(keys.into());
+                }
+            }
+            None => resp.set_ttl(-1),
+        }
+        let _ = cb.send(Ok(resp));
+    }
+
+    /// Proposes the revocation of the expired leases, only the leader does so
+    /// and every member deletes the keys once it is applied
+    fn revoke_expired_leases(&mut self) {
+        if !self.is_leader() {
+            return;
+        }
+        for id in self.lessor.expired(Instant::now()) {
+            info!("lease {} expired", id);
+            let mut cmd = RaftCommand::new();
+            {
+                let req = cmd.mut_lease_revoke();
+                req.set_id(id);
+                // no client waits for it
+                req.mut_header().set_node_id(INVALID_ID);
+            }
+            if let Err(e) = self.propose_command(&cmd) {
+                error!("failed to propose revocation of lease {}: {}", id, e);
+            }
+        }
+    }
+
+    /// Appends the command to the log, returns the term and index of its
+    /// entry
+    fn propose_command(&mut self, cmd: &RaftCommand) -> Result<(u64, u64)> {
+        let data = encode_command(cmd)?;
+        self.raft_node.propose(vec![], data).map_err(|e| {
+            error!("raft propose error {}", e);
+            Error::from(e)
+        })?;
+        let raft = &self.raft_node.raft;
+        Ok((raft.term, raft.raft_log.last_index()))
+    }
+
+    fn propose_conf(&mut self, mut conf: ConfRequest, conf_id: u64, cb: ConfCallback) {
+        if !self.is_leader() {
+            match self.forward_target(conf.get_header().get_forwarded()) {
+                Ok(leader_id) => {
+                    conf.mut_header().set_forwarded(true);
+                    self.raft_clients.forward_conf(leader_id, &conf, cb);
+                }
+                Err(e) => {
+                    let _ = cb.send(Err(e));
+                }
+            }
+            return;
+        }
+        let mut change = conf.take_change();
+        change.set_id(conf_id);
+        let res = match self.raft_node.propose_conf_change(vec![], change) {
+            Ok(_) => Ok(ConfResponse::new()),
+            Err(e) => {
+                error!("raft propose conf change error {}", e);
+                Err(e.into())
+            }
+        };
+        let _ = cb.send(res);
+    }
+
+    /// Proposes a compaction once the history grows beyond the retention,
+    /// only the leader does so to avoid duplicated proposals
+    fn maybe_auto_compact(&mut self) {
+        if !self.is_leader() {
+            return;
+        }
+        let revision = match self.compactor {
+            Some(ref mut c) => c.poll(
+                Instant::now(),
+                self.kv_store.revision(),
+                self.kv_store.compacted(),
+            ),
+            None => return,
+        };
+        if let Some(revision) = revision {
+            info!("auto compacting the history to revision {}", revision);
+            let mut cmd = RaftCommand::new();
+            {
+                let op = cmd.mut_op();
+                op.set_field_type(Op::Compact);
+                op.set_revision(revision);
+                // no client waits for it
+                op.mut_header().set_node_id(INVALID_ID);
+            }
+            if let Err(e) = self.propose_command(&cmd) {
+                error!("failed to propose compaction {}", e);
+            }
+        }
+    }
+
+    fn read_index(&mut self, op: OpRequest, cb: Callback) {
+        let id = self.proposals.register(cb);
+        let mut ctx = vec![0; 8];
+        BigEndian::write_u64(&mut ctx, id);
+        let lease = self.options.lease_read
+            && op.get_consistency() == ReadConsistency::Lease
+            && self.raft_node.raft.state == StateRole::Leader;
+        self.reads.insert(id, op, lease);
+        if lease {
+            if let Some(index) = self.lease_read_index() {
+                self.reads.confirm(id, index);
+                return self.serve_reads();
+            }
+        }
+        self.raft_node.read_index(ctx);
+    }
+
+    /// Returns the read index the leader confirms by itself while it holds
+    /// the lease, without a heartbeat round. The check quorum steps it down
+    /// once it stops hearing from a quorum, and it knows the entries of the
+    /// former leaders committed once one of its term is.
+    fn lease_read_index(&self) -> Option<u64> {
+        let raft = &self.raft_node.raft;
+        let committed = raft.raft_log.committed;
+        if raft.state != StateRole::Leader
+            || !raft.check_quorum
+            || raft.raft_log.term(committed).unwrap_or(0) != raft.term
+        {
+            return None;
+        }
+        Some(committed)
+    }
+
+    fn on_read_states(&mut self, states: &[ReadState]) {
+        for rs in states {
+            if rs.request_ctx.len() != 8 {
+                warn!("unexpected read index context {:?}", rs.request_ctx);
+                continue;
+            }
+            self.reads
+                .confirm(BigEndian::read_u64(&rs.request_ctx), rs.index);
+        }
+    }
+
+    /// Serves the reads whose read index has been applied
+    fn serve_reads(&mut self) {
+        for (id, op, lease) in self.reads.take_ready(self.applied_index) {
+            match self.kv_store.read(&op) {
+                Ok(resp) => self.proposals.complete(id, resp),
+                Err(e) => self.proposals.fail(id, e),
+            }
+            let ty = if lease { "lease" } else { "read_index" };
+            READ_COUNTER_VEC.with_label_values(&[ty]).inc();
+        }
     }
 
     fn ents_to_apply(&self, ents: &mut Vec<Entry>) {
@@ -168,25 +602,29 @@ impl Node {
     }
 
     fn publish_entries(&mut self, ents: &Vec<Entry>) -> Result<()> {
+        let mut events = vec![];
         for entry in ents {
             match entry.get_entry_type() {
                 EntryType::EntryNormal => {
-                    let mut op = OpRequest::new();
-                    op.merge_from_bytes(entry.get_data()).unwrap();
-                    match op.field_type {
-                        Op::Set => {
-                            self.kv_store.set(op.key, op.value);
-                        }
-                        Op::Del => {
-                            self.kv_store.delete(&op.key);
+                    // the new leader appends an empty entry on election
+                    if !entry.get_data().is_empty() {
+                        let mut cmd = decode_command(entry.get_data())?;
+                        if cmd.has_txn() {
+                            self.apply_txn(entry.get_index(), cmd.take_txn(), &mut events)?;
+                        } else if cmd.has_lease_grant() {
+                            self.apply_lease_grant(entry.get_index(), cmd.take_lease_grant());
+                        } else if cmd.has_lease_revoke() {
+                            let req = cmd.take_lease_revoke();
+                            self.apply_lease_revoke(entry.get_index(), req, &mut events)?;
+                        } else {
+                            self.apply_op(entry.get_index(), cmd.take_op(), &mut events)?;
                         }
-                        _ => (),
                     }
                 }
                 EntryType::EntryConfChange => {
                     let mut change = ConfChange::new();
                     change.merge_from_bytes(entry.get_data())?;
-                    self.raft_node.apply_conf_change(&change);
+                    self.conf_state = Some(self.raft_node.apply_conf_change(&change));
                     match change.get_change_type() {
                         ConfChangeType::AddNode => {
                             if change.get_context().len() > 0 {
@@ -208,57 +646,175 @@ impl Node {
             }
             self.applied_index = entry.get_index();
         }
+        let applied: HashMap<u64, u64> = ents
+            .iter()
+            .map(|entry| (entry.get_index(), entry.get_term()))
+            .collect();
+        self.proposals.drop_overwritten(&applied);
+        self.txns.drop_overwritten(&applied);
+        self.grants.drop_overwritten(&applied);
+        self.revokes.drop_overwritten(&applied);
+        self.watchers.notify(&events, self.kv_store.revision());
         Ok(())
     }
 
-    fn load_snapshot(&mut self) -> Option<Snapshot> {
-        match self.snapshotter.load() {
-            Ok(v) => v,
-            Err(err) => {
-                panic!("error loading snapshot {}", err);
+    /// Applies a write at the revision of its raft index, the changes of
+    /// keys are appended to `events`
+    fn apply_op(&mut self, index: u64, mut op: OpRequest, events: &mut Vec<Event>) -> Result<()> {
+        let res = match op.field_type {
+            Op::Set => {
+                let value = op.take_value();
+                self.kv_store
+                    .set_with_lease(op.get_key().to_vec(), value, op.get_lease(), index)
+            }
+            Op::Del => Ok(self.kv_store.delete(op.get_key(), index)),
+            // fails on every member alike, the store stays the same
+            Op::Compact => self.kv_store.compact(op.get_revision()).map(|_| None),
+            _ => Ok(None),
+        };
+        match op.field_type {
+            Op::Set | Op::Del => events.extend(self.key_events(op.get_key(), index)?),
+            _ => (),
+        }
+        // only the node which proposed the op has a client waiting for it
+        if op.get_header().get_node_id() != self.id {
+            if let Err(e) = res {
+                debug!("failed to apply op at index {}: {}", index, e);
+            }
+            return Ok(());
+        }
+        let id = op.get_header().get_id();
+        match res {
+            Ok(prev) => {
+                let mut resp = OpResponse::new();
+                if let Some(kv) = prev {
+                    resp.set_prev_kv(kv);
+                }
+                resp.mut_header().set_revision(self.kv_store.revision());
+                self.proposals.complete(id, resp);
             }
+            Err(e) => self.proposals.fail(id, e),
         }
+        Ok(())
     }
 
-    fn open_wal(&mut self, snap: &Option<Snapshot>) {
-        let wal = if wal_exists(&self.wal_dir) && snap.is_some() {
-            let md = snap.as_ref().unwrap().get_metadata();
-            Wal::open_at(&self.wal_dir, (md.term, md.index))
-        } else {
-            Wal::create(&self.wal_dir)
-        };
-        self.wal = Some(wal.expect("wal dir opened failed"));
-    }
-
-    fn reply_wal(&mut self) {
-        info!("replayint wal of member {}", self.id);
-        let snap = self.load_snapshot();
-        self.open_wal(&snap);
-        let (hs, ents) = self
-            .wal
-            .as_mut()
-            .unwrap()
-            .read_all()
-            .expect("failed to read wal");
-        if snap.is_some() {
-            self.raft_node
-                .mut_store()
-                .wl()
-                .apply_snapshot(snap.as_ref().unwrap().clone())
-                .unwrap();
-            debug!("get snapshot");
-            self.kv_store.from_snapshot(snap.as_ref().unwrap()).unwrap();
+    /// Applies the txn at the revision of its raft index, the changes of
+    /// keys are appended to `events`
+    fn apply_txn(&mut self, index: u64, txn: TxnRequest, events: &mut Vec<Event>) -> Result<()> {
+        let res = self.kv_store.txn(&txn, index);
+        if let Ok(ref resp) = res {
+            let ops = if resp.get_succeeded() {
+                txn.get_success()
+            } else {
+                txn.get_failure()
+            };
+            let mut keys: Vec<&[u8]> = ops
+                .iter()
+                .filter(|op| op.field_type == Op::Set || op.field_type == Op::Del)
+                .map(|op| op.get_key())
+                .collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                events.extend(self.key_events(key, index)?);
+            }
+        }
+        let header = txn.get_header();
+        if header.get_node_id() != self.id {
+            if let Err(e) = res {
+                debug!("failed to apply txn at index {}: {}", index, e);
+            }
+            return Ok(());
         }
-        self.raft_node.mut_store().wl().set_hardstate(hs);
-        self.raft_node.mut_store().wl().append(&ents).unwrap();
-        if ents.len() > 0 {
-            self.last_index = ents.last().unwrap().get_index();
+        match res {
+            Ok(resp) => self.txns.complete(header.get_id(), resp),
+            Err(e) => self.txns.fail(header.get_id(), e),
         }
+        Ok(())
+    }
 
-        let snap = self.raft_node.get_store().snapshot().unwrap();
-        self.conf_state = Some(snap.get_metadata().get_conf_state().clone());
-        self.snapshot_index = snap.get_metadata().get_index();
-        self.applied_index = snap.get_metadata().get_index();
+    fn apply_lease_grant(&mut self, index: u64, req: LeaseGrantRequest) {
+        let res = self.kv_store.grant(req.get_id(), req.get_ttl());
+        if res.is_ok() {
+            self.lessor
+                .grant(req.get_id(), req.get_ttl(), Instant::now());
+        }
+        let header = req.get_header();
+        if header.get_node_id() != self.id {
+            if let Err(e) = res {
+                debug!("failed to apply lease grant at index {}: {}", index, e);
+            }
+            return;
+        }
+        match res {
+            Ok(_) => {
+                let mut resp = LeaseGrantResponse::new();
+                resp.mut_header().set_revision(self.kv_store.revision());
+                resp.set_id(req.get_id());
+                resp.set_ttl(req.get_ttl());
+                self.grants.complete(header.get_id(), resp);
+            }
+            Err(e) => self.grants.fail(header.get_id(), e),
+        }
+    }
+
+    /// Revokes the lease and deletes its keys at the revision of its raft
+    /// index, the deletions are appended to `events`
+    fn apply_lease_revoke(
+        &mut self,
+        index: u64,
+        req: LeaseRevokeRequest,
+        events: &mut Vec<Event>,
+    ) -> Result<()> {
+        let res = self.kv_store.revoke(req.get_id(), index);
+        if let Ok(ref keys) = res {
+            self.lessor.revoke(req.get_id());
+            for key in keys {
+                events.extend(self.key_events(key, index)?);
+            }
+        }
+        let header = req.get_header();
+        if header.get_node_id() != self.id {
+            if let Err(e) = res {
+                debug!("failed to apply lease revoke at index {}: {}", index, e);
+            }
+            return Ok(());
+        }
+        match res {
+            Ok(_) => {
+                let mut resp = LeaseRevokeResponse::new();
+                resp.mut_header().set_revision(self.kv_store.revision());
+                self.revokes.complete(header.get_id(), resp);
+            }
+            Err(e) => self.revokes.fail(header.get_id(), e),
+        }
+        Ok(())
+    }
+
+    /// Returns the changes of the key at `index`, empty if nothing was
+    /// deleted
+    fn key_events(&self, key: &[u8], index: u64) -> Result<Vec<Event>> {
+        let key = Bound::Included(key);
+        self.kv_store.events((key, key), index)
+    }
+
+    fn on_leader_change(&mut self, leader_id: u64) {
+        if leader_id == self.leader_id {
+            return;
+        }
+        info!(
+            "member {} observed leader change from {} to {}",
+            self.id, self.leader_id, leader_id
+        );
+        // the proposals still pending may be committed by the new leader,
+        // they are failed only once their entries are overwritten
+        if leader_id == self.id {
+            // the clients kept their leases alive on the previous leader
+            self.lessor.promote(Instant::now());
+        }
+        // raft drops the pending read index requests as well
+        self.reads.clear();
+        self.leader_id = leader_id;
     }
 
     fn publish_snapshot(&mut self, snap: &Snapshot) {
@@ -271,7 +827,11 @@ impl Node {
                 self.snapshot_index
             );
         }
+        let revision = self.kv_store.revision();
         self.kv_store.from_snapshot(snap).unwrap();
+        // the watchers missed the writes within the snapshot
+        self.watchers.on_snapshot(revision + 1, &self.kv_store);
+        self.lessor.reset(self.kv_store.leases(), Instant::now());
         self.conf_state = Some(snap.get_metadata().get_conf_state().clone());
         self.snapshot_index = idx;
         self.applied_index = idx;
@@ -300,45 +860,30 @@ impl Node {
                 .clone()
         };
 
+        // the snapshot must be durable before the entries it covers are
+        // released from the wal
+        self.snapshotter.save(&snap).unwrap();
+
         let compact_index = if self.applied_index > SNAPSHOT_CATCH_UP_ENTRIES_N {
             self.applied_index - SNAPSHOT_CATCH_UP_ENTRIES_N
         } else {
             1
         };
-        {
-            self.raft_node
-                .mut_store()
-                .wl()
-                .compact(compact_index)
-                .unwrap();
+        if let Err(e) = self.raft_node.mut_store().wl().compact(compact_index) {
+            warn!("failed to compact log at index {}: {}", compact_index, e);
+        } else {
+            info!("compacted log at index {}", compact_index);
         }
-        self.save_snap(&snap).unwrap();
-        info!("compacted log at index {}", compact_index);
         self.snapshot_index = self.applied_index;
     }
 
     fn on_ready(&mut self) {
         let mut ready = self.raft_node.ready();
-        // save to wal
-        self.wal
-            .as_mut()
-            .unwrap()
-            .insert(ready.hs.clone(), &ready.entries, ready.must_sync)
-            .unwrap();
-        // Append entries to the Raft log
-        self.raft_node
-            .mut_store()
-            .wl()
-            .append(&ready.entries)
-            .unwrap();
-        if let Some(ref hs) = ready.hs {
-            // Raft HardState changed, and we need to persist it.
-            self.raft_node.mut_store().wl().set_hardstate(hs.clone());
-        }
-
-        // handle snapshot
+        // handle snapshot, the entries of this ready follow it
         if !is_empty_snap(&ready.snapshot) {
-            self.save_snap(&ready.snapshot).unwrap();
+            // the held entries precede the snapshot
+            self.commit_batch();
+            self.snapshotter.save(&ready.snapshot).unwrap();
             self.raft_node
                 .mut_store()
                 .wl()
@@ -347,25 +892,78 @@ impl Node {
             self.publish_snapshot(&ready.snapshot);
         }
 
+        // persist the hard state and append entries to the Raft log, the
+        // messages and committed entries wait for the sync
+        self.raft_node
+            .mut_store()
+            .wl()
+            .save(ready.hs.clone(), &ready.entries, ready.must_sync)
+            .unwrap();
+        let msgs = ready.messages.drain(..).collect();
+        let committed_entries = ready.committed_entries.take().unwrap_or_default();
+        let unsynced = self.raft_node.get_store().rl().unsynced() > 0;
+        self.batch
+            .push(msgs, committed_entries, unsynced, Instant::now());
+
+        if let Some(leader_id) = ready.ss.as_ref().map(|ss| ss.leader_id) {
+            self.on_leader_change(leader_id);
+        }
+
+        self.on_read_states(&ready.read_states);
+        self.maybe_commit_batch();
+        self.serve_reads();
+
+        // Advance the Raft
+        self.raft_node.advance(ready);
+    }
+
+    /// Commits the batch once it is due, or right away if nothing it holds
+    /// waits for a sync
+    fn maybe_commit_batch(&mut self) {
+        let unsynced = self.raft_node.get_store().rl().unsynced();
+        if unsynced > 0 && !self.batch.is_due(unsynced, Instant::now()) {
+            return;
+        }
+        self.commit_batch();
+    }
+
+    /// Syncs the wal with a single fsync, then sends the held messages and
+    /// applies the held committed entries
+    fn commit_batch(&mut self) {
+        // a ready may persist entries or the hard state without holding any
+        // messages or committed entries
         {
-            // send msgs
-            let msgs = ready.messages.drain(..);
-            for msg in msgs {
-                let mut r_msg = RaftMessage::new();
-                r_msg.set_message(msg);
-                self.raft_clients.send(r_msg).unwrap();
+            let mut store = self.raft_node.mut_store().wl();
+            if store.unsynced() > 0 {
+                store.sync().unwrap();
             }
-            //self.raft_clients.flush();
         }
+        if self.batch.is_empty() {
+            return;
+        }
+        let (msgs, mut committed_entries) = self.batch.take();
+        for msg in msgs {
+            let mut r_msg = RaftMessage::new();
+            r_msg.set_message(msg);
+            self.raft_clients.send(r_msg).unwrap();
+        }
+        //self.raft_clients.flush();
 
         // handle commited entries
-        if let Some(mut committed_entries) = ready.committed_entries.take() {
+        if !committed_entries.is_empty() {
             self.ents_to_apply(&mut committed_entries);
             self.publish_entries(&committed_entries).unwrap();
+            self.serve_reads();
+            self.maybe_trigger_snapshot();
         }
+    }
+}
 
-        self.maybe_trigger_snapshot();
-        // Advance the Raft
-        self.raft_node.advance(ready);
+/// Limits of the batch the effects of the readies are held in, only the
+/// batch mode holds them past the ready
+fn group_commit(sync: &SyncMode) -> GroupCommit {
+    match *sync {
+        SyncMode::Batch(ref group_commit) => group_commit.clone(),
+        _ => GroupCommit::default(),
     }
 }
@@ -0,0 +1,233 @@
+use errors::*;
+use futures::sync::oneshot::Sender;
+use proto::mumpb::{
+    ConfResponse, LeaseGrantResponse, LeaseKeepAliveResponse, LeaseRevokeResponse,
+    LeaseTimeToLiveResponse, OpRequest, OpResponse, TxnResponse,
+};
+use std::collections::HashMap;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+pub type Callback = Sender<Result<OpResponse>>;
+pub type ConfCallback = Sender<Result<ConfResponse>>;
+pub type TxnCallback = Sender<Result<TxnResponse>>;
+pub type LeaseGrantCallback = Sender<Result<LeaseGrantResponse>>;
+pub type LeaseRevokeCallback = Sender<Result<LeaseRevokeResponse>>;
+pub type LeaseKeepAliveCallback = Sender<Result<LeaseKeepAliveResponse>>;
+pub type LeaseTtlCallback = Sender<Result<LeaseTimeToLiveResponse>>;
+
+struct Proposal<T> {
+    deadline: Instant,
+    cb: Sender<Result<T>>,
+    // (term, index) of its entry once appended to the log
+    entry: Option<(u64, u64)>,
+}
+
+/// Proposals made by this node which are waiting to be applied
+pub struct Proposals<T> {
+    next_id: u64,
+    timeout: Duration,
+    pending: HashMap<u64, Proposal<T>>,
+}
+
+impl<T> Proposals<T> {
+    pub fn new(timeout: Duration) -> Proposals<T> {
+        // seed ids by wall clock, so a restarted node never reuses the ids
+        // of proposals still sitting in its log
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::from_secs(0));
+        Proposals {
+            next_id: (now.as_secs() << 32) | u64::from(now.subsec_nanos()),
+            timeout: timeout,
+            pending: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, cb: Sender<Result<T>>) -> u64 {
+        self.next_id += 1;
+        self.pending.insert(
+            self.next_id,
+            Proposal {
+                deadline: Instant::now() + self.timeout,
+                cb: cb,
+                entry: None,
+            },
+        );
+        self.next_id
+    }
+
+    pub fn complete(&mut self, id: u64, resp: T) {
+        if let Some(p) = self.pending.remove(&id) {
+            // the client may have gone away
+            let _ = p.cb.send(Ok(resp));
+        }
+    }
+
+    pub fn fail(&mut self, id: u64, err: Error) {
+        if let Some(p) = self.pending.remove(&id) {
+            let _ = p.cb.send(Err(err));
+        }
+    }
+
+    /// Records the entry the proposal was appended to the log as
+    pub fn proposed(&mut self, id: u64, term: u64, index: u64) {
+        if let Some(p) = self.pending.get_mut(&id) {
+            p.entry = Some((term, index));
+        }
+    }
+
+    /// Fails the proposals whose entry was overwritten by a new leader, an
+    /// entry of another term was applied at its index. The others are still
+    /// pending across a leader change, they may be applied yet
+    pub fn drop_overwritten(&mut self, applied: &HashMap<u64, u64>) {
+        let dropped: Vec<u64> = self
+            .pending
+            .iter()
+            .filter(|&(_, p)| match p.entry {
+                Some((term, index)) => applied.get(&index).map_or(false, |t| *t != term),
+                None => false,
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        for id in dropped {
+            warn!("proposal {} overwritten by a new leader", id);
+            self.fail(id, Error::ProposalDropped);
+        }
+    }
+
+    /// Fails every proposal whose deadline has passed, returns their ids
+    pub fn expire(&mut self, now: Instant) -> Vec<u64> {
+        let expired: Vec<u64> = self
+            .pending
+            .iter()
+            .filter(|&(_, p)| p.deadline <= now)
+            .map(|(id, _)| *id)
+            .collect();
+        for id in &expired {
+            warn!("proposal {} timeout", id);
+            self.fail(*id, Error::ProposalTimeout);
+        }
+        expired
+    }
+}
+
+/// Linearizable reads waiting for their read index to be applied
+pub struct PendingReads {
+    // request id -> (read op, read index once raft confirmed it, lease read)
+    reads: HashMap<u64, (OpRequest, Option<u64>, bool)>,
+}
+
+impl PendingReads {
+    pub fn new() -> PendingReads {
+        PendingReads {
+            reads: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, id: u64, op: OpRequest, lease: bool) {
+        self.reads.insert(id, (op, None, lease));
+    }
+
+    pub fn confirm(&mut self, id: u64, index: u64) {
+        if let Some(read) = self.reads.get_mut(&id) {
+            read.1 = Some(index);
+        }
+    }
+
+    pub fn remove(&mut self, id: u64) {
+        self.reads.remove(&id);
+    }
+
+    pub fn clear(&mut self) {
+        self.reads.clear();
+    }
+
+    /// Takes the reads whose read index is not beyond `applied_index`
+    pub fn take_ready(&mut self, applied_index: u64) -> Vec<(u64, OpRequest, bool)> {
+        let ready: Vec<u64> = self
+            .reads
+            .iter()
+            .filter(|&(_, &(_, index, _))| index.map_or(false, |i| i <= applied_index))
+            .map(|(id, _)| *id)
+            .collect();
+        ready
+            .into_iter()
+            .map(|id| {
+                let (op, _, lease) = self.reads.remove(&id).unwrap();
+                (id, op, lease)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::sync::oneshot::channel;
+    use futures::Future;
+
+    #[test]
+    fn complete_test() {
+        let mut proposals = Proposals::new(Duration::from_secs(10));
+        let (cb, rx) = channel();
+        let id = proposals.register(cb);
+        let mut resp = OpResponse::new();
+        resp.mut_prev_kv().set_key(vec![1u8]);
+        proposals.complete(id, resp.clone());
+        assert_eq!(rx.wait().unwrap().unwrap(), resp);
+    }
+
+    #[test]
+    fn expire_test() {
+        let mut proposals = Proposals::new(Duration::from_secs(0));
+        let (cb1, rx1) = channel();
+        let (cb2, rx2) = channel();
+        let id1 = proposals.register(cb1);
+        let id2 = proposals.register(cb2);
+        assert!(id1 != id2);
+        proposals.expire(Instant::now());
+        match rx1.wait().unwrap() {
+            Err(Error::ProposalTimeout) => (),
+            _ => panic!("expected timeout"),
+        }
+        assert!(rx2.wait().unwrap().is_err());
+    }
+
+    #[test]
+    fn overwritten_test() {
+        let mut proposals = Proposals::new(Duration::from_secs(10));
+        let (cb1, rx1) = channel();
+        let (cb2, _rx2) = channel();
+        let id1 = proposals.register(cb1);
+        let id2 = proposals.register(cb2);
+        proposals.proposed(id1, 2, 5);
+        proposals.proposed(id2, 2, 6);
+        let mut applied = HashMap::new();
+        applied.insert(5, 3);
+        applied.insert(6, 2);
+        proposals.drop_overwritten(&applied);
+        match rx1.wait().unwrap() {
+            Err(Error::ProposalDropped) => (),
+            _ => panic!("expected dropped proposal"),
+        }
+        // its own entry, completed once applied
+        assert_eq!(proposals.pending.keys().collect::<Vec<_>>(), vec![&id2]);
+    }
+
+    #[test]
+    fn pending_reads_test() {
+        let mut reads = PendingReads::new();
+        reads.insert(1, OpRequest::new(), false);
+        reads.insert(2, OpRequest::new(), true);
+        reads.insert(3, OpRequest::new(), false);
+        reads.confirm(1, 5);
+        reads.confirm(2, 7);
+        assert!(reads.take_ready(4).is_empty());
+        let ready = reads.take_ready(5);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].0, 1);
+        assert!(reads.take_ready(5).is_empty());
+        // unconfirmed reads are never ready
+        assert_eq!(reads.take_ready(100).len(), 1);
+    }
+}
@@ -1,4 +1,62 @@
+use errors::*;
+use protobuf::{parse_from_bytes, Message};
 use raft::eraftpb;
 
 pub mod mumpb;
 pub mod mumpb_grpc;
+
+use self::mumpb::{OpRequest, RaftCommand};
+
+// the first byte of the command entries, no protobuf message starts with
+// the field number 0, unlike the bare OpRequest entries written before the
+// RaftCommand
+const COMMAND_MAGIC: u8 = 0;
+const COMMAND_VERSION: u8 = 1;
+
+/// Encodes the command into the data of a raft entry, behind its version
+pub fn encode_command(cmd: &RaftCommand) -> Result<Vec<u8>> {
+    let mut data = vec![COMMAND_MAGIC, COMMAND_VERSION];
+    cmd.write_to_vec(&mut data)?;
+    Ok(data)
+}
+
+/// Decodes the data of a raft entry, the OpRequest of an older entry is
+/// wrapped in a command
+pub fn decode_command(data: &[u8]) -> Result<RaftCommand> {
+    if data.first() != Some(&COMMAND_MAGIC) {
+        let mut cmd = RaftCommand::new();
+        cmd.set_op(parse_from_bytes::<OpRequest>(data)?);
+        return Ok(cmd);
+    }
+    match data.get(1) {
+        Some(&COMMAND_VERSION) => Ok(parse_from_bytes(&data[2..])?),
+        Some(&version) => Err(Error::CommandVersion(version, COMMAND_VERSION)),
+        None => Err(Error::CommandVersion(0, COMMAND_VERSION)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mumpb::Op;
+    use super::*;
+
+    #[test]
+    fn command_test() {
+        let mut cmd = RaftCommand::new();
+        cmd.mut_lease_grant().set_ttl(5);
+        let data = encode_command(&cmd).unwrap();
+        assert_eq!(decode_command(&data).unwrap(), cmd);
+
+        // an entry written before the commands
+        let mut op = OpRequest::new();
+        op.set_field_type(Op::Set);
+        op.set_key(b"k".to_vec());
+        let legacy = op.write_to_bytes().unwrap();
+        assert_eq!(decode_command(&legacy).unwrap().get_op(), &op);
+
+        match decode_command(&[COMMAND_MAGIC, 9]) {
+            Err(Error::CommandVersion(9, _)) => (),
+            _ => panic!("expected unknown version"),
+        }
+    }
+}
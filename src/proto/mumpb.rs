@@ -319,6 +319,232 @@ impl ::protobuf::reflect::ProtobufValue for Done {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestHeader {
+    // message fields
+    pub node_id: u64,
+    pub id: u64,
+    pub forwarded: bool,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl RequestHeader {
+    pub fn new() -> RequestHeader {
+        ::std::default::Default::default()
+    }
+
+    // uint64 node_id = 1;
+
+    pub fn clear_node_id(&mut self) {
+        self.node_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_id(&mut self, v: u64) {
+        self.node_id = v;
+    }
+
+    pub fn get_node_id(&self) -> u64 {
+        self.node_id
+    }
+
+    // uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = v;
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    // bool forwarded = 3;
+
+    pub fn clear_forwarded(&mut self) {
+        self.forwarded = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_forwarded(&mut self, v: bool) {
+        self.forwarded = v;
+    }
+
+    pub fn get_forwarded(&self) -> bool {
+        self.forwarded
+    }
+}
+
+impl ::protobuf::Message for RequestHeader {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.node_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.forwarded = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.node_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.forwarded != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        if self.id != 0 {
+            os.write_uint64(2, self.id)?;
+        }
+        if self.forwarded != false {
+            os.write_bool(3, self.forwarded)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestHeader {
+        RequestHeader::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "node_id",
+                    |m: &RequestHeader| { &m.node_id },
+                    |m: &mut RequestHeader| { &mut m.node_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    |m: &RequestHeader| { &m.id },
+                    |m: &mut RequestHeader| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "forwarded",
+                    |m: &RequestHeader| { &m.forwarded },
+                    |m: &mut RequestHeader| { &mut m.forwarded },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RequestHeader>(
+                    "RequestHeader",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RequestHeader {
+        static mut instance: ::protobuf::lazy::Lazy<RequestHeader> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RequestHeader,
+        };
+        unsafe {
+            instance.get(RequestHeader::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RequestHeader {
+    fn clear(&mut self) {
+        self.clear_node_id();
+        self.clear_id();
+        self.clear_forwarded();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestHeader {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestHeader {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct OpRequest {
     // message fields
@@ -326,6 +552,16 @@ pub struct OpRequest {
     pub key: ::std::vec::Vec<u8>,
     pub value: ::std::vec::Vec<u8>,
     pub limit: u32,
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    pub consistency: ReadConsistency,
+    pub end_key: ::std::vec::Vec<u8>,
+    pub reverse: bool,
+    pub keys_only: bool,
+    pub revision: u64,
+    pub lease: u64,
+    pub exclude_start: bool,
+    pub include_end: bool,
+    pub prefix: bool,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -383,44 +619,7397 @@ impl OpRequest {
         self.value.clear();
     }
 
-    // Param is passed by value, moved
-    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
-        self.value = v;
-    }
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+
+    // uint32 limit = 4;
+
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u32) {
+        self.limit = v;
+    }
+
+    pub fn get_limit(&self) -> u32 {
+        self.limit
+    }
+
+    // .mumpb.RequestHeader header = 5;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| RequestHeader::default_instance())
+    }
+
+    // .mumpb.ReadConsistency consistency = 6;
+
+    pub fn clear_consistency(&mut self) {
+        self.consistency = ReadConsistency::Linearizable;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_consistency(&mut self, v: ReadConsistency) {
+        self.consistency = v;
+    }
+
+    pub fn get_consistency(&self) -> ReadConsistency {
+        self.consistency
+    }
+
+    // bytes end_key = 7;
+
+    pub fn clear_end_key(&mut self) {
+        self.end_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.end_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_end_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.end_key
+    }
+
+    // Take field
+    pub fn take_end_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.end_key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_end_key(&self) -> &[u8] {
+        &self.end_key
+    }
+
+    // bool reverse = 8;
+
+    pub fn clear_reverse(&mut self) {
+        self.reverse = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reverse(&mut self, v: bool) {
+        self.reverse = v;
+    }
+
+    pub fn get_reverse(&self) -> bool {
+        self.reverse
+    }
+
+    // bool keys_only = 9;
+
+    pub fn clear_keys_only(&mut self) {
+        self.keys_only = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys_only(&mut self, v: bool) {
+        self.keys_only = v;
+    }
+
+    pub fn get_keys_only(&self) -> bool {
+        self.keys_only
+    }
+
+    // uint64 revision = 10;
+
+    pub fn clear_revision(&mut self) {
+        self.revision = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: u64) {
+        self.revision = v;
+    }
+
+    pub fn get_revision(&self) -> u64 {
+        self.revision
+    }
+
+    // uint64 lease = 11;
+
+    pub fn clear_lease(&mut self) {
+        self.lease = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lease(&mut self, v: u64) {
+        self.lease = v;
+    }
+
+    pub fn get_lease(&self) -> u64 {
+        self.lease
+    }
+
+    // bool exclude_start = 12;
+
+    pub fn clear_exclude_start(&mut self) {
+        self.exclude_start = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exclude_start(&mut self, v: bool) {
+        self.exclude_start = v;
+    }
+
+    pub fn get_exclude_start(&self) -> bool {
+        self.exclude_start
+    }
+
+    // bool include_end = 13;
+
+    pub fn clear_include_end(&mut self) {
+        self.include_end = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_include_end(&mut self, v: bool) {
+        self.include_end = v;
+    }
+
+    pub fn get_include_end(&self) -> bool {
+        self.include_end
+    }
+
+    // bool prefix = 14;
+
+    pub fn clear_prefix(&mut self) {
+        self.prefix = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prefix(&mut self, v: bool) {
+        self.prefix = v;
+    }
+
+    pub fn get_prefix(&self) -> bool {
+        self.prefix
+    }
+}
+
+impl ::protobuf::Message for OpRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.limit = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.consistency, 6, &mut self.unknown_fields)?
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.end_key)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.reverse = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.keys_only = tmp;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.revision = tmp;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.lease = tmp;
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.exclude_start = tmp;
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.include_end = tmp;
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.prefix = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.field_type != Op::Set {
+            my_size += ::protobuf::rt::enum_size(1, self.field_type);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.consistency != ReadConsistency::Linearizable {
+            my_size += ::protobuf::rt::enum_size(6, self.consistency);
+        }
+        if !self.end_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.end_key);
+        }
+        if self.reverse != false {
+            my_size += 2;
+        }
+        if self.keys_only != false {
+            my_size += 2;
+        }
+        if self.revision != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.revision, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.lease != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.lease, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.exclude_start != false {
+            my_size += 2;
+        }
+        if self.include_end != false {
+            my_size += 2;
+        }
+        if self.prefix != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.field_type != Op::Set {
+            os.write_enum(1, self.field_type.value())?;
+        }
+        if !self.key.is_empty() {
+            os.write_bytes(2, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(4, self.limit)?;
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.consistency != ReadConsistency::Linearizable {
+            os.write_enum(6, self.consistency.value())?;
+        }
+        if !self.end_key.is_empty() {
+            os.write_bytes(7, &self.end_key)?;
+        }
+        if self.reverse != false {
+            os.write_bool(8, self.reverse)?;
+        }
+        if self.keys_only != false {
+            os.write_bool(9, self.keys_only)?;
+        }
+        if self.revision != 0 {
+            os.write_uint64(10, self.revision)?;
+        }
+        if self.lease != 0 {
+            os.write_uint64(11, self.lease)?;
+        }
+        if self.exclude_start != false {
+            os.write_bool(12, self.exclude_start)?;
+        }
+        if self.include_end != false {
+            os.write_bool(13, self.include_end)?;
+        }
+        if self.prefix != false {
+            os.write_bool(14, self.prefix)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OpRequest {
+        OpRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Op>>(
+                    "type",
+                    |m: &OpRequest| { &m.field_type },
+                    |m: &mut OpRequest| { &mut m.field_type },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &OpRequest| { &m.key },
+                    |m: &mut OpRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &OpRequest| { &m.value },
+                    |m: &mut OpRequest| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "limit",
+                    |m: &OpRequest| { &m.limit },
+                    |m: &mut OpRequest| { &mut m.limit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                    "header",
+                    |m: &OpRequest| { &m.header },
+                    |m: &mut OpRequest| { &mut m.header },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ReadConsistency>>(
+                    "consistency",
+                    |m: &OpRequest| { &m.consistency },
+                    |m: &mut OpRequest| { &mut m.consistency },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "end_key",
+                    |m: &OpRequest| { &m.end_key },
+                    |m: &mut OpRequest| { &mut m.end_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "reverse",
+                    |m: &OpRequest| { &m.reverse },
+                    |m: &mut OpRequest| { &mut m.reverse },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "keys_only",
+                    |m: &OpRequest| { &m.keys_only },
+                    |m: &mut OpRequest| { &mut m.keys_only },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "revision",
+                    |m: &OpRequest| { &m.revision },
+                    |m: &mut OpRequest| { &mut m.revision },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "lease",
+                    |m: &OpRequest| { &m.lease },
+                    |m: &mut OpRequest| { &mut m.lease },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "exclude_start",
+                    |m: &OpRequest| { &m.exclude_start },
+                    |m: &mut OpRequest| { &mut m.exclude_start },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "include_end",
+                    |m: &OpRequest| { &m.include_end },
+                    |m: &mut OpRequest| { &mut m.include_end },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "prefix",
+                    |m: &OpRequest| { &m.prefix },
+                    |m: &mut OpRequest| { &mut m.prefix },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OpRequest>(
+                    "OpRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static OpRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OpRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OpRequest,
+        };
+        unsafe {
+            instance.get(OpRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for OpRequest {
+    fn clear(&mut self) {
+        self.clear_field_type();
+        self.clear_key();
+        self.clear_value();
+        self.clear_limit();
+        self.clear_header();
+        self.clear_consistency();
+        self.clear_end_key();
+        self.clear_reverse();
+        self.clear_keys_only();
+        self.clear_revision();
+        self.clear_lease();
+        self.clear_exclude_start();
+        self.clear_include_end();
+        self.clear_prefix();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OpRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OpRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvPair {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    pub create_revision: u64,
+    pub mod_revision: u64,
+    pub version: u64,
+    pub lease: u64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl KvPair {
+    pub fn new() -> KvPair {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+
+    // bytes value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+
+    // uint64 create_revision = 3;
+
+    pub fn clear_create_revision(&mut self) {
+        self.create_revision = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_revision(&mut self, v: u64) {
+        self.create_revision = v;
+    }
+
+    pub fn get_create_revision(&self) -> u64 {
+        self.create_revision
+    }
+
+    // uint64 mod_revision = 4;
+
+    pub fn clear_mod_revision(&mut self) {
+        self.mod_revision = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mod_revision(&mut self, v: u64) {
+        self.mod_revision = v;
+    }
+
+    pub fn get_mod_revision(&self) -> u64 {
+        self.mod_revision
+    }
+
+    // uint64 version = 5;
+
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = v;
+    }
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+
+    // uint64 lease = 6;
+
+    pub fn clear_lease(&mut self) {
+        self.lease = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lease(&mut self, v: u64) {
+        self.lease = v;
+    }
+
+    pub fn get_lease(&self) -> u64 {
+        self.lease
+    }
+}
+
+impl ::protobuf::Message for KvPair {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.create_revision = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.mod_revision = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.lease = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        if self.create_revision != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.create_revision, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.mod_revision != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.mod_revision, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.lease != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.lease, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        if self.create_revision != 0 {
+            os.write_uint64(3, self.create_revision)?;
+        }
+        if self.mod_revision != 0 {
+            os.write_uint64(4, self.mod_revision)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(5, self.version)?;
+        }
+        if self.lease != 0 {
+            os.write_uint64(6, self.lease)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvPair {
+        KvPair::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &KvPair| { &m.key },
+                    |m: &mut KvPair| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &KvPair| { &m.value },
+                    |m: &mut KvPair| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "create_revision",
+                    |m: &KvPair| { &m.create_revision },
+                    |m: &mut KvPair| { &mut m.create_revision },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "mod_revision",
+                    |m: &KvPair| { &m.mod_revision },
+                    |m: &mut KvPair| { &mut m.mod_revision },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "version",
+                    |m: &KvPair| { &m.version },
+                    |m: &mut KvPair| { &mut m.version },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "lease",
+                    |m: &KvPair| { &m.lease },
+                    |m: &mut KvPair| { &mut m.lease },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<KvPair>(
+                    "KvPair",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvPair {
+        static mut instance: ::protobuf::lazy::Lazy<KvPair> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const KvPair,
+        };
+        unsafe {
+            instance.get(KvPair::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvPair {
+    fn clear(&mut self) {
+        self.clear_key();
+        self.clear_value();
+        self.clear_create_revision();
+        self.clear_mod_revision();
+        self.clear_version();
+        self.clear_lease();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvPair {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvPair {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResponseHeader {
+    // message fields
+    pub revision: u64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl ResponseHeader {
+    pub fn new() -> ResponseHeader {
+        ::std::default::Default::default()
+    }
+
+    // uint64 revision = 1;
+
+    pub fn clear_revision(&mut self) {
+        self.revision = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: u64) {
+        self.revision = v;
+    }
+
+    pub fn get_revision(&self) -> u64 {
+        self.revision
+    }
+}
+
+impl ::protobuf::Message for ResponseHeader {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.revision = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.revision != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.revision, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.revision != 0 {
+            os.write_uint64(1, self.revision)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResponseHeader {
+        ResponseHeader::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "revision",
+                    |m: &ResponseHeader| { &m.revision },
+                    |m: &mut ResponseHeader| { &mut m.revision },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ResponseHeader>(
+                    "ResponseHeader",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ResponseHeader {
+        static mut instance: ::protobuf::lazy::Lazy<ResponseHeader> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ResponseHeader,
+        };
+        unsafe {
+            instance.get(ResponseHeader::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ResponseHeader {
+    fn clear(&mut self) {
+        self.clear_revision();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResponseHeader {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResponseHeader {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OpResponse {
+    // message fields
+    pub kvs: ::protobuf::RepeatedField<KvPair>,
+    pub prev_kv: ::protobuf::SingularPtrField<KvPair>,
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl OpResponse {
+    pub fn new() -> OpResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .mumpb.KvPair kvs = 1;
+
+    pub fn clear_kvs(&mut self) {
+        self.kvs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kvs(&mut self, v: ::protobuf::RepeatedField<KvPair>) {
+        self.kvs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_kvs(&mut self) -> &mut ::protobuf::RepeatedField<KvPair> {
+        &mut self.kvs
+    }
+
+    // Take field
+    pub fn take_kvs(&mut self) -> ::protobuf::RepeatedField<KvPair> {
+        ::std::mem::replace(&mut self.kvs, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_kvs(&self) -> &[KvPair] {
+        &self.kvs
+    }
+
+    // .mumpb.KvPair prev_kv = 2;
+
+    pub fn clear_prev_kv(&mut self) {
+        self.prev_kv.clear();
+    }
+
+    pub fn has_prev_kv(&self) -> bool {
+        self.prev_kv.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prev_kv(&mut self, v: KvPair) {
+        self.prev_kv = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prev_kv(&mut self) -> &mut KvPair {
+        if self.prev_kv.is_none() {
+            self.prev_kv.set_default();
+        }
+        self.prev_kv.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_prev_kv(&mut self) -> KvPair {
+        self.prev_kv.take().unwrap_or_else(|| KvPair::new())
+    }
+
+    pub fn get_prev_kv(&self) -> &KvPair {
+        self.prev_kv.as_ref().unwrap_or_else(|| KvPair::default_instance())
+    }
+
+    // .mumpb.ResponseHeader header = 3;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+}
+
+impl ::protobuf::Message for OpResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.kvs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.prev_kv {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.kvs)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.prev_kv)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.kvs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.prev_kv.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.kvs {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.prev_kv.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OpResponse {
+        OpResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KvPair>>(
+                    "kvs",
+                    |m: &OpResponse| { &m.kvs },
+                    |m: &mut OpResponse| { &mut m.kvs },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KvPair>>(
+                    "prev_kv",
+                    |m: &OpResponse| { &m.prev_kv },
+                    |m: &mut OpResponse| { &mut m.prev_kv },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &OpResponse| { &m.header },
+                    |m: &mut OpResponse| { &mut m.header },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OpResponse>(
+                    "OpResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static OpResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OpResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OpResponse,
+        };
+        unsafe {
+            instance.get(OpResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for OpResponse {
+    fn clear(&mut self) {
+        self.clear_kvs();
+        self.clear_prev_kv();
+        self.clear_header();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OpResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OpResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Compare {
+    // message fields
+    pub result: CompareResult,
+    pub target: CompareTarget,
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    pub number: u64,
+    pub exists: bool,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl Compare {
+    pub fn new() -> Compare {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.CompareResult result = 1;
+
+    pub fn clear_result(&mut self) {
+        self.result = CompareResult::Equal;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: CompareResult) {
+        self.result = v;
+    }
+
+    pub fn get_result(&self) -> CompareResult {
+        self.result
+    }
+
+    // .mumpb.CompareTarget target = 2;
+
+    pub fn clear_target(&mut self) {
+        self.target = CompareTarget::Value;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: CompareTarget) {
+        self.target = v;
+    }
+
+    pub fn get_target(&self) -> CompareTarget {
+        self.target
+    }
+
+    // bytes key = 3;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+
+    // bytes value = 4;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+
+    // uint64 number = 5;
+
+    pub fn clear_number(&mut self) {
+        self.number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number(&mut self, v: u64) {
+        self.number = v;
+    }
+
+    pub fn get_number(&self) -> u64 {
+        self.number
+    }
+
+    // bool exists = 6;
+
+    pub fn clear_exists(&mut self) {
+        self.exists = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exists(&mut self, v: bool) {
+        self.exists = v;
+    }
+
+    pub fn get_exists(&self) -> bool {
+        self.exists
+    }
+}
+
+impl ::protobuf::Message for Compare {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.result, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.target, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.number = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.exists = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.result != CompareResult::Equal {
+            my_size += ::protobuf::rt::enum_size(1, self.result);
+        }
+        if self.target != CompareTarget::Value {
+            my_size += ::protobuf::rt::enum_size(2, self.target);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.value);
+        }
+        if self.number != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.exists != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.result != CompareResult::Equal {
+            os.write_enum(1, self.result.value())?;
+        }
+        if self.target != CompareTarget::Value {
+            os.write_enum(2, self.target.value())?;
+        }
+        if !self.key.is_empty() {
+            os.write_bytes(3, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(4, &self.value)?;
+        }
+        if self.number != 0 {
+            os.write_uint64(5, self.number)?;
+        }
+        if self.exists != false {
+            os.write_bool(6, self.exists)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Compare {
+        Compare::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CompareResult>>(
+                    "result",
+                    |m: &Compare| { &m.result },
+                    |m: &mut Compare| { &mut m.result },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CompareTarget>>(
+                    "target",
+                    |m: &Compare| { &m.target },
+                    |m: &mut Compare| { &mut m.target },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &Compare| { &m.key },
+                    |m: &mut Compare| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &Compare| { &m.value },
+                    |m: &mut Compare| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "number",
+                    |m: &Compare| { &m.number },
+                    |m: &mut Compare| { &mut m.number },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "exists",
+                    |m: &Compare| { &m.exists },
+                    |m: &mut Compare| { &mut m.exists },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Compare>(
+                    "Compare",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Compare {
+        static mut instance: ::protobuf::lazy::Lazy<Compare> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Compare,
+        };
+        unsafe {
+            instance.get(Compare::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Compare {
+    fn clear(&mut self) {
+        self.clear_result();
+        self.clear_target();
+        self.clear_key();
+        self.clear_value();
+        self.clear_number();
+        self.clear_exists();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Compare {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Compare {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TxnRequest {
+    // message fields
+    pub compares: ::protobuf::RepeatedField<Compare>,
+    pub success: ::protobuf::RepeatedField<OpRequest>,
+    pub failure: ::protobuf::RepeatedField<OpRequest>,
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl TxnRequest {
+    pub fn new() -> TxnRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .mumpb.Compare compares = 1;
+
+    pub fn clear_compares(&mut self) {
+        self.compares.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compares(&mut self, v: ::protobuf::RepeatedField<Compare>) {
+        self.compares = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_compares(&mut self) -> &mut ::protobuf::RepeatedField<Compare> {
+        &mut self.compares
+    }
+
+    // Take field
+    pub fn take_compares(&mut self) -> ::protobuf::RepeatedField<Compare> {
+        ::std::mem::replace(&mut self.compares, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_compares(&self) -> &[Compare] {
+        &self.compares
+    }
+
+    // repeated .mumpb.OpRequest success = 2;
+
+    pub fn clear_success(&mut self) {
+        self.success.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: ::protobuf::RepeatedField<OpRequest>) {
+        self.success = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_success(&mut self) -> &mut ::protobuf::RepeatedField<OpRequest> {
+        &mut self.success
+    }
+
+    // Take field
+    pub fn take_success(&mut self) -> ::protobuf::RepeatedField<OpRequest> {
+        ::std::mem::replace(&mut self.success, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_success(&self) -> &[OpRequest] {
+        &self.success
+    }
+
+    // repeated .mumpb.OpRequest failure = 3;
+
+    pub fn clear_failure(&mut self) {
+        self.failure.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failure(&mut self, v: ::protobuf::RepeatedField<OpRequest>) {
+        self.failure = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_failure(&mut self) -> &mut ::protobuf::RepeatedField<OpRequest> {
+        &mut self.failure
+    }
+
+    // Take field
+    pub fn take_failure(&mut self) -> ::protobuf::RepeatedField<OpRequest> {
+        ::std::mem::replace(&mut self.failure, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_failure(&self) -> &[OpRequest] {
+        &self.failure
+    }
+
+    // .mumpb.RequestHeader header = 4;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| RequestHeader::default_instance())
+    }
+}
+
+impl ::protobuf::Message for TxnRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.compares {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.success {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.failure {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.compares)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.success)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.failure)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.compares {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.success {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.failure {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.compares {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.success {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.failure {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TxnRequest {
+        TxnRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Compare>>(
+                    "compares",
+                    |m: &TxnRequest| { &m.compares },
+                    |m: &mut TxnRequest| { &mut m.compares },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OpRequest>>(
+                    "success",
+                    |m: &TxnRequest| { &m.success },
+                    |m: &mut TxnRequest| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OpRequest>>(
+                    "failure",
+                    |m: &TxnRequest| { &m.failure },
+                    |m: &mut TxnRequest| { &mut m.failure },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                    "header",
+                    |m: &TxnRequest| { &m.header },
+                    |m: &mut TxnRequest| { &mut m.header },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TxnRequest>(
+                    "TxnRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TxnRequest {
+        static mut instance: ::protobuf::lazy::Lazy<TxnRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TxnRequest,
+        };
+        unsafe {
+            instance.get(TxnRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TxnRequest {
+    fn clear(&mut self) {
+        self.clear_compares();
+        self.clear_success();
+        self.clear_failure();
+        self.clear_header();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TxnRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TxnRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TxnResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    pub succeeded: bool,
+    pub responses: ::protobuf::RepeatedField<OpResponse>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl TxnResponse {
+    pub fn new() -> TxnResponse {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.ResponseHeader header = 1;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+
+    // bool succeeded = 2;
+
+    pub fn clear_succeeded(&mut self) {
+        self.succeeded = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_succeeded(&mut self, v: bool) {
+        self.succeeded = v;
+    }
+
+    pub fn get_succeeded(&self) -> bool {
+        self.succeeded
+    }
+
+    // repeated .mumpb.OpResponse responses = 3;
+
+    pub fn clear_responses(&mut self) {
+        self.responses.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_responses(&mut self, v: ::protobuf::RepeatedField<OpResponse>) {
+        self.responses = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_responses(&mut self) -> &mut ::protobuf::RepeatedField<OpResponse> {
+        &mut self.responses
+    }
+
+    // Take field
+    pub fn take_responses(&mut self) -> ::protobuf::RepeatedField<OpResponse> {
+        ::std::mem::replace(&mut self.responses, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_responses(&self) -> &[OpResponse] {
+        &self.responses
+    }
+}
+
+impl ::protobuf::Message for TxnResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.responses {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.succeeded = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.responses)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.succeeded != false {
+            my_size += 2;
+        }
+        for value in &self.responses {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.succeeded != false {
+            os.write_bool(2, self.succeeded)?;
+        }
+        for v in &self.responses {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TxnResponse {
+        TxnResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &TxnResponse| { &m.header },
+                    |m: &mut TxnResponse| { &mut m.header },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "succeeded",
+                    |m: &TxnResponse| { &m.succeeded },
+                    |m: &mut TxnResponse| { &mut m.succeeded },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OpResponse>>(
+                    "responses",
+                    |m: &TxnResponse| { &m.responses },
+                    |m: &mut TxnResponse| { &mut m.responses },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TxnResponse>(
+                    "TxnResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TxnResponse {
+        static mut instance: ::protobuf::lazy::Lazy<TxnResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TxnResponse,
+        };
+        unsafe {
+            instance.get(TxnResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TxnResponse {
+    fn clear(&mut self) {
+        self.clear_header();
+        self.clear_succeeded();
+        self.clear_responses();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TxnResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TxnResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LeaseGrantRequest {
+    // message fields
+    pub ttl: u64,
+    pub id: u64,
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LeaseGrantRequest {
+    pub fn new() -> LeaseGrantRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint64 ttl = 1;
+
+    pub fn clear_ttl(&mut self) {
+        self.ttl = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl(&mut self, v: u64) {
+        self.ttl = v;
+    }
+
+    pub fn get_ttl(&self) -> u64 {
+        self.ttl
+    }
+
+    // uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = v;
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    // .mumpb.RequestHeader header = 3;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| RequestHeader::default_instance())
+    }
+}
+
+impl ::protobuf::Message for LeaseGrantRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttl = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.ttl != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.ttl, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.ttl != 0 {
+            os.write_uint64(1, self.ttl)?;
+        }
+        if self.id != 0 {
+            os.write_uint64(2, self.id)?;
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LeaseGrantRequest {
+        LeaseGrantRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttl",
+                    |m: &LeaseGrantRequest| { &m.ttl },
+                    |m: &mut LeaseGrantRequest| { &mut m.ttl },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    |m: &LeaseGrantRequest| { &m.id },
+                    |m: &mut LeaseGrantRequest| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                    "header",
+                    |m: &LeaseGrantRequest| { &m.header },
+                    |m: &mut LeaseGrantRequest| { &mut m.header },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LeaseGrantRequest>(
+                    "LeaseGrantRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LeaseGrantRequest {
+        static mut instance: ::protobuf::lazy::Lazy<LeaseGrantRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LeaseGrantRequest,
+        };
+        unsafe {
+            instance.get(LeaseGrantRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LeaseGrantRequest {
+    fn clear(&mut self) {
+        self.clear_ttl();
+        self.clear_id();
+        self.clear_header();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LeaseGrantRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseGrantRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LeaseGrantResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    pub id: u64,
+    pub ttl: u64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LeaseGrantResponse {
+    pub fn new() -> LeaseGrantResponse {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.ResponseHeader header = 1;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+
+    // uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = v;
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    // uint64 ttl = 3;
+
+    pub fn clear_ttl(&mut self) {
+        self.ttl = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl(&mut self, v: u64) {
+        self.ttl = v;
+    }
+
+    pub fn get_ttl(&self) -> u64 {
+        self.ttl
+    }
+}
+
+impl ::protobuf::Message for LeaseGrantResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttl = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ttl != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.ttl, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.id != 0 {
+            os.write_uint64(2, self.id)?;
+        }
+        if self.ttl != 0 {
+            os.write_uint64(3, self.ttl)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LeaseGrantResponse {
+        LeaseGrantResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &LeaseGrantResponse| { &m.header },
+                    |m: &mut LeaseGrantResponse| { &mut m.header },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    |m: &LeaseGrantResponse| { &m.id },
+                    |m: &mut LeaseGrantResponse| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttl",
+                    |m: &LeaseGrantResponse| { &m.ttl },
+                    |m: &mut LeaseGrantResponse| { &mut m.ttl },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LeaseGrantResponse>(
+                    "LeaseGrantResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LeaseGrantResponse {
+        static mut instance: ::protobuf::lazy::Lazy<LeaseGrantResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LeaseGrantResponse,
+        };
+        unsafe {
+            instance.get(LeaseGrantResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LeaseGrantResponse {
+    fn clear(&mut self) {
+        self.clear_header();
+        self.clear_id();
+        self.clear_ttl();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LeaseGrantResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseGrantResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LeaseRevokeRequest {
+    // message fields
+    pub id: u64,
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LeaseRevokeRequest {
+    pub fn new() -> LeaseRevokeRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = v;
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    // .mumpb.RequestHeader header = 2;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| RequestHeader::default_instance())
+    }
+}
+
+impl ::protobuf::Message for LeaseRevokeRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LeaseRevokeRequest {
+        LeaseRevokeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    |m: &LeaseRevokeRequest| { &m.id },
+                    |m: &mut LeaseRevokeRequest| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                    "header",
+                    |m: &LeaseRevokeRequest| { &m.header },
+                    |m: &mut LeaseRevokeRequest| { &mut m.header },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LeaseRevokeRequest>(
+                    "LeaseRevokeRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LeaseRevokeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<LeaseRevokeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LeaseRevokeRequest,
+        };
+        unsafe {
+            instance.get(LeaseRevokeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LeaseRevokeRequest {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_header();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LeaseRevokeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseRevokeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LeaseRevokeResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LeaseRevokeResponse {
+    pub fn new() -> LeaseRevokeResponse {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.ResponseHeader header = 1;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+}
+
+impl ::protobuf::Message for LeaseRevokeResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LeaseRevokeResponse {
+        LeaseRevokeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &LeaseRevokeResponse| { &m.header },
+                    |m: &mut LeaseRevokeResponse| { &mut m.header },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LeaseRevokeResponse>(
+                    "LeaseRevokeResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LeaseRevokeResponse {
+        static mut instance: ::protobuf::lazy::Lazy<LeaseRevokeResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LeaseRevokeResponse,
+        };
+        unsafe {
+            instance.get(LeaseRevokeResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LeaseRevokeResponse {
+    fn clear(&mut self) {
+        self.clear_header();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LeaseRevokeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseRevokeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LeaseKeepAliveRequest {
+    // message fields
+    pub id: u64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LeaseKeepAliveRequest {
+    pub fn new() -> LeaseKeepAliveRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = v;
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl ::protobuf::Message for LeaseKeepAliveRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LeaseKeepAliveRequest {
+        LeaseKeepAliveRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    |m: &LeaseKeepAliveRequest| { &m.id },
+                    |m: &mut LeaseKeepAliveRequest| { &mut m.id },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LeaseKeepAliveRequest>(
+                    "LeaseKeepAliveRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LeaseKeepAliveRequest {
+        static mut instance: ::protobuf::lazy::Lazy<LeaseKeepAliveRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LeaseKeepAliveRequest,
+        };
+        unsafe {
+            instance.get(LeaseKeepAliveRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LeaseKeepAliveRequest {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LeaseKeepAliveRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseKeepAliveRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LeaseKeepAliveResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    pub id: u64,
+    pub ttl: u64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LeaseKeepAliveResponse {
+    pub fn new() -> LeaseKeepAliveResponse {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.ResponseHeader header = 1;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+
+    // uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = v;
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    // uint64 ttl = 3;
+
+    pub fn clear_ttl(&mut self) {
+        self.ttl = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl(&mut self, v: u64) {
+        self.ttl = v;
+    }
+
+    pub fn get_ttl(&self) -> u64 {
+        self.ttl
+    }
+}
+
+impl ::protobuf::Message for LeaseKeepAliveResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttl = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ttl != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.ttl, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.id != 0 {
+            os.write_uint64(2, self.id)?;
+        }
+        if self.ttl != 0 {
+            os.write_uint64(3, self.ttl)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LeaseKeepAliveResponse {
+        LeaseKeepAliveResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &LeaseKeepAliveResponse| { &m.header },
+                    |m: &mut LeaseKeepAliveResponse| { &mut m.header },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    |m: &LeaseKeepAliveResponse| { &m.id },
+                    |m: &mut LeaseKeepAliveResponse| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttl",
+                    |m: &LeaseKeepAliveResponse| { &m.ttl },
+                    |m: &mut LeaseKeepAliveResponse| { &mut m.ttl },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LeaseKeepAliveResponse>(
+                    "LeaseKeepAliveResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LeaseKeepAliveResponse {
+        static mut instance: ::protobuf::lazy::Lazy<LeaseKeepAliveResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LeaseKeepAliveResponse,
+        };
+        unsafe {
+            instance.get(LeaseKeepAliveResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LeaseKeepAliveResponse {
+    fn clear(&mut self) {
+        self.clear_header();
+        self.clear_id();
+        self.clear_ttl();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LeaseKeepAliveResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseKeepAliveResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LeaseTimeToLiveRequest {
+    // message fields
+    pub id: u64,
+    pub keys: bool,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LeaseTimeToLiveRequest {
+    pub fn new() -> LeaseTimeToLiveRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = v;
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    // bool keys = 2;
+
+    pub fn clear_keys(&mut self) {
+        self.keys = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: bool) {
+        self.keys = v;
+    }
+
+    pub fn get_keys(&self) -> bool {
+        self.keys
+    }
+}
+
+impl ::protobuf::Message for LeaseTimeToLiveRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.keys = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.keys != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if self.keys != false {
+            os.write_bool(2, self.keys)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LeaseTimeToLiveRequest {
+        LeaseTimeToLiveRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    |m: &LeaseTimeToLiveRequest| { &m.id },
+                    |m: &mut LeaseTimeToLiveRequest| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "keys",
+                    |m: &LeaseTimeToLiveRequest| { &m.keys },
+                    |m: &mut LeaseTimeToLiveRequest| { &mut m.keys },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LeaseTimeToLiveRequest>(
+                    "LeaseTimeToLiveRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LeaseTimeToLiveRequest {
+        static mut instance: ::protobuf::lazy::Lazy<LeaseTimeToLiveRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LeaseTimeToLiveRequest,
+        };
+        unsafe {
+            instance.get(LeaseTimeToLiveRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LeaseTimeToLiveRequest {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_keys();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LeaseTimeToLiveRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseTimeToLiveRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LeaseTimeToLiveResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    pub id: u64,
+    pub ttl: i64,
+    pub granted_ttl: u64,
+    pub keys: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LeaseTimeToLiveResponse {
+    pub fn new() -> LeaseTimeToLiveResponse {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.ResponseHeader header = 1;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+
+    // uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = v;
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    // int64 ttl = 3;
+
+    pub fn clear_ttl(&mut self) {
+        self.ttl = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl(&mut self, v: i64) {
+        self.ttl = v;
+    }
+
+    pub fn get_ttl(&self) -> i64 {
+        self.ttl
+    }
+
+    // uint64 granted_ttl = 4;
+
+    pub fn clear_granted_ttl(&mut self) {
+        self.granted_ttl = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_granted_ttl(&mut self, v: u64) {
+        self.granted_ttl = v;
+    }
+
+    pub fn get_granted_ttl(&self) -> u64 {
+        self.granted_ttl
+    }
+
+    // repeated bytes keys = 5;
+
+    pub fn clear_keys(&mut self) {
+        self.keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.keys = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_keys(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.keys
+    }
+
+    // Take field
+    pub fn take_keys(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.keys, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_keys(&self) -> &[::std::vec::Vec<u8>] {
+        &self.keys
+    }
+}
+
+impl ::protobuf::Message for LeaseTimeToLiveResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.ttl = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.granted_ttl = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.keys)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ttl != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.ttl, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.granted_ttl != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.granted_ttl, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.keys {
+            my_size += ::protobuf::rt::bytes_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.id != 0 {
+            os.write_uint64(2, self.id)?;
+        }
+        if self.ttl != 0 {
+            os.write_int64(3, self.ttl)?;
+        }
+        if self.granted_ttl != 0 {
+            os.write_uint64(4, self.granted_ttl)?;
+        }
+        for v in &self.keys {
+            os.write_bytes(5, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LeaseTimeToLiveResponse {
+        LeaseTimeToLiveResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &LeaseTimeToLiveResponse| { &m.header },
+                    |m: &mut LeaseTimeToLiveResponse| { &mut m.header },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    |m: &LeaseTimeToLiveResponse| { &m.id },
+                    |m: &mut LeaseTimeToLiveResponse| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "ttl",
+                    |m: &LeaseTimeToLiveResponse| { &m.ttl },
+                    |m: &mut LeaseTimeToLiveResponse| { &mut m.ttl },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "granted_ttl",
+                    |m: &LeaseTimeToLiveResponse| { &m.granted_ttl },
+                    |m: &mut LeaseTimeToLiveResponse| { &mut m.granted_ttl },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "keys",
+                    |m: &LeaseTimeToLiveResponse| { &m.keys },
+                    |m: &mut LeaseTimeToLiveResponse| { &mut m.keys },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LeaseTimeToLiveResponse>(
+                    "LeaseTimeToLiveResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LeaseTimeToLiveResponse {
+        static mut instance: ::protobuf::lazy::Lazy<LeaseTimeToLiveResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LeaseTimeToLiveResponse,
+        };
+        unsafe {
+            instance.get(LeaseTimeToLiveResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LeaseTimeToLiveResponse {
+    fn clear(&mut self) {
+        self.clear_header();
+        self.clear_id();
+        self.clear_ttl();
+        self.clear_granted_ttl();
+        self.clear_keys();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LeaseTimeToLiveResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseTimeToLiveResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RaftCommand {
+    // message fields
+    pub op: ::protobuf::SingularPtrField<OpRequest>,
+    pub txn: ::protobuf::SingularPtrField<TxnRequest>,
+    pub lease_grant: ::protobuf::SingularPtrField<LeaseGrantRequest>,
+    pub lease_revoke: ::protobuf::SingularPtrField<LeaseRevokeRequest>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl RaftCommand {
+    pub fn new() -> RaftCommand {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.OpRequest op = 1;
+
+    pub fn clear_op(&mut self) {
+        self.op.clear();
+    }
+
+    pub fn has_op(&self) -> bool {
+        self.op.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_op(&mut self, v: OpRequest) {
+        self.op = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_op(&mut self) -> &mut OpRequest {
+        if self.op.is_none() {
+            self.op.set_default();
+        }
+        self.op.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_op(&mut self) -> OpRequest {
+        self.op.take().unwrap_or_else(|| OpRequest::new())
+    }
+
+    pub fn get_op(&self) -> &OpRequest {
+        self.op.as_ref().unwrap_or_else(|| OpRequest::default_instance())
+    }
+
+    // .mumpb.TxnRequest txn = 2;
+
+    pub fn clear_txn(&mut self) {
+        self.txn.clear();
+    }
+
+    pub fn has_txn(&self) -> bool {
+        self.txn.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_txn(&mut self, v: TxnRequest) {
+        self.txn = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_txn(&mut self) -> &mut TxnRequest {
+        if self.txn.is_none() {
+            self.txn.set_default();
+        }
+        self.txn.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_txn(&mut self) -> TxnRequest {
+        self.txn.take().unwrap_or_else(|| TxnRequest::new())
+    }
+
+    pub fn get_txn(&self) -> &TxnRequest {
+        self.txn.as_ref().unwrap_or_else(|| TxnRequest::default_instance())
+    }
+
+    // .mumpb.LeaseGrantRequest lease_grant = 3;
+
+    pub fn clear_lease_grant(&mut self) {
+        self.lease_grant.clear();
+    }
+
+    pub fn has_lease_grant(&self) -> bool {
+        self.lease_grant.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lease_grant(&mut self, v: LeaseGrantRequest) {
+        self.lease_grant = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_lease_grant(&mut self) -> &mut LeaseGrantRequest {
+        if self.lease_grant.is_none() {
+            self.lease_grant.set_default();
+        }
+        self.lease_grant.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_lease_grant(&mut self) -> LeaseGrantRequest {
+        self.lease_grant.take().unwrap_or_else(|| LeaseGrantRequest::new())
+    }
+
+    pub fn get_lease_grant(&self) -> &LeaseGrantRequest {
+        self.lease_grant.as_ref().unwrap_or_else(|| LeaseGrantRequest::default_instance())
+    }
+
+    // .mumpb.LeaseRevokeRequest lease_revoke = 4;
+
+    pub fn clear_lease_revoke(&mut self) {
+        self.lease_revoke.clear();
+    }
+
+    pub fn has_lease_revoke(&self) -> bool {
+        self.lease_revoke.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lease_revoke(&mut self, v: LeaseRevokeRequest) {
+        self.lease_revoke = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_lease_revoke(&mut self) -> &mut LeaseRevokeRequest {
+        if self.lease_revoke.is_none() {
+            self.lease_revoke.set_default();
+        }
+        self.lease_revoke.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_lease_revoke(&mut self) -> LeaseRevokeRequest {
+        self.lease_revoke.take().unwrap_or_else(|| LeaseRevokeRequest::new())
+    }
+
+    pub fn get_lease_revoke(&self) -> &LeaseRevokeRequest {
+        self.lease_revoke.as_ref().unwrap_or_else(|| LeaseRevokeRequest::default_instance())
+    }
+}
+
+impl ::protobuf::Message for RaftCommand {
+    fn is_initialized(&self) -> bool {
+        for v in &self.op {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.txn {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.lease_grant {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.lease_revoke {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.op)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.txn)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.lease_grant)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.lease_revoke)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.op.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.txn.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.lease_grant.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.lease_revoke.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.op.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.txn.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.lease_grant.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.lease_revoke.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RaftCommand {
+        RaftCommand::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OpRequest>>(
+                    "op",
+                    |m: &RaftCommand| { &m.op },
+                    |m: &mut RaftCommand| { &mut m.op },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TxnRequest>>(
+                    "txn",
+                    |m: &RaftCommand| { &m.txn },
+                    |m: &mut RaftCommand| { &mut m.txn },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LeaseGrantRequest>>(
+                    "lease_grant",
+                    |m: &RaftCommand| { &m.lease_grant },
+                    |m: &mut RaftCommand| { &mut m.lease_grant },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LeaseRevokeRequest>>(
+                    "lease_revoke",
+                    |m: &RaftCommand| { &m.lease_revoke },
+                    |m: &mut RaftCommand| { &mut m.lease_revoke },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RaftCommand>(
+                    "RaftCommand",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RaftCommand {
+        static mut instance: ::protobuf::lazy::Lazy<RaftCommand> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RaftCommand,
+        };
+        unsafe {
+            instance.get(RaftCommand::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RaftCommand {
+    fn clear(&mut self) {
+        self.clear_op();
+        self.clear_txn();
+        self.clear_lease_grant();
+        self.clear_lease_revoke();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RaftCommand {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftCommand {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConfRequest {
+    // message fields
+    pub change: ::protobuf::SingularPtrField<super::eraftpb::ConfChange>,
+    pub header: ::protobuf::SingularPtrField<RequestHeader>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl ConfRequest {
+    pub fn new() -> ConfRequest {
+        ::std::default::Default::default()
+    }
+
+    // .eraftpb.ConfChange change = 1;
+
+    pub fn clear_change(&mut self) {
+        self.change.clear();
+    }
+
+    pub fn has_change(&self) -> bool {
+        self.change.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_change(&mut self, v: super::eraftpb::ConfChange) {
+        self.change = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_change(&mut self) -> &mut super::eraftpb::ConfChange {
+        if self.change.is_none() {
+            self.change.set_default();
+        }
+        self.change.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_change(&mut self) -> super::eraftpb::ConfChange {
+        self.change.take().unwrap_or_else(|| super::eraftpb::ConfChange::new())
+    }
+
+    pub fn get_change(&self) -> &super::eraftpb::ConfChange {
+        self.change.as_ref().unwrap_or_else(|| super::eraftpb::ConfChange::default_instance())
+    }
+
+    // .mumpb.RequestHeader header = 2;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: RequestHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut RequestHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> RequestHeader {
+        self.header.take().unwrap_or_else(|| RequestHeader::new())
+    }
+
+    pub fn get_header(&self) -> &RequestHeader {
+        self.header.as_ref().unwrap_or_else(|| RequestHeader::default_instance())
+    }
+}
+
+impl ::protobuf::Message for ConfRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.change {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.change)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.change.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.change.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConfRequest {
+        ConfRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::eraftpb::ConfChange>>(
+                    "change",
+                    |m: &ConfRequest| { &m.change },
+                    |m: &mut ConfRequest| { &mut m.change },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RequestHeader>>(
+                    "header",
+                    |m: &ConfRequest| { &m.header },
+                    |m: &mut ConfRequest| { &mut m.header },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ConfRequest>(
+                    "ConfRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ConfRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ConfRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ConfRequest,
+        };
+        unsafe {
+            instance.get(ConfRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ConfRequest {
+    fn clear(&mut self) {
+        self.clear_change();
+        self.clear_header();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConfRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConfRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConfResponse {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl ConfResponse {
+    pub fn new() -> ConfResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for ConfResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConfResponse {
+        ConfResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<ConfResponse>(
+                    "ConfResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ConfResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ConfResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ConfResponse,
+        };
+        unsafe {
+            instance.get(ConfResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ConfResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConfResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConfResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WatchRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub end_key: ::std::vec::Vec<u8>,
+    pub start_revision: u64,
+    pub progress_notify: bool,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WatchRequest {
+    pub fn new() -> WatchRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+
+    // bytes end_key = 2;
+
+    pub fn clear_end_key(&mut self) {
+        self.end_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.end_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_end_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.end_key
+    }
+
+    // Take field
+    pub fn take_end_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.end_key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_end_key(&self) -> &[u8] {
+        &self.end_key
+    }
+
+    // uint64 start_revision = 3;
+
+    pub fn clear_start_revision(&mut self) {
+        self.start_revision = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_revision(&mut self, v: u64) {
+        self.start_revision = v;
+    }
+
+    pub fn get_start_revision(&self) -> u64 {
+        self.start_revision
+    }
+
+    // bool progress_notify = 4;
+
+    pub fn clear_progress_notify(&mut self) {
+        self.progress_notify = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_progress_notify(&mut self, v: bool) {
+        self.progress_notify = v;
+    }
+
+    pub fn get_progress_notify(&self) -> bool {
+        self.progress_notify
+    }
+}
+
+impl ::protobuf::Message for WatchRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.end_key)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_revision = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.progress_notify = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if !self.end_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.end_key);
+        }
+        if self.start_revision != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.start_revision, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.progress_notify != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.end_key.is_empty() {
+            os.write_bytes(2, &self.end_key)?;
+        }
+        if self.start_revision != 0 {
+            os.write_uint64(3, self.start_revision)?;
+        }
+        if self.progress_notify != false {
+            os.write_bool(4, self.progress_notify)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WatchRequest {
+        WatchRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &WatchRequest| { &m.key },
+                    |m: &mut WatchRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "end_key",
+                    |m: &WatchRequest| { &m.end_key },
+                    |m: &mut WatchRequest| { &mut m.end_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_revision",
+                    |m: &WatchRequest| { &m.start_revision },
+                    |m: &mut WatchRequest| { &mut m.start_revision },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "progress_notify",
+                    |m: &WatchRequest| { &m.progress_notify },
+                    |m: &mut WatchRequest| { &mut m.progress_notify },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WatchRequest>(
+                    "WatchRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WatchRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WatchRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WatchRequest,
+        };
+        unsafe {
+            instance.get(WatchRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WatchRequest {
+    fn clear(&mut self) {
+        self.clear_key();
+        self.clear_end_key();
+        self.clear_start_revision();
+        self.clear_progress_notify();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WatchRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WatchRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Event {
+    // message fields
+    pub field_type: EventType,
+    pub kv: ::protobuf::SingularPtrField<KvPair>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl Event {
+    pub fn new() -> Event {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.EventType type = 1;
+
+    pub fn clear_field_type(&mut self) {
+        self.field_type = EventType::Put;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: EventType) {
+        self.field_type = v;
+    }
+
+    pub fn get_field_type(&self) -> EventType {
+        self.field_type
+    }
+
+    // .mumpb.KvPair kv = 2;
+
+    pub fn clear_kv(&mut self) {
+        self.kv.clear();
+    }
+
+    pub fn has_kv(&self) -> bool {
+        self.kv.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kv(&mut self, v: KvPair) {
+        self.kv = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kv(&mut self) -> &mut KvPair {
+        if self.kv.is_none() {
+            self.kv.set_default();
+        }
+        self.kv.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_kv(&mut self) -> KvPair {
+        self.kv.take().unwrap_or_else(|| KvPair::new())
+    }
+
+    pub fn get_kv(&self) -> &KvPair {
+        self.kv.as_ref().unwrap_or_else(|| KvPair::default_instance())
+    }
+}
+
+impl ::protobuf::Message for Event {
+    fn is_initialized(&self) -> bool {
+        for v in &self.kv {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.kv)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.field_type != EventType::Put {
+            my_size += ::protobuf::rt::enum_size(1, self.field_type);
+        }
+        if let Some(ref v) = self.kv.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.field_type != EventType::Put {
+            os.write_enum(1, self.field_type.value())?;
+        }
+        if let Some(ref v) = self.kv.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Event {
+        Event::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<EventType>>(
+                    "type",
+                    |m: &Event| { &m.field_type },
+                    |m: &mut Event| { &mut m.field_type },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KvPair>>(
+                    "kv",
+                    |m: &Event| { &m.kv },
+                    |m: &mut Event| { &mut m.kv },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Event>(
+                    "Event",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Event {
+        static mut instance: ::protobuf::lazy::Lazy<Event> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Event,
+        };
+        unsafe {
+            instance.get(Event::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Event {
+    fn clear(&mut self) {
+        self.clear_field_type();
+        self.clear_kv();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Event {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Event {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WatchResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    pub created: bool,
+    pub events: ::protobuf::RepeatedField<Event>,
+    pub compact_revision: u64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WatchResponse {
+    pub fn new() -> WatchResponse {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.ResponseHeader header = 1;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+
+    // bool created = 2;
+
+    pub fn clear_created(&mut self) {
+        self.created = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created(&mut self, v: bool) {
+        self.created = v;
+    }
+
+    pub fn get_created(&self) -> bool {
+        self.created
+    }
+
+    // repeated .mumpb.Event events = 3;
+
+    pub fn clear_events(&mut self) {
+        self.events.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_events(&mut self, v: ::protobuf::RepeatedField<Event>) {
+        self.events = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_events(&mut self) -> &mut ::protobuf::RepeatedField<Event> {
+        &mut self.events
+    }
+
+    // Take field
+    pub fn take_events(&mut self) -> ::protobuf::RepeatedField<Event> {
+        ::std::mem::replace(&mut self.events, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_events(&self) -> &[Event] {
+        &self.events
+    }
+
+    // uint64 compact_revision = 4;
+
+    pub fn clear_compact_revision(&mut self) {
+        self.compact_revision = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compact_revision(&mut self, v: u64) {
+        self.compact_revision = v;
+    }
+
+    pub fn get_compact_revision(&self) -> u64 {
+        self.compact_revision
+    }
+}
+
+impl ::protobuf::Message for WatchResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.events {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.created = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.events)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.compact_revision = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.created != false {
+            my_size += 2;
+        }
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.compact_revision != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.compact_revision, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.created != false {
+            os.write_bool(2, self.created)?;
+        }
+        for v in &self.events {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.compact_revision != 0 {
+            os.write_uint64(4, self.compact_revision)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WatchResponse {
+        WatchResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &WatchResponse| { &m.header },
+                    |m: &mut WatchResponse| { &mut m.header },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "created",
+                    |m: &WatchResponse| { &m.created },
+                    |m: &mut WatchResponse| { &mut m.created },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Event>>(
+                    "events",
+                    |m: &WatchResponse| { &m.events },
+                    |m: &mut WatchResponse| { &mut m.events },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "compact_revision",
+                    |m: &WatchResponse| { &m.compact_revision },
+                    |m: &mut WatchResponse| { &mut m.compact_revision },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WatchResponse>(
+                    "WatchResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WatchResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WatchResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WatchResponse,
+        };
+        unsafe {
+            instance.get(WatchResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WatchResponse {
+    fn clear(&mut self) {
+        self.clear_header();
+        self.clear_created();
+        self.clear_events();
+        self.clear_compact_revision();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WatchResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LockRequest {
+    // message fields
+    pub name: ::std::vec::Vec<u8>,
+    pub lease: u64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LockRequest {
+    pub fn new() -> LockRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::vec::Vec<u8>) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.name, ::std::vec::Vec::new())
+    }
+
+    pub fn get_name(&self) -> &[u8] {
+        &self.name
+    }
+
+    // uint64 lease = 2;
+
+    pub fn clear_lease(&mut self) {
+        self.lease = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lease(&mut self, v: u64) {
+        self.lease = v;
+    }
+
+    pub fn get_lease(&self) -> u64 {
+        self.lease
+    }
+}
+
+impl ::protobuf::Message for LockRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.lease = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.name);
+        }
+        if self.lease != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.lease, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_bytes(1, &self.name)?;
+        }
+        if self.lease != 0 {
+            os.write_uint64(2, self.lease)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LockRequest {
+        LockRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "name",
+                    |m: &LockRequest| { &m.name },
+                    |m: &mut LockRequest| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "lease",
+                    |m: &LockRequest| { &m.lease },
+                    |m: &mut LockRequest| { &mut m.lease },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LockRequest>(
+                    "LockRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LockRequest {
+        static mut instance: ::protobuf::lazy::Lazy<LockRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LockRequest,
+        };
+        unsafe {
+            instance.get(LockRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LockRequest {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_lease();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LockRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LockRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LockResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    pub key: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LockResponse {
+    pub fn new() -> LockResponse {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.ResponseHeader header = 1;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+
+    // bytes key = 2;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+}
+
+impl ::protobuf::Message for LockResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.key.is_empty() {
+            os.write_bytes(2, &self.key)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LockResponse {
+        LockResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &LockResponse| { &m.header },
+                    |m: &mut LockResponse| { &mut m.header },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &LockResponse| { &m.key },
+                    |m: &mut LockResponse| { &mut m.key },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LockResponse>(
+                    "LockResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LockResponse {
+        static mut instance: ::protobuf::lazy::Lazy<LockResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LockResponse,
+        };
+        unsafe {
+            instance.get(LockResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LockResponse {
+    fn clear(&mut self) {
+        self.clear_header();
+        self.clear_key();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LockResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LockResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UnlockRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub revision: u64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl UnlockRequest {
+    pub fn new() -> UnlockRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+
+    // uint64 revision = 2;
+
+    pub fn clear_revision(&mut self) {
+        self.revision = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: u64) {
+        self.revision = v;
+    }
+
+    pub fn get_revision(&self) -> u64 {
+        self.revision
+    }
+}
+
+impl ::protobuf::Message for UnlockRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.revision = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if self.revision != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.revision, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if self.revision != 0 {
+            os.write_uint64(2, self.revision)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UnlockRequest {
+        UnlockRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &UnlockRequest| { &m.key },
+                    |m: &mut UnlockRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "revision",
+                    |m: &UnlockRequest| { &m.revision },
+                    |m: &mut UnlockRequest| { &mut m.revision },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<UnlockRequest>(
+                    "UnlockRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static UnlockRequest {
+        static mut instance: ::protobuf::lazy::Lazy<UnlockRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const UnlockRequest,
+        };
+        unsafe {
+            instance.get(UnlockRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for UnlockRequest {
+    fn clear(&mut self) {
+        self.clear_key();
+        self.clear_revision();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UnlockRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnlockRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UnlockResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl UnlockResponse {
+    pub fn new() -> UnlockResponse {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.ResponseHeader header = 1;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+}
+
+impl ::protobuf::Message for UnlockResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UnlockResponse {
+        UnlockResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &UnlockResponse| { &m.header },
+                    |m: &mut UnlockResponse| { &mut m.header },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<UnlockResponse>(
+                    "UnlockResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static UnlockResponse {
+        static mut instance: ::protobuf::lazy::Lazy<UnlockResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const UnlockResponse,
+        };
+        unsafe {
+            instance.get(UnlockResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for UnlockResponse {
+    fn clear(&mut self) {
+        self.clear_header();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UnlockResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnlockResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LeaderKey {
+    // message fields
+    pub name: ::std::vec::Vec<u8>,
+    pub key: ::std::vec::Vec<u8>,
+    pub revision: u64,
+    pub lease: u64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl LeaderKey {
+    pub fn new() -> LeaderKey {
+        ::std::default::Default::default()
+    }
+
+    // bytes name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::vec::Vec<u8>) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.name, ::std::vec::Vec::new())
+    }
+
+    pub fn get_name(&self) -> &[u8] {
+        &self.name
+    }
+
+    // bytes key = 2;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+
+    // uint64 revision = 3;
+
+    pub fn clear_revision(&mut self) {
+        self.revision = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: u64) {
+        self.revision = v;
+    }
+
+    pub fn get_revision(&self) -> u64 {
+        self.revision
+    }
+
+    // uint64 lease = 4;
+
+    pub fn clear_lease(&mut self) {
+        self.lease = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lease(&mut self, v: u64) {
+        self.lease = v;
+    }
+
+    pub fn get_lease(&self) -> u64 {
+        self.lease
+    }
+}
+
+impl ::protobuf::Message for LeaderKey {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.revision = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.lease = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.name);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.key);
+        }
+        if self.revision != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.revision, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.lease != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.lease, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_bytes(1, &self.name)?;
+        }
+        if !self.key.is_empty() {
+            os.write_bytes(2, &self.key)?;
+        }
+        if self.revision != 0 {
+            os.write_uint64(3, self.revision)?;
+        }
+        if self.lease != 0 {
+            os.write_uint64(4, self.lease)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LeaderKey {
+        LeaderKey::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "name",
+                    |m: &LeaderKey| { &m.name },
+                    |m: &mut LeaderKey| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &LeaderKey| { &m.key },
+                    |m: &mut LeaderKey| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "revision",
+                    |m: &LeaderKey| { &m.revision },
+                    |m: &mut LeaderKey| { &mut m.revision },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "lease",
+                    |m: &LeaderKey| { &m.lease },
+                    |m: &mut LeaderKey| { &mut m.lease },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LeaderKey>(
+                    "LeaderKey",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LeaderKey {
+        static mut instance: ::protobuf::lazy::Lazy<LeaderKey> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LeaderKey,
+        };
+        unsafe {
+            instance.get(LeaderKey::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LeaderKey {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_key();
+        self.clear_revision();
+        self.clear_lease();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LeaderKey {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaderKey {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CampaignRequest {
+    // message fields
+    pub name: ::std::vec::Vec<u8>,
+    pub lease: u64,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl CampaignRequest {
+    pub fn new() -> CampaignRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::vec::Vec<u8>) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.name, ::std::vec::Vec::new())
+    }
+
+    pub fn get_name(&self) -> &[u8] {
+        &self.name
+    }
+
+    // uint64 lease = 2;
+
+    pub fn clear_lease(&mut self) {
+        self.lease = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lease(&mut self, v: u64) {
+        self.lease = v;
+    }
+
+    pub fn get_lease(&self) -> u64 {
+        self.lease
+    }
+
+    // bytes value = 3;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl ::protobuf::Message for CampaignRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.lease = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.name);
+        }
+        if self.lease != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.lease, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_bytes(1, &self.name)?;
+        }
+        if self.lease != 0 {
+            os.write_uint64(2, self.lease)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CampaignRequest {
+        CampaignRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "name",
+                    |m: &CampaignRequest| { &m.name },
+                    |m: &mut CampaignRequest| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "lease",
+                    |m: &CampaignRequest| { &m.lease },
+                    |m: &mut CampaignRequest| { &mut m.lease },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &CampaignRequest| { &m.value },
+                    |m: &mut CampaignRequest| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CampaignRequest>(
+                    "CampaignRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CampaignRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CampaignRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CampaignRequest,
+        };
+        unsafe {
+            instance.get(CampaignRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CampaignRequest {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_lease();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CampaignRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CampaignRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CampaignResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    pub leader: ::protobuf::SingularPtrField<LeaderKey>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl CampaignResponse {
+    pub fn new() -> CampaignResponse {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.ResponseHeader header = 1;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+
+    // .mumpb.LeaderKey leader = 2;
+
+    pub fn clear_leader(&mut self) {
+        self.leader.clear();
+    }
+
+    pub fn has_leader(&self) -> bool {
+        self.leader.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leader(&mut self, v: LeaderKey) {
+        self.leader = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_leader(&mut self) -> &mut LeaderKey {
+        if self.leader.is_none() {
+            self.leader.set_default();
+        }
+        self.leader.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_leader(&mut self) -> LeaderKey {
+        self.leader.take().unwrap_or_else(|| LeaderKey::new())
+    }
+
+    pub fn get_leader(&self) -> &LeaderKey {
+        self.leader.as_ref().unwrap_or_else(|| LeaderKey::default_instance())
+    }
+}
+
+impl ::protobuf::Message for CampaignResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.leader {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.leader)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.leader.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.leader.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CampaignResponse {
+        CampaignResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &CampaignResponse| { &m.header },
+                    |m: &mut CampaignResponse| { &mut m.header },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LeaderKey>>(
+                    "leader",
+                    |m: &CampaignResponse| { &m.leader },
+                    |m: &mut CampaignResponse| { &mut m.leader },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CampaignResponse>(
+                    "CampaignResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CampaignResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CampaignResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CampaignResponse,
+        };
+        unsafe {
+            instance.get(CampaignResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CampaignResponse {
+    fn clear(&mut self) {
+        self.clear_header();
+        self.clear_leader();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CampaignResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CampaignResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProclaimRequest {
+    // message fields
+    pub leader: ::protobuf::SingularPtrField<LeaderKey>,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl ProclaimRequest {
+    pub fn new() -> ProclaimRequest {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.LeaderKey leader = 1;
+
+    pub fn clear_leader(&mut self) {
+        self.leader.clear();
+    }
+
+    pub fn has_leader(&self) -> bool {
+        self.leader.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leader(&mut self, v: LeaderKey) {
+        self.leader = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_leader(&mut self) -> &mut LeaderKey {
+        if self.leader.is_none() {
+            self.leader.set_default();
+        }
+        self.leader.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_leader(&mut self) -> LeaderKey {
+        self.leader.take().unwrap_or_else(|| LeaderKey::new())
+    }
+
+    pub fn get_leader(&self) -> &LeaderKey {
+        self.leader.as_ref().unwrap_or_else(|| LeaderKey::default_instance())
+    }
+
+    // bytes value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl ::protobuf::Message for ProclaimRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.leader {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.leader)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.leader.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.leader.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ProclaimRequest {
+        ProclaimRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LeaderKey>>(
+                    "leader",
+                    |m: &ProclaimRequest| { &m.leader },
+                    |m: &mut ProclaimRequest| { &mut m.leader },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &ProclaimRequest| { &m.value },
+                    |m: &mut ProclaimRequest| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ProclaimRequest>(
+                    "ProclaimRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ProclaimRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ProclaimRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ProclaimRequest,
+        };
+        unsafe {
+            instance.get(ProclaimRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ProclaimRequest {
+    fn clear(&mut self) {
+        self.clear_leader();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProclaimRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProclaimRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProclaimResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl ProclaimResponse {
+    pub fn new() -> ProclaimResponse {
+        ::std::default::Default::default()
+    }
+
+    // .mumpb.ResponseHeader header = 1;
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.value
+    pub fn clear_header(&mut self) {
+        self.header.clear();
     }
 
-    // Take field
-    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
     }
 
-    pub fn get_value(&self) -> &[u8] {
-        &self.value
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
     }
 
-    // uint32 limit = 4;
-
-    pub fn clear_limit(&mut self) {
-        self.limit = 0;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
     }
 
-    // Param is passed by value, moved
-    pub fn set_limit(&mut self, v: u32) {
-        self.limit = v;
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
     }
 
-    pub fn get_limit(&self) -> u32 {
-        self.limit
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
     }
 }
 
-impl ::protobuf::Message for OpRequest {
+impl ::protobuf::Message for ProclaimResponse {
     fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -429,20 +8018,7 @@ impl ::protobuf::Message for OpRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 1, &mut self.unknown_fields)?
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.limit = tmp;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -456,17 +8032,9 @@ impl ::protobuf::Message for OpRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.field_type != Op::Set {
-            my_size += ::protobuf::rt::enum_size(1, self.field_type);
-        }
-        if !self.key.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.key);
-        }
-        if !self.value.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.value);
-        }
-        if self.limit != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -474,17 +8042,10 @@ impl ::protobuf::Message for OpRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.field_type != Op::Set {
-            os.write_enum(1, self.field_type.value())?;
-        }
-        if !self.key.is_empty() {
-            os.write_bytes(2, &self.key)?;
-        }
-        if !self.value.is_empty() {
-            os.write_bytes(3, &self.value)?;
-        }
-        if self.limit != 0 {
-            os.write_uint32(4, self.limit)?;
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -516,8 +8077,8 @@ impl ::protobuf::Message for OpRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> OpRequest {
-        OpRequest::new()
+    fn new() -> ProclaimResponse {
+        ProclaimResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -528,28 +8089,13 @@ impl ::protobuf::Message for OpRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Op>>(
-                    "type",
-                    |m: &OpRequest| { &m.field_type },
-                    |m: &mut OpRequest| { &mut m.field_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "key",
-                    |m: &OpRequest| { &m.key },
-                    |m: &mut OpRequest| { &mut m.key },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "value",
-                    |m: &OpRequest| { &m.value },
-                    |m: &mut OpRequest| { &mut m.value },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "limit",
-                    |m: &OpRequest| { &m.limit },
-                    |m: &mut OpRequest| { &mut m.limit },
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &ProclaimResponse| { &m.header },
+                    |m: &mut ProclaimResponse| { &mut m.header },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OpRequest>(
-                    "OpRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<ProclaimResponse>(
+                    "ProclaimResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -557,108 +8103,78 @@ impl ::protobuf::Message for OpRequest {
         }
     }
 
-    fn default_instance() -> &'static OpRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OpRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ProclaimResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ProclaimResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OpRequest,
+            ptr: 0 as *const ProclaimResponse,
         };
         unsafe {
-            instance.get(OpRequest::new)
+            instance.get(ProclaimResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for OpRequest {
+impl ::protobuf::Clear for ProclaimResponse {
     fn clear(&mut self) {
-        self.clear_field_type();
-        self.clear_key();
-        self.clear_value();
-        self.clear_limit();
+        self.clear_header();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OpRequest {
+impl ::std::fmt::Debug for ProclaimResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OpRequest {
+impl ::protobuf::reflect::ProtobufValue for ProclaimResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct KvPair {
+pub struct LeaderRequest {
     // message fields
-    pub key: ::std::vec::Vec<u8>,
-    pub value: ::std::vec::Vec<u8>,
+    pub name: ::std::vec::Vec<u8>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl KvPair {
-    pub fn new() -> KvPair {
+impl LeaderRequest {
+    pub fn new() -> LeaderRequest {
         ::std::default::Default::default()
     }
 
-    // bytes key = 1;
-
-    pub fn clear_key(&mut self) {
-        self.key.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
-        self.key = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.key
-    }
-
-    // Take field
-    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
-    }
-
-    pub fn get_key(&self) -> &[u8] {
-        &self.key
-    }
-
-    // bytes value = 2;
+    // bytes name = 1;
 
-    pub fn clear_value(&mut self) {
-        self.value.clear();
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
-        self.value = v;
+    pub fn set_name(&mut self, v: ::std::vec::Vec<u8>) {
+        self.name = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.value
+    pub fn mut_name(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.name
     }
 
     // Take field
-    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    pub fn take_name(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.name, ::std::vec::Vec::new())
     }
 
-    pub fn get_value(&self) -> &[u8] {
-        &self.value
+    pub fn get_name(&self) -> &[u8] {
+        &self.name
     }
 }
 
-impl ::protobuf::Message for KvPair {
+impl ::protobuf::Message for LeaderRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -668,10 +8184,7 @@ impl ::protobuf::Message for KvPair {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.name)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -685,11 +8198,8 @@ impl ::protobuf::Message for KvPair {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.key.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.key);
-        }
-        if !self.value.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.name);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -697,11 +8207,8 @@ impl ::protobuf::Message for KvPair {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.key.is_empty() {
-            os.write_bytes(1, &self.key)?;
-        }
-        if !self.value.is_empty() {
-            os.write_bytes(2, &self.value)?;
+        if !self.name.is_empty() {
+            os.write_bytes(1, &self.name)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -733,8 +8240,8 @@ impl ::protobuf::Message for KvPair {
         Self::descriptor_static()
     }
 
-    fn new() -> KvPair {
-        KvPair::new()
+    fn new() -> LeaderRequest {
+        LeaderRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -746,17 +8253,12 @@ impl ::protobuf::Message for KvPair {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "key",
-                    |m: &KvPair| { &m.key },
-                    |m: &mut KvPair| { &mut m.key },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "value",
-                    |m: &KvPair| { &m.value },
-                    |m: &mut KvPair| { &mut m.value },
+                    "name",
+                    |m: &LeaderRequest| { &m.name },
+                    |m: &mut LeaderRequest| { &mut m.name },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<KvPair>(
-                    "KvPair",
+                ::protobuf::reflect::MessageDescriptor::new::<LeaderRequest>(
+                    "LeaderRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -764,80 +8266,126 @@ impl ::protobuf::Message for KvPair {
         }
     }
 
-    fn default_instance() -> &'static KvPair {
-        static mut instance: ::protobuf::lazy::Lazy<KvPair> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static LeaderRequest {
+        static mut instance: ::protobuf::lazy::Lazy<LeaderRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const KvPair,
+            ptr: 0 as *const LeaderRequest,
         };
         unsafe {
-            instance.get(KvPair::new)
+            instance.get(LeaderRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for KvPair {
+impl ::protobuf::Clear for LeaderRequest {
     fn clear(&mut self) {
-        self.clear_key();
-        self.clear_value();
+        self.clear_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for KvPair {
+impl ::std::fmt::Debug for LeaderRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for KvPair {
+impl ::protobuf::reflect::ProtobufValue for LeaderRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OpResponse {
+pub struct LeaderResponse {
     // message fields
-    pub kvs: ::protobuf::RepeatedField<KvPair>,
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
+    pub kv: ::protobuf::SingularPtrField<KvPair>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl OpResponse {
-    pub fn new() -> OpResponse {
+impl LeaderResponse {
+    pub fn new() -> LeaderResponse {
         ::std::default::Default::default()
     }
 
-    // repeated .mumpb.KvPair kvs = 1;
+    // .mumpb.ResponseHeader header = 1;
 
-    pub fn clear_kvs(&mut self) {
-        self.kvs.clear();
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_kvs(&mut self, v: ::protobuf::RepeatedField<KvPair>) {
-        self.kvs = v;
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_kvs(&mut self) -> &mut ::protobuf::RepeatedField<KvPair> {
-        &mut self.kvs
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_kvs(&mut self) -> ::protobuf::RepeatedField<KvPair> {
-        ::std::mem::replace(&mut self.kvs, ::protobuf::RepeatedField::new())
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
     }
 
-    pub fn get_kvs(&self) -> &[KvPair] {
-        &self.kvs
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
+
+    // .mumpb.KvPair kv = 2;
+
+    pub fn clear_kv(&mut self) {
+        self.kv.clear();
+    }
+
+    pub fn has_kv(&self) -> bool {
+        self.kv.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kv(&mut self, v: KvPair) {
+        self.kv = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kv(&mut self) -> &mut KvPair {
+        if self.kv.is_none() {
+            self.kv.set_default();
+        }
+        self.kv.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_kv(&mut self) -> KvPair {
+        self.kv.take().unwrap_or_else(|| KvPair::new())
+    }
+
+    pub fn get_kv(&self) -> &KvPair {
+        self.kv.as_ref().unwrap_or_else(|| KvPair::default_instance())
     }
 }
 
-impl ::protobuf::Message for OpResponse {
+impl ::protobuf::Message for LeaderResponse {
     fn is_initialized(&self) -> bool {
-        for v in &self.kvs {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.kv {
             if !v.is_initialized() {
                 return false;
             }
@@ -850,7 +8398,10 @@ impl ::protobuf::Message for OpResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.kvs)?;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.kv)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -864,21 +8415,30 @@ impl ::protobuf::Message for OpResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.kvs {
-            let len = value.compute_size();
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        }
+        if let Some(ref v) = self.kv.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.kvs {
+        if let Some(ref v) = self.header.as_ref() {
             os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
-        };
+        }
+        if let Some(ref v) = self.kv.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -909,8 +8469,8 @@ impl ::protobuf::Message for OpResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> OpResponse {
-        OpResponse::new()
+    fn new() -> LeaderResponse {
+        LeaderResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -921,13 +8481,18 @@ impl ::protobuf::Message for OpResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KvPair>>(
-                    "kvs",
-                    |m: &OpResponse| { &m.kvs },
-                    |m: &mut OpResponse| { &mut m.kvs },
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &LeaderResponse| { &m.header },
+                    |m: &mut LeaderResponse| { &mut m.header },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OpResponse>(
-                    "OpResponse",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KvPair>>(
+                    "kv",
+                    |m: &LeaderResponse| { &m.kv },
+                    |m: &mut LeaderResponse| { &mut m.kv },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LeaderResponse>(
+                    "LeaderResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -935,87 +8500,88 @@ impl ::protobuf::Message for OpResponse {
         }
     }
 
-    fn default_instance() -> &'static OpResponse {
-        static mut instance: ::protobuf::lazy::Lazy<OpResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static LeaderResponse {
+        static mut instance: ::protobuf::lazy::Lazy<LeaderResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OpResponse,
+            ptr: 0 as *const LeaderResponse,
         };
         unsafe {
-            instance.get(OpResponse::new)
+            instance.get(LeaderResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for OpResponse {
+impl ::protobuf::Clear for LeaderResponse {
     fn clear(&mut self) {
-        self.clear_kvs();
+        self.clear_header();
+        self.clear_kv();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OpResponse {
+impl ::std::fmt::Debug for LeaderResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OpResponse {
+impl ::protobuf::reflect::ProtobufValue for LeaderResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ConfRequest {
+pub struct ResignRequest {
     // message fields
-    pub change: ::protobuf::SingularPtrField<super::eraftpb::ConfChange>,
+    pub leader: ::protobuf::SingularPtrField<LeaderKey>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl ConfRequest {
-    pub fn new() -> ConfRequest {
+impl ResignRequest {
+    pub fn new() -> ResignRequest {
         ::std::default::Default::default()
     }
 
-    // .eraftpb.ConfChange change = 1;
+    // .mumpb.LeaderKey leader = 1;
 
-    pub fn clear_change(&mut self) {
-        self.change.clear();
+    pub fn clear_leader(&mut self) {
+        self.leader.clear();
     }
 
-    pub fn has_change(&self) -> bool {
-        self.change.is_some()
+    pub fn has_leader(&self) -> bool {
+        self.leader.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_change(&mut self, v: super::eraftpb::ConfChange) {
-        self.change = ::protobuf::SingularPtrField::some(v);
+    pub fn set_leader(&mut self, v: LeaderKey) {
+        self.leader = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_change(&mut self) -> &mut super::eraftpb::ConfChange {
-        if self.change.is_none() {
-            self.change.set_default();
+    pub fn mut_leader(&mut self) -> &mut LeaderKey {
+        if self.leader.is_none() {
+            self.leader.set_default();
         }
-        self.change.as_mut().unwrap()
+        self.leader.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_change(&mut self) -> super::eraftpb::ConfChange {
-        self.change.take().unwrap_or_else(|| super::eraftpb::ConfChange::new())
+    pub fn take_leader(&mut self) -> LeaderKey {
+        self.leader.take().unwrap_or_else(|| LeaderKey::new())
     }
 
-    pub fn get_change(&self) -> &super::eraftpb::ConfChange {
-        self.change.as_ref().unwrap_or_else(|| super::eraftpb::ConfChange::default_instance())
+    pub fn get_leader(&self) -> &LeaderKey {
+        self.leader.as_ref().unwrap_or_else(|| LeaderKey::default_instance())
     }
 }
 
-impl ::protobuf::Message for ConfRequest {
+impl ::protobuf::Message for ResignRequest {
     fn is_initialized(&self) -> bool {
-        for v in &self.change {
+        for v in &self.leader {
             if !v.is_initialized() {
                 return false;
             }
@@ -1028,7 +8594,7 @@ impl ::protobuf::Message for ConfRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.change)?;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.leader)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1042,7 +8608,7 @@ impl ::protobuf::Message for ConfRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.change.as_ref() {
+        if let Some(ref v) = self.leader.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
@@ -1052,7 +8618,7 @@ impl ::protobuf::Message for ConfRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.change.as_ref() {
+        if let Some(ref v) = self.leader.as_ref() {
             os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
@@ -1087,8 +8653,8 @@ impl ::protobuf::Message for ConfRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> ConfRequest {
-        ConfRequest::new()
+    fn new() -> ResignRequest {
+        ResignRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1099,13 +8665,13 @@ impl ::protobuf::Message for ConfRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::eraftpb::ConfChange>>(
-                    "change",
-                    |m: &ConfRequest| { &m.change },
-                    |m: &mut ConfRequest| { &mut m.change },
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LeaderKey>>(
+                    "leader",
+                    |m: &ResignRequest| { &m.leader },
+                    |m: &mut ResignRequest| { &mut m.leader },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ConfRequest>(
-                    "ConfRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<ResignRequest>(
+                    "ResignRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1113,51 +8679,91 @@ impl ::protobuf::Message for ConfRequest {
         }
     }
 
-    fn default_instance() -> &'static ConfRequest {
-        static mut instance: ::protobuf::lazy::Lazy<ConfRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ResignRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ResignRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ConfRequest,
+            ptr: 0 as *const ResignRequest,
         };
         unsafe {
-            instance.get(ConfRequest::new)
+            instance.get(ResignRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ConfRequest {
+impl ::protobuf::Clear for ResignRequest {
     fn clear(&mut self) {
-        self.clear_change();
+        self.clear_leader();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ConfRequest {
+impl ::std::fmt::Debug for ResignRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ConfRequest {
+impl ::protobuf::reflect::ProtobufValue for ResignRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ConfResponse {
+pub struct ResignResponse {
+    // message fields
+    pub header: ::protobuf::SingularPtrField<ResponseHeader>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl ConfResponse {
-    pub fn new() -> ConfResponse {
+impl ResignResponse {
+    pub fn new() -> ResignResponse {
         ::std::default::Default::default()
     }
+
+    // .mumpb.ResponseHeader header = 1;
+
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ResponseHeader) {
+        self.header = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ResponseHeader {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ResponseHeader {
+        self.header.take().unwrap_or_else(|| ResponseHeader::new())
+    }
+
+    pub fn get_header(&self) -> &ResponseHeader {
+        self.header.as_ref().unwrap_or_else(|| ResponseHeader::default_instance())
+    }
 }
 
-impl ::protobuf::Message for ConfResponse {
+impl ::protobuf::Message for ResignResponse {
     fn is_initialized(&self) -> bool {
+        for v in &self.header {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1165,6 +8771,9 @@ impl ::protobuf::Message for ConfResponse {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1177,12 +8786,21 @@ impl ::protobuf::Message for ConfResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        if let Some(ref v) = self.header.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1213,8 +8831,8 @@ impl ::protobuf::Message for ConfResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> ConfResponse {
-        ConfResponse::new()
+    fn new() -> ResignResponse {
+        ResignResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1224,9 +8842,14 @@ impl ::protobuf::Message for ConfResponse {
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<ConfResponse>(
-                    "ConfResponse",
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResponseHeader>>(
+                    "header",
+                    |m: &ResignResponse| { &m.header },
+                    |m: &mut ResignResponse| { &mut m.header },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ResignResponse>(
+                    "ResignResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1234,30 +8857,31 @@ impl ::protobuf::Message for ConfResponse {
         }
     }
 
-    fn default_instance() -> &'static ConfResponse {
-        static mut instance: ::protobuf::lazy::Lazy<ConfResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ResignResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ResignResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ConfResponse,
+            ptr: 0 as *const ResignResponse,
         };
         unsafe {
-            instance.get(ConfResponse::new)
+            instance.get(ResignResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ConfResponse {
+impl ::protobuf::Clear for ResignResponse {
     fn clear(&mut self) {
+        self.clear_header();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ConfResponse {
+impl ::std::fmt::Debug for ResignResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ConfResponse {
+impl ::protobuf::reflect::ProtobufValue for ResignResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -1269,6 +8893,7 @@ pub enum Op {
     Get = 1,
     Del = 2,
     Scan = 3,
+    Compact = 4,
 }
 
 impl ::protobuf::ProtobufEnum for Op {
@@ -1282,6 +8907,7 @@ impl ::protobuf::ProtobufEnum for Op {
             1 => ::std::option::Option::Some(Op::Get),
             2 => ::std::option::Option::Some(Op::Del),
             3 => ::std::option::Option::Some(Op::Scan),
+            4 => ::std::option::Option::Some(Op::Compact),
             _ => ::std::option::Option::None
         }
     }
@@ -1292,6 +8918,7 @@ impl ::protobuf::ProtobufEnum for Op {
             Op::Get,
             Op::Del,
             Op::Scan,
+            Op::Compact,
         ];
         values
     }
@@ -1324,22 +8951,371 @@ impl ::protobuf::reflect::ProtobufValue for Op {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ReadConsistency {
+    Linearizable = 0,
+    Stale = 1,
+    Lease = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ReadConsistency {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ReadConsistency> {
+        match value {
+            0 => ::std::option::Option::Some(ReadConsistency::Linearizable),
+            1 => ::std::option::Option::Some(ReadConsistency::Stale),
+            2 => ::std::option::Option::Some(ReadConsistency::Lease),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ReadConsistency] = &[
+            ReadConsistency::Linearizable,
+            ReadConsistency::Stale,
+            ReadConsistency::Lease,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("ReadConsistency", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for ReadConsistency {
+}
+
+impl ::std::default::Default for ReadConsistency {
+    fn default() -> Self {
+        ReadConsistency::Linearizable
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadConsistency {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CompareTarget {
+    Value = 0,
+    Version = 1,
+    CreateRevision = 2,
+    ModRevision = 3,
+    Exists = 4,
+}
+
+impl ::protobuf::ProtobufEnum for CompareTarget {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CompareTarget> {
+        match value {
+            0 => ::std::option::Option::Some(CompareTarget::Value),
+            1 => ::std::option::Option::Some(CompareTarget::Version),
+            2 => ::std::option::Option::Some(CompareTarget::CreateRevision),
+            3 => ::std::option::Option::Some(CompareTarget::ModRevision),
+            4 => ::std::option::Option::Some(CompareTarget::Exists),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CompareTarget] = &[
+            CompareTarget::Value,
+            CompareTarget::Version,
+            CompareTarget::CreateRevision,
+            CompareTarget::ModRevision,
+            CompareTarget::Exists,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("CompareTarget", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for CompareTarget {
+}
+
+impl ::std::default::Default for CompareTarget {
+    fn default() -> Self {
+        CompareTarget::Value
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompareTarget {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CompareResult {
+    Equal = 0,
+    Greater = 1,
+    Less = 2,
+    NotEqual = 3,
+}
+
+impl ::protobuf::ProtobufEnum for CompareResult {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CompareResult> {
+        match value {
+            0 => ::std::option::Option::Some(CompareResult::Equal),
+            1 => ::std::option::Option::Some(CompareResult::Greater),
+            2 => ::std::option::Option::Some(CompareResult::Less),
+            3 => ::std::option::Option::Some(CompareResult::NotEqual),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CompareResult] = &[
+            CompareResult::Equal,
+            CompareResult::Greater,
+            CompareResult::Less,
+            CompareResult::NotEqual,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("CompareResult", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for CompareResult {
+}
+
+impl ::std::default::Default for CompareResult {
+    fn default() -> Self {
+        CompareResult::Equal
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompareResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum EventType {
+    Put = 0,
+    Delete = 1,
+}
+
+impl ::protobuf::ProtobufEnum for EventType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<EventType> {
+        match value {
+            0 => ::std::option::Option::Some(EventType::Put),
+            1 => ::std::option::Option::Some(EventType::Delete),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [EventType] = &[
+            EventType::Put,
+            EventType::Delete,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("EventType", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for EventType {
+}
+
+impl ::std::default::Default for EventType {
+    fn default() -> Self {
+        EventType::Put
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EventType {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0bmumpb.proto\x12\x05mumpb\x1a\reraftpb.proto\"9\n\x0bRaftMessage\
     \x12*\n\x07message\x18\x01\x20\x01(\x0b2\x10.eraftpb.MessageR\x07message\
-    \"\x06\n\x04Done\"h\n\tOpRequest\x12\x1d\n\x04type\x18\x01\x20\x01(\x0e2\
-    \t.mumpb.OpR\x04type\x12\x10\n\x03key\x18\x02\x20\x01(\x0cR\x03key\x12\
-    \x14\n\x05value\x18\x03\x20\x01(\x0cR\x05value\x12\x14\n\x05limit\x18\
-    \x04\x20\x01(\rR\x05limit\"0\n\x06KvPair\x12\x10\n\x03key\x18\x01\x20\
-    \x01(\x0cR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value\"-\n\
-    \nOpResponse\x12\x1f\n\x03kvs\x18\x01\x20\x03(\x0b2\r.mumpb.KvPairR\x03k\
-    vs\":\n\x0bConfRequest\x12+\n\x06change\x18\x01\x20\x01(\x0b2\x13.eraftp\
-    b.ConfChangeR\x06change\"\x0e\n\x0cConfResponse*)\n\x02Op\x12\x07\n\x03S\
-    et\x10\0\x12\x07\n\x03Get\x10\x01\x12\x07\n\x03Del\x10\x02\x12\x08\n\x04\
-    Scan\x10\x032\x90\x01\n\x03Mum\x12+\n\x02Op\x12\x10.mumpb.OpRequest\x1a\
-    \x11.mumpb.OpResponse\"\0\x121\n\x04Conf\x12\x12.mumpb.ConfRequest\x1a\
-    \x13.mumpb.ConfResponse\"\0\x12)\n\x04Raft\x12\x12.mumpb.RaftMessage\x1a\
-    \x0b.mumpb.Done\"\0b\x06proto3\
+    \"\x06\n\x04Done\"V\n\rRequestHeader\x12\x17\n\x07node_id\x18\x01\x20\
+    \x01(\x04R\x06nodeId\x12\x0e\n\x02id\x18\x02\x20\x01(\x04R\x02id\x12\x1c\
+    \n\tforwarded\x18\x03\x20\x01(\x08R\tforwarded\"\xb0\x03\n\tOpRequest\
+    \x12\x1d\n\x04type\x18\x01\x20\x01(\x0e2\t.mumpb.OpR\x04type\x12\x10\n\
+    \x03key\x18\x02\x20\x01(\x0cR\x03key\x12\x14\n\x05value\x18\x03\x20\x01(\
+    \x0cR\x05value\x12\x14\n\x05limit\x18\x04\x20\x01(\rR\x05limit\x12,\n\
+    \x06header\x18\x05\x20\x01(\x0b2\x14.mumpb.RequestHeaderR\x06header\x128\
+    \n\x0bconsistency\x18\x06\x20\x01(\x0e2\x16.mumpb.ReadConsistencyR\x0bco\
+    nsistency\x12\x17\n\x07end_key\x18\x07\x20\x01(\x0cR\x06endKey\x12\x18\n\
+    \x07reverse\x18\x08\x20\x01(\x08R\x07reverse\x12\x1b\n\tkeys_only\x18\t\
+    \x20\x01(\x08R\x08keysOnly\x12\x1a\n\x08revision\x18\n\x20\x01(\x04R\x08\
+    revision\x12\x14\n\x05lease\x18\x0b\x20\x01(\x04R\x05lease\x12#\n\rexclu\
+    de_start\x18\x0c\x20\x01(\x08R\x0cexcludeStart\x12\x1f\n\x0binclude_end\
+    \x18\r\x20\x01(\x08R\nincludeEnd\x12\x16\n\x06prefix\x18\x0e\x20\x01(\
+    \x08R\x06prefix\"\xac\x01\n\x06KvPair\x12\x10\n\x03key\x18\x01\x20\x01(\
+    \x0cR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value\x12'\n\
+    \x0fcreate_revision\x18\x03\x20\x01(\x04R\x0ecreateRevision\x12!\n\x0cmo\
+    d_revision\x18\x04\x20\x01(\x04R\x0bmodRevision\x12\x18\n\x07version\x18\
+    \x05\x20\x01(\x04R\x07version\x12\x14\n\x05lease\x18\x06\x20\x01(\x04R\
+    \x05lease\",\n\x0eResponseHeader\x12\x1a\n\x08revision\x18\x01\x20\x01(\
+    \x04R\x08revision\"\x84\x01\n\nOpResponse\x12\x1f\n\x03kvs\x18\x01\x20\
+    \x03(\x0b2\r.mumpb.KvPairR\x03kvs\x12&\n\x07prev_kv\x18\x02\x20\x01(\x0b\
+    2\r.mumpb.KvPairR\x06prevKv\x12-\n\x06header\x18\x03\x20\x01(\x0b2\x15.m\
+    umpb.ResponseHeaderR\x06header\"\xbd\x01\n\x07Compare\x12,\n\x06result\
+    \x18\x01\x20\x01(\x0e2\x14.mumpb.CompareResultR\x06result\x12,\n\x06targ\
+    et\x18\x02\x20\x01(\x0e2\x14.mumpb.CompareTargetR\x06target\x12\x10\n\
+    \x03key\x18\x03\x20\x01(\x0cR\x03key\x12\x14\n\x05value\x18\x04\x20\x01(\
+    \x0cR\x05value\x12\x16\n\x06number\x18\x05\x20\x01(\x04R\x06number\x12\
+    \x16\n\x06exists\x18\x06\x20\x01(\x08R\x06exists\"\xbe\x01\n\nTxnRequest\
+    \x12*\n\x08compares\x18\x01\x20\x03(\x0b2\x0e.mumpb.CompareR\x08compares\
+    \x12*\n\x07success\x18\x02\x20\x03(\x0b2\x10.mumpb.OpRequestR\x07success\
+    \x12*\n\x07failure\x18\x03\x20\x03(\x0b2\x10.mumpb.OpRequestR\x07failure\
+    \x12,\n\x06header\x18\x04\x20\x01(\x0b2\x14.mumpb.RequestHeaderR\x06head\
+    er\"\x8b\x01\n\x0bTxnResponse\x12-\n\x06header\x18\x01\x20\x01(\x0b2\x15\
+    .mumpb.ResponseHeaderR\x06header\x12\x1c\n\tsucceeded\x18\x02\x20\x01(\
+    \x08R\tsucceeded\x12/\n\tresponses\x18\x03\x20\x03(\x0b2\x11.mumpb.OpRes\
+    ponseR\tresponses\"c\n\x11LeaseGrantRequest\x12\x10\n\x03ttl\x18\x01\x20\
+    \x01(\x04R\x03ttl\x12\x0e\n\x02id\x18\x02\x20\x01(\x04R\x02id\x12,\n\x06\
+    header\x18\x03\x20\x01(\x0b2\x14.mumpb.RequestHeaderR\x06header\"e\n\x12\
+    LeaseGrantResponse\x12-\n\x06header\x18\x01\x20\x01(\x0b2\x15.mumpb.Resp\
+    onseHeaderR\x06header\x12\x0e\n\x02id\x18\x02\x20\x01(\x04R\x02id\x12\
+    \x10\n\x03ttl\x18\x03\x20\x01(\x04R\x03ttl\"R\n\x12LeaseRevokeRequest\
+    \x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12,\n\x06header\x18\x02\
+    \x20\x01(\x0b2\x14.mumpb.RequestHeaderR\x06header\"D\n\x13LeaseRevokeRes\
+    ponse\x12-\n\x06header\x18\x01\x20\x01(\x0b2\x15.mumpb.ResponseHeaderR\
+    \x06header\"'\n\x15LeaseKeepAliveRequest\x12\x0e\n\x02id\x18\x01\x20\x01\
+    (\x04R\x02id\"i\n\x16LeaseKeepAliveResponse\x12-\n\x06header\x18\x01\x20\
+    \x01(\x0b2\x15.mumpb.ResponseHeaderR\x06header\x12\x0e\n\x02id\x18\x02\
+    \x20\x01(\x04R\x02id\x12\x10\n\x03ttl\x18\x03\x20\x01(\x04R\x03ttl\"<\n\
+    \x16LeaseTimeToLiveRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\
+    \x12\x12\n\x04keys\x18\x02\x20\x01(\x08R\x04keys\"\x9f\x01\n\x17LeaseTim\
+    eToLiveResponse\x12-\n\x06header\x18\x01\x20\x01(\x0b2\x15.mumpb.Respons\
+    eHeaderR\x06header\x12\x0e\n\x02id\x18\x02\x20\x01(\x04R\x02id\x12\x10\n\
+    \x03ttl\x18\x03\x20\x01(\x03R\x03ttl\x12\x1f\n\x0bgranted_ttl\x18\x04\
+    \x20\x01(\x04R\ngrantedTtl\x12\x12\n\x04keys\x18\x05\x20\x03(\x0cR\x04ke\
+    ys\"\xcd\x01\n\x0bRaftCommand\x12\x20\n\x02op\x18\x01\x20\x01(\x0b2\x10.\
+    mumpb.OpRequestR\x02op\x12#\n\x03txn\x18\x02\x20\x01(\x0b2\x11.mumpb.Txn\
+    RequestR\x03txn\x129\n\x0blease_grant\x18\x03\x20\x01(\x0b2\x18.mumpb.Le\
+    aseGrantRequestR\nleaseGrant\x12<\n\x0clease_revoke\x18\x04\x20\x01(\x0b\
+    2\x19.mumpb.LeaseRevokeRequestR\x0bleaseRevoke\"h\n\x0bConfRequest\x12+\
+    \n\x06change\x18\x01\x20\x01(\x0b2\x13.eraftpb.ConfChangeR\x06change\x12\
+    ,\n\x06header\x18\x02\x20\x01(\x0b2\x14.mumpb.RequestHeaderR\x06header\"\
+    \x0e\n\x0cConfResponse\"\x89\x01\n\x0cWatchRequest\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\x0cR\x03key\x12\x17\n\x07end_key\x18\x02\x20\x01(\x0cR\x06\
+    endKey\x12%\n\x0estart_revision\x18\x03\x20\x01(\x04R\rstartRevision\x12\
+    '\n\x0fprogress_notify\x18\x04\x20\x01(\x08R\x0eprogressNotify\"L\n\x05E\
+    vent\x12$\n\x04type\x18\x01\x20\x01(\x0e2\x10.mumpb.EventTypeR\x04type\
+    \x12\x1d\n\x02kv\x18\x02\x20\x01(\x0b2\r.mumpb.KvPairR\x02kv\"\xa9\x01\n\
+    \rWatchResponse\x12-\n\x06header\x18\x01\x20\x01(\x0b2\x15.mumpb.Respons\
+    eHeaderR\x06header\x12\x18\n\x07created\x18\x02\x20\x01(\x08R\x07created\
+    \x12$\n\x06events\x18\x03\x20\x03(\x0b2\x0c.mumpb.EventR\x06events\x12)\
+    \n\x10compact_revision\x18\x04\x20\x01(\x04R\x0fcompactRevision\"7\n\x0b\
+    LockRequest\x12\x12\n\x04name\x18\x01\x20\x01(\x0cR\x04name\x12\x14\n\
+    \x05lease\x18\x02\x20\x01(\x04R\x05lease\"O\n\x0cLockResponse\x12-\n\x06\
+    header\x18\x01\x20\x01(\x0b2\x15.mumpb.ResponseHeaderR\x06header\x12\x10\
+    \n\x03key\x18\x02\x20\x01(\x0cR\x03key\"=\n\rUnlockRequest\x12\x10\n\x03\
+    key\x18\x01\x20\x01(\x0cR\x03key\x12\x1a\n\x08revision\x18\x02\x20\x01(\
+    \x04R\x08revision\"?\n\x0eUnlockResponse\x12-\n\x06header\x18\x01\x20\
+    \x01(\x0b2\x15.mumpb.ResponseHeaderR\x06header\"c\n\tLeaderKey\x12\x12\n\
+    \x04name\x18\x01\x20\x01(\x0cR\x04name\x12\x10\n\x03key\x18\x02\x20\x01(\
+    \x0cR\x03key\x12\x1a\n\x08revision\x18\x03\x20\x01(\x04R\x08revision\x12\
+    \x14\n\x05lease\x18\x04\x20\x01(\x04R\x05lease\"Q\n\x0fCampaignRequest\
+    \x12\x12\n\x04name\x18\x01\x20\x01(\x0cR\x04name\x12\x14\n\x05lease\x18\
+    \x02\x20\x01(\x04R\x05lease\x12\x14\n\x05value\x18\x03\x20\x01(\x0cR\x05\
+    value\"k\n\x10CampaignResponse\x12-\n\x06header\x18\x01\x20\x01(\x0b2\
+    \x15.mumpb.ResponseHeaderR\x06header\x12(\n\x06leader\x18\x02\x20\x01(\
+    \x0b2\x10.mumpb.LeaderKeyR\x06leader\"Q\n\x0fProclaimRequest\x12(\n\x06l\
+    eader\x18\x01\x20\x01(\x0b2\x10.mumpb.LeaderKeyR\x06leader\x12\x14\n\x05\
+    value\x18\x02\x20\x01(\x0cR\x05value\"A\n\x10ProclaimResponse\x12-\n\x06\
+    header\x18\x01\x20\x01(\x0b2\x15.mumpb.ResponseHeaderR\x06header\"#\n\rL\
+    eaderRequest\x12\x12\n\x04name\x18\x01\x20\x01(\x0cR\x04name\"^\n\x0eLea\
+    derResponse\x12-\n\x06header\x18\x01\x20\x01(\x0b2\x15.mumpb.ResponseHea\
+    derR\x06header\x12\x1d\n\x02kv\x18\x02\x20\x01(\x0b2\r.mumpb.KvPairR\x02\
+    kv\"9\n\rResignRequest\x12(\n\x06leader\x18\x01\x20\x01(\x0b2\x10.mumpb.\
+    LeaderKeyR\x06leader\"?\n\x0eResignResponse\x12-\n\x06header\x18\x01\x20\
+    \x01(\x0b2\x15.mumpb.ResponseHeaderR\x06header*6\n\x02Op\x12\x07\n\x03Se\
+    t\x10\0\x12\x07\n\x03Get\x10\x01\x12\x07\n\x03Del\x10\x02\x12\x08\n\x04S\
+    can\x10\x03\x12\x0b\n\x07Compact\x10\x04*9\n\x0fReadConsistency\x12\x10\
+    \n\x0cLinearizable\x10\0\x12\t\n\x05Stale\x10\x01\x12\t\n\x05Lease\x10\
+    \x02*X\n\rCompareTarget\x12\t\n\x05Value\x10\0\x12\x0b\n\x07Version\x10\
+    \x01\x12\x12\n\x0eCreateRevision\x10\x02\x12\x0f\n\x0bModRevision\x10\
+    \x03\x12\n\n\x06Exists\x10\x04*?\n\rCompareResult\x12\t\n\x05Equal\x10\0\
+    \x12\x0b\n\x07Greater\x10\x01\x12\x08\n\x04Less\x10\x02\x12\x0c\n\x08Not\
+    Equal\x10\x03*\x20\n\tEventType\x12\x07\n\x03Put\x10\0\x12\n\n\x06Delete\
+    \x10\x012\xae\x04\n\x03Mum\x12+\n\x02Op\x12\x10.mumpb.OpRequest\x1a\x11.\
+    mumpb.OpResponse\"\0\x121\n\x04Conf\x12\x12.mumpb.ConfRequest\x1a\x13.mu\
+    mpb.ConfResponse\"\0\x12)\n\x04Raft\x12\x12.mumpb.RaftMessage\x1a\x0b.mu\
+    mpb.Done\"\0\x126\n\x05Watch\x12\x13.mumpb.WatchRequest\x1a\x14.mumpb.Wa\
+    tchResponse\"\00\x01\x12.\n\x03Txn\x12\x11.mumpb.TxnRequest\x1a\x12.mump\
+    b.TxnResponse\"\0\x12C\n\nLeaseGrant\x12\x18.mumpb.LeaseGrantRequest\x1a\
+    \x19.mumpb.LeaseGrantResponse\"\0\x12F\n\x0bLeaseRevoke\x12\x19.mumpb.Le\
+    aseRevokeRequest\x1a\x1a.mumpb.LeaseRevokeResponse\"\0\x12S\n\x0eLeaseKe\
+    epAlive\x12\x1c.mumpb.LeaseKeepAliveRequest\x1a\x1d.mumpb.LeaseKeepAlive\
+    Response\"\0(\x010\x01\x12R\n\x0fLeaseTimeToLive\x12\x1d.mumpb.LeaseTime\
+    ToLiveRequest\x1a\x1e.mumpb.LeaseTimeToLiveResponse\"\02r\n\x04Lock\x121\
+    \n\x04Lock\x12\x12.mumpb.LockRequest\x1a\x13.mumpb.LockResponse\"\0\x127\
+    \n\x06Unlock\x12\x14.mumpb.UnlockRequest\x1a\x15.mumpb.UnlockResponse\"\
+    \02\xb6\x02\n\x08Election\x12=\n\x08Campaign\x12\x16.mumpb.CampaignReque\
+    st\x1a\x17.mumpb.CampaignResponse\"\0\x12=\n\x08Proclaim\x12\x16.mumpb.P\
+    roclaimRequest\x1a\x17.mumpb.ProclaimResponse\"\0\x127\n\x06Leader\x12\
+    \x14.mumpb.LeaderRequest\x1a\x15.mumpb.LeaderResponse\"\0\x12:\n\x07Obse\
+    rve\x12\x14.mumpb.LeaderRequest\x1a\x15.mumpb.LeaderResponse\"\00\x01\
+    \x127\n\x06Resign\x12\x14.mumpb.ResignRequest\x1a\x15.mumpb.ResignRespon\
+    se\"\0b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
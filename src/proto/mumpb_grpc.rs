@@ -39,6 +39,48 @@ const METHOD_MUM_RAFT: ::grpcio::Method<super::mumpb::RaftMessage, super::mumpb:
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_MUM_WATCH: ::grpcio::Method<super::mumpb::WatchRequest, super::mumpb::WatchResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/mumpb.Mum/Watch",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MUM_TXN: ::grpcio::Method<super::mumpb::TxnRequest, super::mumpb::TxnResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/mumpb.Mum/Txn",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MUM_LEASE_GRANT: ::grpcio::Method<super::mumpb::LeaseGrantRequest, super::mumpb::LeaseGrantResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/mumpb.Mum/LeaseGrant",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MUM_LEASE_REVOKE: ::grpcio::Method<super::mumpb::LeaseRevokeRequest, super::mumpb::LeaseRevokeResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/mumpb.Mum/LeaseRevoke",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MUM_LEASE_KEEP_ALIVE: ::grpcio::Method<super::mumpb::LeaseKeepAliveRequest, super::mumpb::LeaseKeepAliveResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Duplex,
+    name: "/mumpb.Mum/LeaseKeepAlive",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MUM_LEASE_TIME_TO_LIVE: ::grpcio::Method<super::mumpb::LeaseTimeToLiveRequest, super::mumpb::LeaseTimeToLiveResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/mumpb.Mum/LeaseTimeToLive",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 pub struct MumClient {
     client: ::grpcio::Client,
 }
@@ -97,6 +139,86 @@ impl MumClient {
     pub fn raft_async(&self, req: &super::mumpb::RaftMessage) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::Done>> {
         self.raft_async_opt(req, ::grpcio::CallOption::default())
     }
+
+    pub fn watch_opt(&self, req: &super::mumpb::WatchRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::mumpb::WatchResponse>> {
+        self.client.server_streaming(&METHOD_MUM_WATCH, req, opt)
+    }
+
+    pub fn watch(&self, req: &super::mumpb::WatchRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::mumpb::WatchResponse>> {
+        self.watch_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn txn_opt(&self, req: &super::mumpb::TxnRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::mumpb::TxnResponse> {
+        self.client.unary_call(&METHOD_MUM_TXN, req, opt)
+    }
+
+    pub fn txn(&self, req: &super::mumpb::TxnRequest) -> ::grpcio::Result<super::mumpb::TxnResponse> {
+        self.txn_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn txn_async_opt(&self, req: &super::mumpb::TxnRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::TxnResponse>> {
+        self.client.unary_call_async(&METHOD_MUM_TXN, req, opt)
+    }
+
+    pub fn txn_async(&self, req: &super::mumpb::TxnRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::TxnResponse>> {
+        self.txn_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn lease_grant_opt(&self, req: &super::mumpb::LeaseGrantRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::mumpb::LeaseGrantResponse> {
+        self.client.unary_call(&METHOD_MUM_LEASE_GRANT, req, opt)
+    }
+
+    pub fn lease_grant(&self, req: &super::mumpb::LeaseGrantRequest) -> ::grpcio::Result<super::mumpb::LeaseGrantResponse> {
+        self.lease_grant_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn lease_grant_async_opt(&self, req: &super::mumpb::LeaseGrantRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::LeaseGrantResponse>> {
+        self.client.unary_call_async(&METHOD_MUM_LEASE_GRANT, req, opt)
+    }
+
+    pub fn lease_grant_async(&self, req: &super::mumpb::LeaseGrantRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::LeaseGrantResponse>> {
+        self.lease_grant_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn lease_revoke_opt(&self, req: &super::mumpb::LeaseRevokeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::mumpb::LeaseRevokeResponse> {
+        self.client.unary_call(&METHOD_MUM_LEASE_REVOKE, req, opt)
+    }
+
+    pub fn lease_revoke(&self, req: &super::mumpb::LeaseRevokeRequest) -> ::grpcio::Result<super::mumpb::LeaseRevokeResponse> {
+        self.lease_revoke_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn lease_revoke_async_opt(&self, req: &super::mumpb::LeaseRevokeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::LeaseRevokeResponse>> {
+        self.client.unary_call_async(&METHOD_MUM_LEASE_REVOKE, req, opt)
+    }
+
+    pub fn lease_revoke_async(&self, req: &super::mumpb::LeaseRevokeRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::LeaseRevokeResponse>> {
+        self.lease_revoke_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn lease_keep_alive_opt(&self, opt: ::grpcio::CallOption) -> ::grpcio::Result<(::grpcio::ClientDuplexSender<super::mumpb::LeaseKeepAliveRequest>, ::grpcio::ClientDuplexReceiver<super::mumpb::LeaseKeepAliveResponse>)> {
+        self.client.duplex_streaming(&METHOD_MUM_LEASE_KEEP_ALIVE, opt)
+    }
+
+    pub fn lease_keep_alive(&self) -> ::grpcio::Result<(::grpcio::ClientDuplexSender<super::mumpb::LeaseKeepAliveRequest>, ::grpcio::ClientDuplexReceiver<super::mumpb::LeaseKeepAliveResponse>)> {
+        self.lease_keep_alive_opt(::grpcio::CallOption::default())
+    }
+
+    pub fn lease_time_to_live_opt(&self, req: &super::mumpb::LeaseTimeToLiveRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::mumpb::LeaseTimeToLiveResponse> {
+        self.client.unary_call(&METHOD_MUM_LEASE_TIME_TO_LIVE, req, opt)
+    }
+
+    pub fn lease_time_to_live(&self, req: &super::mumpb::LeaseTimeToLiveRequest) -> ::grpcio::Result<super::mumpb::LeaseTimeToLiveResponse> {
+        self.lease_time_to_live_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn lease_time_to_live_async_opt(&self, req: &super::mumpb::LeaseTimeToLiveRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::LeaseTimeToLiveResponse>> {
+        self.client.unary_call_async(&METHOD_MUM_LEASE_TIME_TO_LIVE, req, opt)
+    }
+
+    pub fn lease_time_to_live_async(&self, req: &super::mumpb::LeaseTimeToLiveRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::LeaseTimeToLiveResponse>> {
+        self.lease_time_to_live_async_opt(req, ::grpcio::CallOption::default())
+    }
     pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item = (), Error = ()> + Send + 'static {
         self.client.spawn(f)
     }
@@ -106,6 +228,12 @@ pub trait Mum {
     fn op(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::OpRequest, sink: ::grpcio::UnarySink<super::mumpb::OpResponse>);
     fn conf(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::ConfRequest, sink: ::grpcio::UnarySink<super::mumpb::ConfResponse>);
     fn raft(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::RaftMessage, sink: ::grpcio::UnarySink<super::mumpb::Done>);
+    fn watch(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::WatchRequest, sink: ::grpcio::ServerStreamingSink<super::mumpb::WatchResponse>);
+    fn txn(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::TxnRequest, sink: ::grpcio::UnarySink<super::mumpb::TxnResponse>);
+    fn lease_grant(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::LeaseGrantRequest, sink: ::grpcio::UnarySink<super::mumpb::LeaseGrantResponse>);
+    fn lease_revoke(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::LeaseRevokeRequest, sink: ::grpcio::UnarySink<super::mumpb::LeaseRevokeResponse>);
+    fn lease_keep_alive(&self, ctx: ::grpcio::RpcContext, stream: ::grpcio::RequestStream<super::mumpb::LeaseKeepAliveRequest>, sink: ::grpcio::DuplexSink<super::mumpb::LeaseKeepAliveResponse>);
+    fn lease_time_to_live(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::LeaseTimeToLiveRequest, sink: ::grpcio::UnarySink<super::mumpb::LeaseTimeToLiveResponse>);
 }
 
 pub fn create_mum<S: Mum + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
@@ -122,5 +250,263 @@ pub fn create_mum<S: Mum + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
     builder = builder.add_unary_handler(&METHOD_MUM_RAFT, move |ctx, req, resp| {
         instance.raft(ctx, req, resp)
     });
+    let instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_MUM_WATCH, move |ctx, req, resp| {
+        instance.watch(ctx, req, resp)
+    });
+    let instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MUM_TXN, move |ctx, req, resp| {
+        instance.txn(ctx, req, resp)
+    });
+    let instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MUM_LEASE_GRANT, move |ctx, req, resp| {
+        instance.lease_grant(ctx, req, resp)
+    });
+    let instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MUM_LEASE_REVOKE, move |ctx, req, resp| {
+        instance.lease_revoke(ctx, req, resp)
+    });
+    let instance = s.clone();
+    builder = builder.add_duplex_streaming_handler(&METHOD_MUM_LEASE_KEEP_ALIVE, move |ctx, req, resp| {
+        instance.lease_keep_alive(ctx, req, resp)
+    });
+    let instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MUM_LEASE_TIME_TO_LIVE, move |ctx, req, resp| {
+        instance.lease_time_to_live(ctx, req, resp)
+    });
+    builder.build()
+}
+
+const METHOD_LOCK_LOCK: ::grpcio::Method<super::mumpb::LockRequest, super::mumpb::LockResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/mumpb.Lock/Lock",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_LOCK_UNLOCK: ::grpcio::Method<super::mumpb::UnlockRequest, super::mumpb::UnlockResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/mumpb.Lock/Unlock",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+pub struct LockClient {
+    client: ::grpcio::Client,
+}
+
+impl LockClient {
+    pub fn new(channel: ::grpcio::Channel) -> Self {
+        LockClient {
+            client: ::grpcio::Client::new(channel),
+        }
+    }
+
+    pub fn lock_opt(&self, req: &super::mumpb::LockRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::mumpb::LockResponse> {
+        self.client.unary_call(&METHOD_LOCK_LOCK, req, opt)
+    }
+
+    pub fn lock(&self, req: &super::mumpb::LockRequest) -> ::grpcio::Result<super::mumpb::LockResponse> {
+        self.lock_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn lock_async_opt(&self, req: &super::mumpb::LockRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::LockResponse>> {
+        self.client.unary_call_async(&METHOD_LOCK_LOCK, req, opt)
+    }
+
+    pub fn lock_async(&self, req: &super::mumpb::LockRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::LockResponse>> {
+        self.lock_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn unlock_opt(&self, req: &super::mumpb::UnlockRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::mumpb::UnlockResponse> {
+        self.client.unary_call(&METHOD_LOCK_UNLOCK, req, opt)
+    }
+
+    pub fn unlock(&self, req: &super::mumpb::UnlockRequest) -> ::grpcio::Result<super::mumpb::UnlockResponse> {
+        self.unlock_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn unlock_async_opt(&self, req: &super::mumpb::UnlockRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::UnlockResponse>> {
+        self.client.unary_call_async(&METHOD_LOCK_UNLOCK, req, opt)
+    }
+
+    pub fn unlock_async(&self, req: &super::mumpb::UnlockRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::UnlockResponse>> {
+        self.unlock_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item = (), Error = ()> + Send + 'static {
+        self.client.spawn(f)
+    }
+}
+
+pub trait Lock {
+    fn lock(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::LockRequest, sink: ::grpcio::UnarySink<super::mumpb::LockResponse>);
+    fn unlock(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::UnlockRequest, sink: ::grpcio::UnarySink<super::mumpb::UnlockResponse>);
+}
+
+pub fn create_lock<S: Lock + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
+    let mut builder = ::grpcio::ServiceBuilder::new();
+    let instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_LOCK_LOCK, move |ctx, req, resp| {
+        instance.lock(ctx, req, resp)
+    });
+    let instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_LOCK_UNLOCK, move |ctx, req, resp| {
+        instance.unlock(ctx, req, resp)
+    });
+    builder.build()
+}
+
+const METHOD_ELECTION_CAMPAIGN: ::grpcio::Method<super::mumpb::CampaignRequest, super::mumpb::CampaignResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/mumpb.Election/Campaign",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_ELECTION_PROCLAIM: ::grpcio::Method<super::mumpb::ProclaimRequest, super::mumpb::ProclaimResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/mumpb.Election/Proclaim",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_ELECTION_LEADER: ::grpcio::Method<super::mumpb::LeaderRequest, super::mumpb::LeaderResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/mumpb.Election/Leader",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_ELECTION_OBSERVE: ::grpcio::Method<super::mumpb::LeaderRequest, super::mumpb::LeaderResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/mumpb.Election/Observe",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_ELECTION_RESIGN: ::grpcio::Method<super::mumpb::ResignRequest, super::mumpb::ResignResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/mumpb.Election/Resign",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+pub struct ElectionClient {
+    client: ::grpcio::Client,
+}
+
+impl ElectionClient {
+    pub fn new(channel: ::grpcio::Channel) -> Self {
+        ElectionClient {
+            client: ::grpcio::Client::new(channel),
+        }
+    }
+
+    pub fn campaign_opt(&self, req: &super::mumpb::CampaignRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::mumpb::CampaignResponse> {
+        self.client.unary_call(&METHOD_ELECTION_CAMPAIGN, req, opt)
+    }
+
+    pub fn campaign(&self, req: &super::mumpb::CampaignRequest) -> ::grpcio::Result<super::mumpb::CampaignResponse> {
+        self.campaign_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn campaign_async_opt(&self, req: &super::mumpb::CampaignRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::CampaignResponse>> {
+        self.client.unary_call_async(&METHOD_ELECTION_CAMPAIGN, req, opt)
+    }
+
+    pub fn campaign_async(&self, req: &super::mumpb::CampaignRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::CampaignResponse>> {
+        self.campaign_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn proclaim_opt(&self, req: &super::mumpb::ProclaimRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::mumpb::ProclaimResponse> {
+        self.client.unary_call(&METHOD_ELECTION_PROCLAIM, req, opt)
+    }
+
+    pub fn proclaim(&self, req: &super::mumpb::ProclaimRequest) -> ::grpcio::Result<super::mumpb::ProclaimResponse> {
+        self.proclaim_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn proclaim_async_opt(&self, req: &super::mumpb::ProclaimRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::ProclaimResponse>> {
+        self.client.unary_call_async(&METHOD_ELECTION_PROCLAIM, req, opt)
+    }
+
+    pub fn proclaim_async(&self, req: &super::mumpb::ProclaimRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::ProclaimResponse>> {
+        self.proclaim_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn leader_opt(&self, req: &super::mumpb::LeaderRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::mumpb::LeaderResponse> {
+        self.client.unary_call(&METHOD_ELECTION_LEADER, req, opt)
+    }
+
+    pub fn leader(&self, req: &super::mumpb::LeaderRequest) -> ::grpcio::Result<super::mumpb::LeaderResponse> {
+        self.leader_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn leader_async_opt(&self, req: &super::mumpb::LeaderRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::LeaderResponse>> {
+        self.client.unary_call_async(&METHOD_ELECTION_LEADER, req, opt)
+    }
+
+    pub fn leader_async(&self, req: &super::mumpb::LeaderRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::LeaderResponse>> {
+        self.leader_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn observe_opt(&self, req: &super::mumpb::LeaderRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::mumpb::LeaderResponse>> {
+        self.client.server_streaming(&METHOD_ELECTION_OBSERVE, req, opt)
+    }
+
+    pub fn observe(&self, req: &super::mumpb::LeaderRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::mumpb::LeaderResponse>> {
+        self.observe_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn resign_opt(&self, req: &super::mumpb::ResignRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::mumpb::ResignResponse> {
+        self.client.unary_call(&METHOD_ELECTION_RESIGN, req, opt)
+    }
+
+    pub fn resign(&self, req: &super::mumpb::ResignRequest) -> ::grpcio::Result<super::mumpb::ResignResponse> {
+        self.resign_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn resign_async_opt(&self, req: &super::mumpb::ResignRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::ResignResponse>> {
+        self.client.unary_call_async(&METHOD_ELECTION_RESIGN, req, opt)
+    }
+
+    pub fn resign_async(&self, req: &super::mumpb::ResignRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::mumpb::ResignResponse>> {
+        self.resign_async_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item = (), Error = ()> + Send + 'static {
+        self.client.spawn(f)
+    }
+}
+
+pub trait Election {
+    fn campaign(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::CampaignRequest, sink: ::grpcio::UnarySink<super::mumpb::CampaignResponse>);
+    fn proclaim(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::ProclaimRequest, sink: ::grpcio::UnarySink<super::mumpb::ProclaimResponse>);
+    fn leader(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::LeaderRequest, sink: ::grpcio::UnarySink<super::mumpb::LeaderResponse>);
+    fn observe(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::LeaderRequest, sink: ::grpcio::ServerStreamingSink<super::mumpb::LeaderResponse>);
+    fn resign(&self, ctx: ::grpcio::RpcContext, req: super::mumpb::ResignRequest, sink: ::grpcio::UnarySink<super::mumpb::ResignResponse>);
+}
+
+pub fn create_election<S: Election + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
+    let mut builder = ::grpcio::ServiceBuilder::new();
+    let instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_ELECTION_CAMPAIGN, move |ctx, req, resp| {
+        instance.campaign(ctx, req, resp)
+    });
+    let instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_ELECTION_PROCLAIM, move |ctx, req, resp| {
+        instance.proclaim(ctx, req, resp)
+    });
+    let instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_ELECTION_LEADER, move |ctx, req, resp| {
+        instance.leader(ctx, req, resp)
+    });
+    let instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_ELECTION_OBSERVE, move |ctx, req, resp| {
+        instance.observe(ctx, req, resp)
+    });
+    let instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_ELECTION_RESIGN, move |ctx, req, resp| {
+        instance.resign(ctx, req, resp)
+    });
     builder.build()
 }
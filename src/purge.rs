@@ -0,0 +1,172 @@
+use errors::*;
+use fs2::FileExt;
+use metrics::*;
+use snap::Snapshotter;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+use util::sync_dir;
+use wal::{first_index, obsolete_segments};
+
+// how often the obsolete files are looked for
+const PURGE_INTERVAL_SECS: u64 = 30;
+
+/// Retention of the wal segments made obsolete by the snapshots
+#[derive(Clone, Debug, PartialEq)]
+pub struct PurgeOptions {
+    /// Obsolete segments kept on top of the ones still needed
+    pub max_wals: usize,
+    /// Newest snapshots which the wal is kept for, so that the member can
+    /// still start from an older one if the newest is broken. The older
+    /// snapshots are deleted
+    pub max_snapshots: usize,
+}
+
+/// Deletes the wal segments entirely covered by the snapshots, then the
+/// snapshots the wal was purged past
+pub struct Purger {
+    wal_dir: PathBuf,
+    snapshotter: Snapshotter,
+    options: PurgeOptions,
+}
+
+impl Purger {
+    pub fn new<P: AsRef<Path>>(wal_dir: P, snap_dir: P, options: PurgeOptions) -> Purger {
+        Purger {
+            wal_dir: wal_dir.as_ref().to_path_buf(),
+            // only the names of the snapshots are read
+            snapshotter: Snapshotter::new(snap_dir, None),
+            options: options,
+        }
+    }
+
+    /// Purges periodically in a background thread, for as long as the
+    /// process lives
+    pub fn spawn(self) -> Result<()> {
+        thread::Builder::new()
+            .name("purge".to_owned())
+            .spawn(move || loop {
+                thread::sleep(Duration::from_secs(PURGE_INTERVAL_SECS));
+                if let Err(e) = self.purge() {
+                    warn!("failed to purge wal {:?}: {}", self.wal_dir, e);
+                }
+                if let Err(e) = self.purge_snapshots() {
+                    warn!("failed to purge snapshots: {}", e);
+                }
+            })?;
+        Ok(())
+    }
+
+    /// Deletes the obsolete segments but the newest `max_wals` of them,
+    /// returns how many were deleted
+    pub fn purge(&self) -> Result<usize> {
+        let index = match self
+            .snapshotter
+            .indexes()?
+            .into_iter()
+            .take(self.options.max_snapshots.max(1))
+            .last()
+        {
+            Some(index) => index,
+            None => return Ok(0),
+        };
+        let obsolete = obsolete_segments(&self.wal_dir, index)?;
+        let n = obsolete.len().saturating_sub(self.options.max_wals);
+        let mut purged = 0;
+        for path in &obsolete[..n] {
+            // the wal still holds the segments it may read, the newer ones
+            // are locked as well
+            if !purge_file(path, "wal")? {
+                break;
+            }
+            purged += 1;
+        }
+        if purged > 0 {
+            sync_dir(&self.wal_dir)?;
+        }
+        Ok(purged)
+    }
+
+    /// Deletes the snapshots but the newest `max_snapshots` of them, which
+    /// the wal was purged past, returns how many were deleted
+    pub fn purge_snapshots(&self) -> Result<usize> {
+        let wal_start = match first_index(&self.wal_dir)? {
+            Some(index) => index,
+            None => return Ok(0),
+        };
+        self.snapshotter
+            .purge(self.options.max_snapshots, wal_start)
+    }
+}
+
+/// Removes the file unless someone holds its lock, returns whether it did
+pub fn purge_file(path: &Path, ty: &str) -> Result<bool> {
+    let f = File::open(path)?;
+    if f.try_lock_exclusive().is_err() {
+        return Ok(false);
+    }
+    let size = f.metadata()?.len();
+    fs::remove_file(path)?;
+    info!("purged {} file {:?}", ty, path);
+    PURGED_FILES_COUNTER_VEC.with_label_values(&[ty]).inc();
+    PURGED_BYTES_COUNTER_VEC
+        .with_label_values(&[ty])
+        .inc_by(size as i64);
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use util::create_dir;
+
+    fn touch<P: AsRef<Path>>(dir: P, name: &str) {
+        File::create(dir.as_ref().join(name)).unwrap();
+    }
+
+    #[test]
+    fn purge_test() {
+        let wal_dir = "./file_tests/purge_wal";
+        let snap_dir = "./file_tests/purge_snap";
+        create_dir(wal_dir).unwrap();
+        create_dir(snap_dir).unwrap();
+        for &(seq, index) in &[(0, 0), (1, 5), (2, 10), (3, 15)] {
+            touch(wal_dir, &format!("{:016x}-{:016x}.wal", seq, index));
+        }
+        let mut options = PurgeOptions {
+            max_wals: 1,
+            max_snapshots: 2,
+        };
+        let purge = |o: &PurgeOptions| Purger::new(wal_dir, snap_dir, o.clone()).purge().unwrap();
+        let purge_snapshots = |o: &PurgeOptions| {
+            Purger::new(wal_dir, snap_dir, o.clone())
+                .purge_snapshots()
+                .unwrap()
+        };
+        // nothing is covered without a snapshot
+        assert_eq!(purge(&options), 0);
+
+        touch(snap_dir, &format!("{:016x}-{:016x}.snap", 1, 7));
+        touch(snap_dir, &format!("{:016x}-{:016x}.snap", 2, 12));
+        // the wal is replayed from 7 if the snapshot at 12 is broken
+        assert_eq!(purge(&options), 0);
+        options.max_snapshots = 1;
+        // the wal still reaches back to the snapshot at 7
+        assert_eq!(purge_snapshots(&options), 0);
+        assert_eq!(purge(&options), 1);
+        options.max_wals = 0;
+        assert_eq!(purge(&options), 1);
+        assert_eq!(obsolete_segments(wal_dir, 12).unwrap().len(), 0);
+        assert_eq!(obsolete_segments(wal_dir, 15).unwrap().len(), 1);
+        assert_eq!(purge_snapshots(&options), 1);
+        let snapshotter = Snapshotter::new(snap_dir, None);
+        assert_eq!(snapshotter.indexes().unwrap(), vec![12]);
+        // the newest snapshot is always kept
+        options.max_snapshots = 0;
+        assert_eq!(purge_snapshots(&options), 0);
+
+        fs::remove_dir_all(wal_dir).unwrap();
+        fs::remove_dir_all(snap_dir).unwrap();
+    }
+}
@@ -0,0 +1,461 @@
+use errors::*;
+use futures::future::{self, loop_fn, Loop};
+use futures::sync::mpsc::{unbounded, UnboundedSender};
+use futures::sync::oneshot;
+use futures::{Future, Sink, Stream};
+use grpcio::Error as GrpcIoError;
+use grpcio::*;
+use kv::prefix_end;
+use node::Msg;
+use proto::mumpb::*;
+use proto::mumpb_grpc::{Election, Lock};
+use server::reply_with;
+use std::sync::Arc;
+use watch::{self, WatchId};
+
+type BoxFuture<T> = Box<Future<Item = T, Error = Error> + Send>;
+
+/// Drops the watcher once the future waiting on it is done with or dropped
+struct Unwatch {
+    id: WatchId,
+    tx: Arc<UnboundedSender<Msg>>,
+}
+
+impl Drop for Unwatch {
+    fn drop(&mut self) {
+        let _ = self.tx.unbounded_send(Msg::Unwatch(self.id));
+    }
+}
+
+/// Issues requests to the raft loop the same way the grpc server does, the
+/// lock and election recipes are built on them
+#[derive(Clone)]
+struct Local {
+    tx: Arc<UnboundedSender<Msg>>,
+}
+
+impl Local {
+    fn txn(&self, txn: TxnRequest) -> BoxFuture<TxnResponse> {
+        let (cb, rx) = oneshot::channel();
+        self.tx
+            .unbounded_send(Msg::Txn { txn: txn, cb: cb })
+            .unwrap();
+        Box::new(rx.map_err(|_| Error::Future).and_then(|r| r))
+    }
+
+    /// Returns the keys starting with `prefix`, read linearizably
+    fn scan_prefix(&self, prefix: &[u8]) -> BoxFuture<OpResponse> {
+        let mut op = OpRequest::new();
+        op.set_field_type(Op::Scan);
+        op.set_key(prefix.to_vec());
+        op.set_prefix(true);
+        let (cb, rx) = oneshot::channel();
+        self.tx
+            .unbounded_send(Msg::Read { op: op, cb: cb })
+            .unwrap();
+        Box::new(rx.map_err(|_| Error::Future).and_then(|r| r))
+    }
+
+    /// Resolves once one of the keys in [key, end_key) changes at or after
+    /// `revision`, or the history is compacted
+    fn wait_change(
+        &self,
+        key: Vec<u8>,
+        end_key: Vec<u8>,
+        revision: u64,
+        deletion: bool,
+    ) -> BoxFuture<()> {
+        let mut req = WatchRequest::new();
+        req.set_key(key);
+        req.set_end_key(end_key);
+        req.set_start_revision(revision);
+        let id = watch::next_id();
+        let (tx, rx) = unbounded();
+        self.tx
+            .unbounded_send(Msg::Watch {
+                id: id,
+                req: req,
+                tx: tx,
+            })
+            .unwrap();
+        let unwatch = Unwatch {
+            id: id,
+            tx: self.tx.clone(),
+        };
+        let f = rx
+            .filter(move |resp| {
+                resp.get_compact_revision() > 0
+                    || resp
+                        .get_events()
+                        .iter()
+                        .any(|e| !deletion || e.get_field_type() == EventType::Delete)
+            })
+            .into_future()
+            // the watch ends once the watcher is dropped as well
+            .then(move |res| {
+                drop(unwatch);
+                res.map(|_| ()).map_err(|_| Error::Future)
+            });
+        Box::new(f)
+    }
+
+    /// Puts the key unless it exists, returns its create revision
+    fn create(&self, key: Vec<u8>, value: Vec<u8>, lease: u64) -> BoxFuture<u64> {
+        let mut txn = TxnRequest::new();
+        {
+            let mut cmp = Compare::new();
+            cmp.set_key(key.clone());
+            cmp.set_target(CompareTarget::CreateRevision);
+            cmp.set_result(CompareResult::Equal);
+            cmp.set_number(0);
+            txn.mut_compares().push(cmp);
+        }
+        let mut set = OpRequest::new();
+        set.set_field_type(Op::Set);
+        set.set_key(key.clone());
+        set.set_value(value);
+        set.set_lease(lease);
+        let mut get = OpRequest::new();
+        get.set_field_type(Op::Get);
+        get.set_key(key);
+        txn.mut_success().push(set.clone());
+        // a retry with the same lease takes over the key, it keeps its create
+        // revision
+        txn.mut_failure().push(get);
+        txn.mut_failure().push(set);
+        Box::new(self.txn(txn).map(|resp| {
+            if resp.get_succeeded() {
+                resp.get_header().get_revision()
+            } else {
+                resp.get_responses()[0].get_kvs()[0].get_create_revision()
+            }
+        }))
+    }
+
+    /// Resolves once every key under `prefix` created before `revision` is
+    /// deleted, fails if `key` created at `revision` is deleted first
+    fn wait_deletes(&self, prefix: Vec<u8>, key: Vec<u8>, revision: u64) -> BoxFuture<()> {
+        let local = self.clone();
+        Box::new(loop_fn((), move |_| {
+            let local = local.clone();
+            let key = key.clone();
+            local.scan_prefix(&prefix).and_then(move |resp| {
+                let next = resp.get_header().get_revision() + 1;
+                if !resp
+                    .get_kvs()
+                    .iter()
+                    .any(|kv| kv.get_create_revision() == revision)
+                {
+                    // the lease expired or the key was deleted meanwhile
+                    let key = String::from_utf8_lossy(&key).into_owned();
+                    return future::Either::B(future::err(Error::KeyDeleted(key)));
+                }
+                match waiting_on(resp.get_kvs(), revision) {
+                    Some(kv) => {
+                        let prev = local.wait_change(kv.get_key().to_vec(), vec![], next, true);
+                        let own = local.wait_change(key, vec![], next, true);
+                        let f = prev.select(own).map_err(|(e, _)| e);
+                        future::Either::A(f.map(|_| Loop::Continue(())))
+                    }
+                    None => future::Either::B(future::ok(Loop::Break(()))),
+                }
+            })
+        }))
+    }
+
+    /// Puts the value of the leader unless it lost the leadership
+    fn proclaim(&self, leader: &LeaderKey, value: Option<Vec<u8>>) -> BoxFuture<ResponseHeader> {
+        let mut txn = TxnRequest::new();
+        {
+            let mut cmp = Compare::new();
+            cmp.set_key(leader.get_key().to_vec());
+            cmp.set_target(CompareTarget::CreateRevision);
+            cmp.set_result(CompareResult::Equal);
+            cmp.set_number(leader.get_revision());
+            txn.mut_compares().push(cmp);
+        }
+        let mut op = OpRequest::new();
+        op.set_key(leader.get_key().to_vec());
+        match value {
+            Some(value) => {
+                op.set_field_type(Op::Set);
+                op.set_value(value);
+                op.set_lease(leader.get_lease());
+            }
+            None => op.set_field_type(Op::Del),
+        }
+        txn.mut_success().push(op);
+        let name = leader.get_name().to_vec();
+        Box::new(self.txn(txn).and_then(move |mut resp| {
+            if resp.get_succeeded() {
+                Ok(resp.take_header())
+            } else {
+                Err(Error::NotElectionLeader(
+                    String::from_utf8_lossy(&name).into_owned(),
+                ))
+            }
+        }))
+    }
+}
+
+/// Returns the key created last before `revision`, the one to wait for
+fn waiting_on(kvs: &[KvPair], revision: u64) -> Option<&KvPair> {
+    kvs.iter()
+        .filter(|kv| kv.get_create_revision() < revision)
+        .max_by_key(|kv| kv.get_create_revision())
+}
+
+/// Returns the key created first, the owner of the lock or the leader
+fn oldest(kvs: &[KvPair]) -> Option<&KvPair> {
+    kvs.iter().min_by_key(|kv| kv.get_create_revision())
+}
+
+/// Keys of a lock or an election are its name followed by the lease
+fn prefix(name: &[u8]) -> Vec<u8> {
+    let mut prefix = name.to_vec();
+    prefix.push(b'/');
+    prefix
+}
+
+fn owner_key(name: &[u8], lease: u64) -> Vec<u8> {
+    let mut key = prefix(name);
+    key.extend(format!("{:x}", lease).into_bytes());
+    key
+}
+
+#[derive(Clone)]
+pub struct LockServer {
+    local: Local,
+}
+
+impl LockServer {
+    pub fn new(tx: Arc<UnboundedSender<Msg>>) -> LockServer {
+        LockServer {
+            local: Local { tx: tx },
+        }
+    }
+}
+
+impl Lock for LockServer {
+    fn lock(&self, ctx: RpcContext, req: LockRequest, sink: UnarySink<LockResponse>) {
+        if req.get_lease() == 0 {
+            return reply_with(&ctx, sink, future::err(Error::LeaseRequired));
+        }
+        let local = self.local.clone();
+        let key = owner_key(req.get_name(), req.get_lease());
+        let name = prefix(req.get_name());
+        // the waiting stops once the call is done with, or once the key is
+        // deleted along with its lease
+        let f = local
+            .create(key.clone(), vec![], req.get_lease())
+            .and_then(move |revision| {
+                local
+                    .wait_deletes(name, key.clone(), revision)
+                    .map(move |_| {
+                        let mut resp = LockResponse::new();
+                        resp.mut_header().set_revision(revision);
+                        resp.set_key(key);
+                        resp
+                    })
+            });
+        reply_with(&ctx, sink, f);
+    }
+
+    fn unlock(&self, ctx: RpcContext, req: UnlockRequest, sink: UnarySink<UnlockResponse>) {
+        let key = req.get_key().to_vec();
+        let revision = req.get_revision();
+        let mut txn = TxnRequest::new();
+        {
+            // only the holder of the lock deletes its key
+            let mut cmp = Compare::new();
+            cmp.set_key(key.clone());
+            cmp.set_target(CompareTarget::CreateRevision);
+            cmp.set_result(CompareResult::Equal);
+            cmp.set_number(revision);
+            txn.mut_compares().push(cmp);
+        }
+        let mut del = OpRequest::new();
+        del.set_field_type(Op::Del);
+        del.set_key(key.clone());
+        txn.mut_success().push(del);
+        let f = self.local.txn(txn).and_then(move |mut txn| {
+            if !txn.get_succeeded() {
+                let key = String::from_utf8_lossy(&key).into_owned();
+                return Err(Error::LockNotHeld(key, revision));
+            }
+            let mut resp = UnlockResponse::new();
+            resp.set_header(txn.take_header());
+            Ok(resp)
+        });
+        reply_with(&ctx, sink, f);
+    }
+}
+
+#[derive(Clone)]
+pub struct ElectionServer {
+    local: Local,
+}
+
+impl ElectionServer {
+    pub fn new(tx: Arc<UnboundedSender<Msg>>) -> ElectionServer {
+        ElectionServer {
+            local: Local { tx: tx },
+        }
+    }
+}
+
+impl Election for ElectionServer {
+    fn campaign(
+        &self,
+        ctx: RpcContext,
+        mut req: CampaignRequest,
+        sink: UnarySink<CampaignResponse>,
+    ) {
+        if req.get_lease() == 0 {
+            return reply_with(&ctx, sink, future::err(Error::LeaseRequired));
+        }
+        let local = self.local.clone();
+        let mut leader = LeaderKey::new();
+        leader.set_name(req.get_name().to_vec());
+        leader.set_key(owner_key(req.get_name(), req.get_lease()));
+        leader.set_lease(req.get_lease());
+        let f = local
+            .create(leader.get_key().to_vec(), req.take_value(), req.get_lease())
+            .and_then(move |revision| {
+                leader.set_revision(revision);
+                let key = leader.get_key().to_vec();
+                local
+                    .wait_deletes(prefix(leader.get_name()), key, revision)
+                    .map(move |_| {
+                        let mut resp = CampaignResponse::new();
+                        resp.mut_header().set_revision(leader.get_revision());
+                        resp.set_leader(leader);
+                        resp
+                    })
+            });
+        reply_with(&ctx, sink, f);
+    }
+
+    fn proclaim(
+        &self,
+        ctx: RpcContext,
+        mut req: ProclaimRequest,
+        sink: UnarySink<ProclaimResponse>,
+    ) {
+        let f = self
+            .local
+            .proclaim(req.get_leader(), Some(req.take_value()))
+            .map(|header| {
+                let mut resp = ProclaimResponse::new();
+                resp.set_header(header);
+                resp
+            });
+        reply_with(&ctx, sink, f);
+    }
+
+    fn leader(&self, ctx: RpcContext, req: LeaderRequest, sink: UnarySink<LeaderResponse>) {
+        let name = req.get_name().to_vec();
+        let f = self
+            .local
+            .scan_prefix(&prefix(req.get_name()))
+            .and_then(move |mut scan| {
+                let kv = oldest(scan.get_kvs()).cloned().ok_or_else(|| {
+                    Error::NoElectionLeader(String::from_utf8_lossy(&name).into_owned())
+                })?;
+                let mut resp = LeaderResponse::new();
+                resp.set_header(scan.take_header());
+                resp.set_kv(kv);
+                Ok(resp)
+            });
+        reply_with(&ctx, sink, f);
+    }
+
+    fn observe(
+        &self,
+        ctx: RpcContext,
+        req: LeaderRequest,
+        sink: ServerStreamingSink<LeaderResponse>,
+    ) {
+        let local = self.local.clone();
+        let prefix = prefix(req.get_name());
+        let (tx, rx) = unbounded();
+        // (key, mod revision) of the leader sent last
+        let observing = loop_fn(None, move |last: Option<(Vec<u8>, u64)>| {
+            let local = local.clone();
+            let prefix = prefix.clone();
+            let tx = tx.clone();
+            local.scan_prefix(&prefix).and_then(move |mut scan| {
+                let next = scan.get_header().get_revision() + 1;
+                let mut last = last;
+                if let Some(kv) = oldest(scan.get_kvs()).cloned() {
+                    let current = (kv.get_key().to_vec(), kv.get_mod_revision());
+                    if last.as_ref() != Some(&current) {
+                        let mut resp = LeaderResponse::new();
+                        resp.set_header(scan.take_header());
+                        resp.set_kv(kv);
+                        if tx.unbounded_send(resp).is_err() {
+                            // the client has gone away
+                            return future::Either::B(future::ok(Loop::Break(())));
+                        }
+                        last = Some(current);
+                    }
+                }
+                let end = prefix_end(&prefix);
+                let f = local.wait_change(prefix, end, next, false);
+                future::Either::A(f.map(move |_| Loop::Continue(last)))
+            })
+        })
+        .map_err(|e| debug!("stopped observing election: {}", e));
+        // observing stops once the stream is closed, the stream ends once
+        // observing stops and drops the sender
+        let observing = observing.then(|_| future::empty::<(), ()>());
+        let f = sink
+            .send_all(
+                rx.map(|resp| (resp, WriteFlags::default()))
+                    .map_err(|_| GrpcIoError::RemoteStopped),
+            )
+            .map(|_| ())
+            .map_err(|e| debug!("observe stream closed {}", e))
+            .select(observing)
+            .map(|_| ())
+            .map_err(|_| ());
+        ctx.spawn(f);
+    }
+
+    fn resign(&self, ctx: RpcContext, req: ResignRequest, sink: UnarySink<ResignResponse>) {
+        let f = self.local.proclaim(req.get_leader(), None).map(|header| {
+            let mut resp = ResignResponse::new();
+            resp.set_header(header);
+            resp
+        });
+        reply_with(&ctx, sink, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kv(key: &[u8], create_revision: u64) -> KvPair {
+        let mut kv = KvPair::new();
+        kv.set_key(key.to_vec());
+        kv.set_create_revision(create_revision);
+        kv
+    }
+
+    #[test]
+    fn waiting_on_test() {
+        let kvs = vec![kv(b"l/1", 5), kv(b"l/2", 3), kv(b"l/3", 7)];
+        assert_eq!(oldest(&kvs).unwrap().get_key(), b"l/2");
+        assert_eq!(waiting_on(&kvs, 7).unwrap().get_key(), b"l/1");
+        assert_eq!(waiting_on(&kvs, 5).unwrap().get_key(), b"l/2");
+        assert!(waiting_on(&kvs, 3).is_none());
+        assert!(oldest(&[]).is_none());
+    }
+
+    #[test]
+    fn owner_key_test() {
+        assert_eq!(owner_key(b"lock", 255), b"lock/ff".to_vec());
+        assert_eq!(prefix_end(&prefix(b"lock")), b"lock0".to_vec());
+    }
+}
@@ -1,26 +1,26 @@
-use errors::Result;
+use errors::{Error, Result};
 use futures::sync::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
-use futures::Future;
+use futures::sync::oneshot;
+use futures::{Future, Sink, Stream};
+use grpcio::Error as GrpcIoError;
 use grpcio::*;
 use kv::Store;
+use metrics::*;
+use node::Msg;
 use proto::mumpb::*;
-use proto::mumpb_grpc::{create_mum, Mum};
-use protobuf::RepeatedField;
+use proto::mumpb_grpc::{create_election, create_lock, create_mum, Mum};
+use recipe::{ElectionServer, LockServer};
 use std::sync::Arc;
+use watch;
 
 #[derive(Clone)]
 pub struct MumServer {
     store: Store,
-    tx: Arc<UnboundedSender<(Option<RaftMessage>, Option<OpRequest>, Option<ConfRequest>)>>,
+    tx: Arc<UnboundedSender<Msg>>,
 }
 
 impl MumServer {
-    pub fn new(
-        store: Store,
-    ) -> (
-        MumServer,
-        UnboundedReceiver<(Option<RaftMessage>, Option<OpRequest>, Option<ConfRequest>)>,
-    ) {
+    pub fn new(store: Store) -> (MumServer, UnboundedReceiver<Msg>) {
         let (tx, rx) = unbounded();
         (
             MumServer {
@@ -37,9 +37,13 @@ impl MumServer {
         host: S,
         port: u16,
     ) -> Result<Server> {
+        let lock = create_lock(LockServer::new(self.tx.clone()));
+        let election = create_election(ElectionServer::new(self.tx.clone()));
         let service = create_mum(self);
         let server = ServerBuilder::new(env)
             .register_service(service)
+            .register_service(lock)
+            .register_service(election)
             .bind(host, port)
             .build()?;
         Ok(server)
@@ -50,44 +54,177 @@ impl Mum for MumServer {
     fn raft(&self, ctx: RpcContext, req: RaftMessage, sink: UnarySink<Done>) {
         let tx = self.tx.clone();
         let resp = Done::new();
-        tx.unbounded_send((Some(req), None, None)).unwrap();
+        tx.unbounded_send(Msg::Raft(req)).unwrap();
         ctx.spawn(sink.success(resp).map_err(|_| ()));
     }
 
     fn op(&self, ctx: RpcContext, req: OpRequest, sink: UnarySink<OpResponse>) {
-        let store = self.store.clone();
         let tx = self.tx.clone();
-        let mut resp = OpResponse::new();
-        let mut kvs = RepeatedField::new();
-        match req.field_type {
-            Op::Set | Op::Del => {
-                tx.unbounded_send((None, Some(req), None)).unwrap();
+        let (cb, rx) = oneshot::channel();
+        match (req.field_type, req.consistency) {
+            (Op::Set, _) | (Op::Del, _) | (Op::Compact, _) => {
+                // reply once the op is committed and applied
+                tx.unbounded_send(Msg::Propose { op: req, cb: cb }).unwrap();
             }
-            Op::Get => match store.get(&req.key) {
-                Some(v) => {
-                    let mut kv = KvPair::new();
-                    kv.set_key(req.key);
-                    kv.set_value(v);
-                    kvs.push(kv);
-                    resp.set_kvs(kvs);
-                }
-                _ => (),
-            },
-            Op::Scan => {
-                for (k, v) in store.scan(req.get_key(), req.get_limit()) {
-                    let mut kv = KvPair::new();
-                    kv.set_key(k);
-                    kv.set_value(v);
-                    kvs.push(kv);
-                }
+            (_, ReadConsistency::Linearizable) | (_, ReadConsistency::Lease) => {
+                tx.unbounded_send(Msg::Read { op: req, cb: cb }).unwrap();
+            }
+            (_, ReadConsistency::Stale) => {
+                READ_COUNTER_VEC.with_label_values(&["stale"]).inc();
+                let _ = cb.send(self.store.read(&req));
             }
         }
-        ctx.spawn(sink.success(resp).map_err(|_| ()));
+        reply(&ctx, sink, rx);
+    }
+
+    fn watch(&self, ctx: RpcContext, req: WatchRequest, sink: ServerStreamingSink<WatchResponse>) {
+        let id = watch::next_id();
+        let (tx, rx) = unbounded();
+        self.tx
+            .unbounded_send(Msg::Watch {
+                id: id,
+                req: req,
+                tx: tx,
+            })
+            .unwrap();
+        // the stream ends on the first write after the client cancels it,
+        // the raft loop writes to the idle watchers once in a while
+        let node_tx = self.tx.clone();
+        let f = sink
+            .send_all(
+                rx.map(|resp| (resp, WriteFlags::default()))
+                    .map_err(|_| GrpcIoError::RemoteStopped),
+            )
+            .then(move |res| {
+                if let Err(e) = res {
+                    debug!("watch stream closed {}", e);
+                }
+                let _ = node_tx.unbounded_send(Msg::Unwatch(id));
+                Ok(())
+            });
+        ctx.spawn(f);
+    }
+
+    fn txn(&self, ctx: RpcContext, req: TxnRequest, sink: UnarySink<TxnResponse>) {
+        let tx = self.tx.clone();
+        let (cb, rx) = oneshot::channel();
+        tx.unbounded_send(Msg::Txn { txn: req, cb: cb }).unwrap();
+        reply(&ctx, sink, rx);
+    }
+
+    fn lease_grant(
+        &self,
+        ctx: RpcContext,
+        req: LeaseGrantRequest,
+        sink: UnarySink<LeaseGrantResponse>,
+    ) {
+        let (cb, rx) = oneshot::channel();
+        self.tx
+            .unbounded_send(Msg::LeaseGrant { req: req, cb: cb })
+            .unwrap();
+        reply(&ctx, sink, rx);
+    }
+
+    fn lease_revoke(
+        &self,
+        ctx: RpcContext,
+        req: LeaseRevokeRequest,
+        sink: UnarySink<LeaseRevokeResponse>,
+    ) {
+        let (cb, rx) = oneshot::channel();
+        self.tx
+            .unbounded_send(Msg::LeaseRevoke { req: req, cb: cb })
+            .unwrap();
+        reply(&ctx, sink, rx);
+    }
+
+    fn lease_keep_alive(
+        &self,
+        ctx: RpcContext,
+        stream: RequestStream<LeaseKeepAliveRequest>,
+        sink: DuplexSink<LeaseKeepAliveResponse>,
+    ) {
+        let tx = self.tx.clone();
+        // every keepalive is answered by the raft loop in order, an error
+        // such as losing the leadership ends the stream
+        let resps = stream
+            .and_then(move |req| {
+                let (cb, rx) = oneshot::channel();
+                tx.unbounded_send(Msg::LeaseKeepAlive { req: req, cb: cb })
+                    .unwrap();
+                rx.map_err(|_| GrpcIoError::RemoteStopped)
+                    .and_then(|r| r.map_err(|e| GrpcIoError::RpcFailure(error_to_status(e))))
+            })
+            .map(|resp| (resp, WriteFlags::default()));
+        let f = sink
+            .send_all(resps)
+            .map(|_| ())
+            .map_err(|e| debug!("lease keepalive stream closed {}", e));
+        ctx.spawn(f);
+    }
+
+    fn lease_time_to_live(
+        &self,
+        ctx: RpcContext,
+        req: LeaseTimeToLiveRequest,
+        sink: UnarySink<LeaseTimeToLiveResponse>,
+    ) {
+        let (cb, rx) = oneshot::channel();
+        self.tx
+            .unbounded_send(Msg::LeaseTimeToLive { req: req, cb: cb })
+            .unwrap();
+        reply(&ctx, sink, rx);
     }
 
     fn conf(&self, ctx: RpcContext, req: ConfRequest, sink: UnarySink<ConfResponse>) {
         let tx = self.tx.clone();
-        tx.unbounded_send((None, None, Some(req))).unwrap();
-        ctx.spawn(sink.success(ConfResponse::new()).map_err(|_| ()));
+        let (cb, rx) = oneshot::channel();
+        tx.unbounded_send(Msg::Conf { conf: req, cb: cb }).unwrap();
+        reply(&ctx, sink, rx);
     }
 }
+
+/// Replies to the client once the raft loop sends the result to `rx`
+pub fn reply<T: Send + 'static>(
+    ctx: &RpcContext,
+    sink: UnarySink<T>,
+    rx: oneshot::Receiver<Result<T>>,
+) {
+    reply_with(ctx, sink, rx.map_err(|_| Error::Future).and_then(|r| r))
+}
+
+/// Replies with the result of the future, which is dropped along with the
+/// sink
+pub fn reply_with<T, F>(ctx: &RpcContext, sink: UnarySink<T>, f: F)
+where
+    T: Send + 'static,
+    F: Future<Item = T, Error = Error> + Send + 'static,
+{
+    let f = f
+        .then(|r| match r {
+            Ok(resp) => sink.success(resp),
+            Err(e) => sink.fail(error_to_status(e)),
+        })
+        .map_err(|_| ());
+    ctx.spawn(f);
+}
+
+fn error_to_status(err: Error) -> RpcStatus {
+    let code = match err {
+        // a forwarded request failed on the leader
+        Error::GrpcIo(GrpcIoError::RpcFailure(status)) => return status,
+        Error::ProposalTimeout => RpcStatusCode::DeadlineExceeded,
+        Error::ProposalDropped => RpcStatusCode::Unavailable,
+        Error::NotLeader { .. } => RpcStatusCode::FailedPrecondition,
+        Error::FutureRevision(..) | Error::Compacted(..) => RpcStatusCode::OutOfRange,
+        Error::InvalidTxn(_) => RpcStatusCode::InvalidArgument,
+        Error::LeaseNotFound(_) => RpcStatusCode::NotFound,
+        Error::LeaseExists(_) => RpcStatusCode::AlreadyExists,
+        Error::LeaseRequired | Error::ZeroLeaseTtl => RpcStatusCode::InvalidArgument,
+        Error::NoElectionLeader(_) => RpcStatusCode::NotFound,
+        Error::NotElectionLeader(_) | Error::LockNotHeld(..) => RpcStatusCode::FailedPrecondition,
+        Error::KeyDeleted(_) => RpcStatusCode::Aborted,
+        _ => RpcStatusCode::Internal,
+    };
+    RpcStatus::new(code, Some(format!("{}", err)))
+}
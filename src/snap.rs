@@ -1,23 +1,40 @@
-use bincode::{deserialize_from, serialize_into};
+use bincode::deserialize;
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use crc::crc32;
+use crypto::Keyring;
 use errors::*;
+use metrics::*;
 use protobuf::Message;
+use purge::purge_file;
 use raft::eraftpb::Snapshot as RaftSnapshot;
-use std::fs::{self, OpenOptions};
+use std::fs::{self, File};
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use util::read_with_ext_and_sort;
+use std::sync::Arc;
+use util::{read_tmp_files, read_with_ext_and_sort, sync_dir};
 
 const SNAP_EXT: &'static str = "snap";
 const SNAP_BROKEN_EXT: &'static str = "broken";
+const SNAP_TMP_EXT: &'static str = "tmp";
+
+const SNAP_MAGIC: &'static [u8; 8] = b"mumsnap\0";
+const SNAP_FORMAT_VERSION: u32 = 1;
+// magic, version, flags, key id and crc
+const SNAP_HEADER_SIZE: usize = 24;
+// the data is encrypted with the key of the header
+const FLAG_ENCRYPTED: u32 = 1;
 
 pub struct Snapshotter {
     dir: PathBuf,
+    // encrypts the new snapshots with its active key
+    keyring: Option<Arc<Keyring>>,
 }
 
 impl Snapshotter {
-    pub fn new<P: AsRef<Path>>(p: P) -> Snapshotter {
+    pub fn new<P: AsRef<Path>>(p: P, keyring: Option<Arc<Keyring>>) -> Snapshotter {
         Snapshotter {
             dir: p.as_ref().to_path_buf(),
+            keyring: keyring,
         }
     }
 
@@ -28,42 +45,57 @@ impl Snapshotter {
         }
         let md = snapshot.metadata.get_ref();
         let spath = self.new_snap_path(md.index, md.term);
-        let b = snapshot.write_to_bytes()?;
-        let crc = crc32::update(0, &crc32::CASTAGNOLI_TABLE, &b);
-        match serialize_into(
-            OpenOptions::new()
-                .read(true)
-                .write(true)
-                .create(true)
-                .open(&spath)?,
-            &(crc, b),
-        ) {
+        let b = self.encode(&snapshot.write_to_bytes()?)?;
+        // a crash leaves at worst a temp file, never a partial snapshot
+        let tpath = append_ext(&spath, SNAP_TMP_EXT);
+        match write_synced(&tpath, &b) {
             Err(err) => {
                 warn!(
                     "failed to write a snap file {} error {}",
-                    spath.to_string_lossy(),
+                    tpath.to_string_lossy(),
                     err
                 );
-                match fs::remove_file(spath.as_path()) {
+                match fs::remove_file(tpath.as_path()) {
                     Err(err) => {
                         warn!(
                             "failed to remove a broken snap file {} error {}",
-                            spath.to_string_lossy(),
+                            tpath.to_string_lossy(),
                             err
                         );
                     }
                     _ => (),
                 }
-                Err(err.into())
+                Err(err)
+            }
+            Ok(()) => {
+                fs::rename(&tpath, &spath)?;
+                sync_dir(&self.dir)?;
+                self.report()
             }
-            Ok(v) => Ok(v),
         }
     }
 
     pub fn load(&self) -> Result<Option<RaftSnapshot>> {
+        // the saves a crash interrupted
+        // only the own ones, the wal may share the directory
+        for tpath in read_tmp_files(&self.dir, SNAP_EXT, SNAP_TMP_EXT)? {
+            warn!("remove the partial snap file {}", tpath.to_string_lossy());
+            fs::remove_file(tpath)?;
+        }
+        self.report()?;
         for spath in read_with_ext_and_sort(&self.dir, SNAP_EXT, true)? {
             match self.load_snap(&spath) {
                 Ok(v) => return Ok(Some(v)),
+                // a missing or wrong key, the crc already vouched for the
+                // data. Refused rather than falling back to an older snapshot
+                Err(err @ Error::UnknownKey(_)) | Err(err @ Error::Crypto(_)) => {
+                    error!(
+                        "failed to decrypt snap {} error {}",
+                        &spath.to_string_lossy(),
+                        err
+                    );
+                    return Err(err);
+                }
                 Err(err) => {
                     warn!(
                         "failed to load snap from {} error {}",
@@ -77,17 +109,118 @@ impl Snapshotter {
         Ok(None)
     }
 
+    /// Returns the indexes of the saved snapshots, newest first
+    pub fn indexes(&self) -> Result<Vec<u64>> {
+        Ok(read_with_ext_and_sort(&self.dir, SNAP_EXT, true)?
+            .iter()
+            .filter_map(|p| index_from_path(p))
+            .collect())
+    }
+
+    /// Deletes the snapshots but the newest `keep` of them, once the wal
+    /// starting at `wal_start` can no longer be replayed from them. Returns
+    /// how many were deleted
+    pub fn purge(&self, keep: usize, wal_start: u64) -> Result<usize> {
+        let mut purged = 0;
+        for spath in read_with_ext_and_sort(&self.dir, SNAP_EXT, true)?
+            .iter()
+            .skip(keep.max(1))
+        {
+            match index_from_path(spath) {
+                Some(index) if index < wal_start => (),
+                _ => continue,
+            }
+            if purge_file(spath, "snap")? {
+                purged += 1;
+            }
+        }
+        if purged > 0 {
+            sync_dir(&self.dir)?;
+        }
+        self.report()?;
+        Ok(purged)
+    }
+
+    // the count and size of the snapshot files
+    fn report(&self) -> Result<()> {
+        let paths = read_with_ext_and_sort(&self.dir, SNAP_EXT, false)?;
+        let mut size = 0;
+        for path in &paths {
+            size += fs::metadata(path)?.len();
+        }
+        SNAP_FILES_GAUGE.set(paths.len() as i64);
+        SNAP_BYTES_GAUGE.set(size as i64);
+        Ok(())
+    }
+
     fn load_snap(&self, p: &PathBuf) -> Result<RaftSnapshot> {
         debug!("load snapshot form path {}", p.to_string_lossy());
-        let snapshot: (u32, Vec<u8>) = deserialize_from(OpenOptions::new().read(true).open(p)?)?;
-        if snapshot.0 != crc32::update(0, &crc32::CASTAGNOLI_TABLE, &snapshot.1) {
-            return Err(Error::CrcMissMatch);
-        }
+        let mut buf = vec![];
+        File::open(p)?.read_to_end(&mut buf)?;
         let mut raft_snapshot = RaftSnapshot::new();
-        raft_snapshot.merge_from_bytes(&snapshot.1)?;
+        raft_snapshot.merge_from_bytes(&self.decode(&buf)?)?;
         return Ok(raft_snapshot);
     }
 
+    /// Prepends the header to the snapshot, encrypted if there is a keyring.
+    /// The header is authenticated along with the data
+    fn encode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let (flags, key_id) = match self.keyring {
+            Some(ref keyring) => (FLAG_ENCRYPTED, keyring.active_id()),
+            None => (0, 0),
+        };
+        let mut buf = Vec::with_capacity(SNAP_HEADER_SIZE + data.len());
+        buf.extend_from_slice(SNAP_MAGIC);
+        buf.write_u32::<LittleEndian>(SNAP_FORMAT_VERSION).unwrap();
+        buf.write_u32::<LittleEndian>(flags).unwrap();
+        buf.write_u32::<LittleEndian>(key_id).unwrap();
+        let data = match self.keyring {
+            Some(ref keyring) => keyring.seal(key_id, &buf, data)?,
+            None => data.to_vec(),
+        };
+        let crc = crc32::update(0, &crc32::CASTAGNOLI_TABLE, &data);
+        buf.write_u32::<LittleEndian>(crc).unwrap();
+        buf.extend_from_slice(&data);
+        Ok(buf)
+    }
+
+    /// Returns the snapshot data of a file, the plaintext ones written
+    /// before the header was introduced included
+    fn decode(&self, buf: &[u8]) -> Result<Vec<u8>> {
+        if !buf.starts_with(SNAP_MAGIC) {
+            let (crc, data): (u32, Vec<u8>) = deserialize(buf)?;
+            if crc != crc32::update(0, &crc32::CASTAGNOLI_TABLE, &data) {
+                return Err(Error::CrcMissMatch);
+            }
+            return Ok(data);
+        }
+        if buf.len() < SNAP_HEADER_SIZE {
+            return Err(Error::SnapHeader(format!("{} bytes", buf.len())));
+        }
+        let mut rdr = &buf[SNAP_MAGIC.len()..SNAP_HEADER_SIZE];
+        let version = rdr.read_u32::<LittleEndian>().unwrap();
+        if version != SNAP_FORMAT_VERSION {
+            return Err(Error::SnapHeader(format!(
+                "format version {}, only {} is supported",
+                version, SNAP_FORMAT_VERSION
+            )));
+        }
+        let flags = rdr.read_u32::<LittleEndian>().unwrap();
+        let key_id = rdr.read_u32::<LittleEndian>().unwrap();
+        let crc = rdr.read_u32::<LittleEndian>().unwrap();
+        let data = &buf[SNAP_HEADER_SIZE..];
+        if crc != crc32::update(0, &crc32::CASTAGNOLI_TABLE, data) {
+            return Err(Error::CrcMissMatch);
+        }
+        if flags & FLAG_ENCRYPTED == 0 {
+            return Ok(data.to_vec());
+        }
+        match self.keyring {
+            Some(ref keyring) => keyring.open(key_id, &buf[..SNAP_HEADER_SIZE - 4], data),
+            None => Err(Error::UnknownKey(key_id)),
+        }
+    }
+
     fn broken(&self, p: &PathBuf) {
         let broken_name = Snapshotter::snap_broken_path(p);
         match fs::rename(p, &broken_name) {
@@ -103,8 +236,9 @@ impl Snapshotter {
         }
     }
 
+    // the broken file is no longer a snap file, it is kept for inspection
     fn snap_broken_path(snap_path: &PathBuf) -> PathBuf {
-        snap_path.join(format!(".{}", SNAP_BROKEN_EXT))
+        append_ext(snap_path, SNAP_BROKEN_EXT)
     }
 
     fn new_snap_path(&self, index: u64, term: u64) -> PathBuf {
@@ -113,10 +247,31 @@ impl Snapshotter {
     }
 }
 
+fn append_ext(p: &Path, ext: &str) -> PathBuf {
+    let mut name = p.as_os_str().to_owned();
+    name.push(format!(".{}", ext));
+    PathBuf::from(name)
+}
+
+fn write_synced(p: &Path, b: &[u8]) -> Result<()> {
+    let mut file = File::create(p)?;
+    file.write_all(b)?;
+    file.sync_all()?;
+    Ok(())
+}
+
+// snapshot files are named term-index
+fn index_from_path(p: &PathBuf) -> Option<u64> {
+    let stem = p.file_stem()?.to_str()?;
+    let index = stem.split('-').nth(1)?;
+    u64::from_str_radix(index, 16).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use raft::eraftpb::SnapshotMetadata;
+    use util::create_dir;
     fn new_snapshot(index: u64, term: u64) -> RaftSnapshot {
         let mut snap = RaftSnapshot::new();
         let mut meta = SnapshotMetadata::new();
@@ -130,7 +285,7 @@ mod tests {
         let index = 1;
         let term = 1;
         let snap_dir = "./file_tests/snap_save";
-        let snap_shotter = Snapshotter::new(snap_dir);
+        let snap_shotter = Snapshotter::new(snap_dir, None);
         let snap_path = snap_shotter.new_snap_path(index, term);
         let snap = new_snapshot(index, term);
         snap_shotter.save(&snap).unwrap();
@@ -143,7 +298,7 @@ mod tests {
         let index = 1;
         let term = 1;
         let snap_dir = "./file_tests/snap_load";
-        let snap_shotter = Snapshotter::new(snap_dir);
+        let snap_shotter = Snapshotter::new(snap_dir, None);
         let snap_path = snap_shotter.new_snap_path(index, term);
         let mut snap = new_snapshot(index, term);
         let data = vec![1u8, 2u8, 3u8];
@@ -154,4 +309,73 @@ mod tests {
         assert_eq!(loaded.data, data);
         fs::remove_file(snap_path).unwrap();
     }
+
+    #[test]
+    fn snapshot_broken_test() {
+        let snap_dir = "./file_tests/snap_broken";
+        let _ = fs::remove_dir_all(snap_dir);
+        create_dir(snap_dir).unwrap();
+        let snap_shotter = Snapshotter::new(snap_dir, None);
+        snap_shotter.save(&new_snapshot(1, 1)).unwrap();
+        let snap_path = snap_shotter.new_snap_path(2, 1);
+        fs::write(&snap_path, b"torn").unwrap();
+        // a save interrupted by a crash
+        let tmp_path = append_ext(&snap_shotter.new_snap_path(3, 1), SNAP_TMP_EXT);
+        fs::write(&tmp_path, b"partial").unwrap();
+        let wal_tmp_path = Path::new(snap_dir).join("0.wal.tmp");
+        fs::write(&wal_tmp_path, b"spare").unwrap();
+
+        let loaded = snap_shotter.load().unwrap().unwrap();
+        assert_eq!(loaded.get_metadata().get_index(), 1);
+        assert!(!tmp_path.exists());
+        assert!(wal_tmp_path.exists());
+        assert!(!snap_path.exists());
+        let broken_path = Path::new(snap_dir).join(format!(
+            "{:016x}-{:016x}.{}.{}",
+            1, 2, SNAP_EXT, SNAP_BROKEN_EXT
+        ));
+        assert!(broken_path.exists());
+        assert_eq!(snap_shotter.indexes().unwrap(), vec![1]);
+        fs::remove_dir_all(snap_dir).unwrap();
+    }
+
+    #[test]
+    fn snapshot_encryption_test() {
+        let snap_dir = "./file_tests/snap_encrypted";
+        create_dir(snap_dir).unwrap();
+        let key_path = Path::new(snap_dir).join("keys");
+        fs::write(&key_path, format!("7 {}", "5a".repeat(32))).unwrap();
+        let keyring = Arc::new(Keyring::load(&key_path).unwrap());
+        let snap_shotter = Snapshotter::new(snap_dir, Some(keyring));
+        let mut snap = new_snapshot(2, 1);
+        let data = b"customer data".to_vec();
+        snap.set_data(data.clone());
+        snap_shotter.save(&snap).unwrap();
+
+        let snap_path = snap_shotter.new_snap_path(2, 1);
+        let mut buf = vec![];
+        File::open(&snap_path)
+            .unwrap()
+            .read_to_end(&mut buf)
+            .unwrap();
+        assert!(!buf.windows(data.len()).any(|w| w == data.as_slice()));
+        assert_eq!(&buf[12..20], &[1, 0, 0, 0, 7, 0, 0, 0]);
+        assert_eq!(snap_shotter.load().unwrap().unwrap().data, data);
+        // unreadable without the key
+        match Snapshotter::new(snap_dir, None).load() {
+            Err(Error::UnknownKey(7)) => (),
+            _ => panic!("expected unknown key"),
+        }
+        // nor quarantined
+        assert!(snap_path.exists());
+
+        // the snapshots written before the header still load
+        let legacy = (
+            crc32::update(0, &crc32::CASTAGNOLI_TABLE, b"old"),
+            b"old".to_vec(),
+        );
+        let legacy = ::bincode::serialize(&legacy).unwrap();
+        assert_eq!(snap_shotter.decode(&legacy).unwrap(), b"old".to_vec());
+        fs::remove_dir_all(snap_dir).unwrap();
+    }
 }
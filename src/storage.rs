@@ -0,0 +1,338 @@
+use errors::*;
+use protobuf::Message;
+use raft::eraftpb::{ConfState, Entry, HardState, Snapshot};
+use raft::storage::{RaftState, Storage};
+use raft::{Error as RaftError, Result as RaftResult, StorageError};
+use std::cmp;
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::Instant;
+use util::HandyRwLock;
+use wal::{wal_exists, Position, Replayed, Wal, WalOptions};
+
+// number of the newest entries kept in memory
+const ENTRY_CACHE_SIZE: usize = 1024;
+
+pub struct WalStorageCore {
+    wal: Wal,
+    hard_state: HardState,
+    snapshot: Snapshot,
+    // index & term of the last compacted entry
+    compacted: (u64, u64),
+    // term & wal position of every entry after the compacted one
+    positions: VecDeque<(u64, Position)>,
+    // the tail of the log
+    cache: VecDeque<Entry>,
+}
+
+impl WalStorageCore {
+    fn first_index(&self) -> u64 {
+        self.compacted.0 + 1
+    }
+
+    fn last_index(&self) -> u64 {
+        self.compacted.0 + self.positions.len() as u64
+    }
+
+    fn term(&self, idx: u64) -> Option<u64> {
+        if idx == self.compacted.0 {
+            return Some(self.compacted.1);
+        }
+        if idx < self.compacted.0 {
+            return None;
+        }
+        self.positions
+            .get((idx - self.first_index()) as usize)
+            .map(|&(term, _)| term)
+    }
+
+    fn entry(&mut self, idx: u64) -> Result<Entry> {
+        if let Some(front) = self.cache.front().map(|e| e.get_index()) {
+            if idx >= front {
+                return Ok(self.cache[(idx - front) as usize].clone());
+            }
+        }
+        let position = self.positions[(idx - self.first_index()) as usize]
+            .1
+            .clone();
+        let entry = self.wal.read_entry(&position)?;
+        if entry.get_index() != idx {
+            panic!(
+                "entry at {:?} has index {}, want {}",
+                position,
+                entry.get_index(),
+                idx
+            );
+        }
+        Ok(entry)
+    }
+
+    fn index_entry(&mut self, entry: &Entry, position: Position) {
+        let idx = entry.get_index();
+        if idx < self.first_index() {
+            return;
+        }
+        if idx > self.last_index() + 1 {
+            panic!(
+                "missing log entries [last: {}, append at: {}]",
+                self.last_index(),
+                idx
+            );
+        }
+        // entries from idx on are overwritten by the new ones
+        let keep = (idx - self.first_index()) as usize;
+        self.positions.truncate(keep);
+        self.positions.push_back((entry.get_term(), position));
+
+        while self.cache.back().map_or(false, |e| e.get_index() >= idx) {
+            self.cache.pop_back();
+        }
+        if self
+            .cache
+            .back()
+            .map_or(false, |e| e.get_index() + 1 != idx)
+        {
+            self.cache.clear();
+        }
+        self.cache.push_back(entry.clone());
+        if self.cache.len() > ENTRY_CACHE_SIZE {
+            self.cache.pop_front();
+        }
+    }
+
+    fn reset(&mut self, index: u64, term: u64) {
+        self.compacted = (index, term);
+        self.positions.clear();
+        self.cache.clear();
+    }
+
+    /// Persists the hard state and entries to the wal, those which must be
+    /// synced are left for the next call of `sync`
+    pub fn save(
+        &mut self,
+        hs: Option<HardState>,
+        entries: &[Entry],
+        must_sync: bool,
+    ) -> Result<()> {
+        let positions = self.wal.insert(hs.clone(), entries, must_sync)?;
+        for (entry, position) in entries.iter().zip(positions) {
+            self.index_entry(entry, position);
+        }
+        if let Some(hs) = hs {
+            self.hard_state = hs;
+        }
+        Ok(())
+    }
+
+    /// Returns the bytes saved since the last sync which must be synced
+    /// before the entries are acknowledged
+    pub fn unsynced(&self) -> u64 {
+        self.wal.unsynced()
+    }
+
+    pub fn sync(&mut self) -> Result<()> {
+        self.wal.sync()
+    }
+
+    /// Syncs the wal if its sync interval has elapsed
+    pub fn maybe_sync(&mut self) -> Result<()> {
+        self.wal.maybe_sync(Instant::now())
+    }
+
+    /// Overwrites the log with the snapshot
+    pub fn apply_snapshot(&mut self, snapshot: Snapshot) -> Result<()> {
+        let (index, term) = {
+            let md = snapshot.get_metadata();
+            (md.get_index(), md.get_term())
+        };
+        if index <= self.snapshot.get_metadata().get_index() {
+            return Err(RaftError::Store(StorageError::SnapshotOutOfDate).into());
+        }
+        self.wal.insert_snapshot((term, index))?;
+        self.wal.release_lock_to(index)?;
+        self.reset(index, term);
+        self.hard_state.set_commit(index);
+        let term = cmp::max(term, self.hard_state.get_term());
+        self.hard_state.set_term(term);
+        self.snapshot = snapshot;
+        Ok(())
+    }
+
+    pub fn create_snapshot(
+        &mut self,
+        idx: u64,
+        cs: Option<ConfState>,
+        data: Vec<u8>,
+    ) -> Result<&Snapshot> {
+        if idx <= self.snapshot.get_metadata().get_index() {
+            return Err(RaftError::Store(StorageError::SnapshotOutOfDate).into());
+        }
+        if idx > self.last_index() {
+            panic!(
+                "snapshot {} is out of bound lastindex({})",
+                idx,
+                self.last_index()
+            );
+        }
+        let term = self.term(idx).unwrap();
+        {
+            let md = self.snapshot.mut_metadata();
+            md.set_index(idx);
+            md.set_term(term);
+            if let Some(cs) = cs {
+                md.set_conf_state(cs);
+            }
+        }
+        self.snapshot.set_data(data);
+        Ok(&self.snapshot)
+    }
+
+    /// Discards the entries up to `compact_index`, the wal segments which
+    /// only hold such entries are released as well
+    pub fn compact(&mut self, compact_index: u64) -> Result<()> {
+        if compact_index <= self.compacted.0 {
+            return Err(RaftError::Store(StorageError::Compacted).into());
+        }
+        if compact_index > self.last_index() {
+            panic!(
+                "compact {} is out of bound lastindex({})",
+                compact_index,
+                self.last_index()
+            );
+        }
+        let term = self.term(compact_index).unwrap();
+        let n = (compact_index - self.compacted.0) as usize;
+        self.positions.drain(..n);
+        self.compacted = (compact_index, term);
+        while self
+            .cache
+            .front()
+            .map_or(false, |e| e.get_index() <= compact_index)
+        {
+            self.cache.pop_front();
+        }
+        self.wal.release_lock_to(compact_index)
+    }
+}
+
+/// Raft log storage backed by the wal, only the newest entries and the
+/// position of every entry are kept in memory
+#[derive(Clone)]
+pub struct WalStorage {
+    core: Arc<RwLock<WalStorageCore>>,
+}
+
+impl WalStorage {
+    /// Opens the wal of the member in `dir`, or creates it, and indexes the
+    /// entries after the snapshot
+    pub fn open<P: AsRef<Path>>(
+        dir: P,
+        snapshot: Option<Snapshot>,
+        node_id: u64,
+        cluster_id: u64,
+        options: WalOptions,
+    ) -> Result<WalStorage> {
+        let snapshot = snapshot.unwrap_or_default();
+        let (index, term) = {
+            let md = snapshot.get_metadata();
+            (md.get_index(), md.get_term())
+        };
+        let wal = if wal_exists(&dir) {
+            Wal::open_at(&dir, (term, index), node_id, cluster_id, options)?
+        } else {
+            Wal::create(&dir, node_id, cluster_id, options)?
+        };
+        let mut core = WalStorageCore {
+            wal: wal,
+            hard_state: HardState::new(),
+            snapshot: snapshot,
+            compacted: (index, term),
+            positions: VecDeque::new(),
+            cache: VecDeque::new(),
+        };
+        let mut replay = core.wal.replay();
+        for r in &mut replay {
+            match r? {
+                Replayed::Entry(entry, position) => core.index_entry(&entry, position),
+                Replayed::Snapshot(term, index) => core.reset(index, term),
+            }
+        }
+        core.hard_state = core.wal.finish_replay(replay)?;
+        info!(
+            "wal storage opened [first index: {}, last index: {}]",
+            core.first_index(),
+            core.last_index()
+        );
+        Ok(WalStorage {
+            core: Arc::new(RwLock::new(core)),
+        })
+    }
+
+    pub fn rl(&self) -> RwLockReadGuard<WalStorageCore> {
+        self.core.rl()
+    }
+
+    pub fn wl(&self) -> RwLockWriteGuard<WalStorageCore> {
+        self.core.wl()
+    }
+}
+
+impl Storage for WalStorage {
+    fn initial_state(&self) -> RaftResult<RaftState> {
+        let core = self.rl();
+        Ok(RaftState {
+            hard_state: core.hard_state.clone(),
+            conf_state: core.snapshot.get_metadata().get_conf_state().clone(),
+        })
+    }
+
+    fn entries(&self, low: u64, high: u64, max_size: u64) -> RaftResult<Vec<Entry>> {
+        let mut core = self.wl();
+        if low <= core.compacted.0 {
+            return Err(RaftError::Store(StorageError::Compacted));
+        }
+        if high > core.last_index() + 1 {
+            panic!(
+                "index out of bound (last: {}, high: {})",
+                core.last_index() + 1,
+                high
+            );
+        }
+        let mut ents = vec![];
+        let mut size = 0;
+        for idx in low..high {
+            let entry = core
+                .entry(idx)
+                .unwrap_or_else(|e| panic!("failed to read entry {} from wal: {}", idx, e));
+            // return at least one entry even if it is larger than max_size
+            size += u64::from(entry.compute_size());
+            if !ents.is_empty() && size > max_size {
+                break;
+            }
+            ents.push(entry);
+        }
+        Ok(ents)
+    }
+
+    fn term(&self, idx: u64) -> RaftResult<u64> {
+        let core = self.rl();
+        if idx < core.compacted.0 {
+            return Err(RaftError::Store(StorageError::Compacted));
+        }
+        core.term(idx)
+            .ok_or(RaftError::Store(StorageError::Unavailable))
+    }
+
+    fn first_index(&self) -> RaftResult<u64> {
+        Ok(self.rl().first_index())
+    }
+
+    fn last_index(&self) -> RaftResult<u64> {
+        Ok(self.rl().last_index())
+    }
+
+    fn snapshot(&self) -> RaftResult<Snapshot> {
+        Ok(self.rl().snapshot.clone())
+    }
+}
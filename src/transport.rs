@@ -1,10 +1,18 @@
 use std::sync::Arc;
 
+use futures::sync::oneshot::Sender;
 use futures::Future;
-use grpcio::{ChannelBuilder, Environment};
-use proto::mumpb::RaftMessage;
+use grpcio::{
+    CallOption, ChannelBuilder, ClientUnaryReceiver, Environment, Error as GrpcIoError,
+    Result as GrpcResult, RpcStatusCode,
+};
+use proposal::{Callback, ConfCallback, LeaseGrantCallback, LeaseRevokeCallback, TxnCallback};
+use proto::mumpb::{
+    ConfRequest, LeaseGrantRequest, LeaseRevokeRequest, OpRequest, RaftMessage, TxnRequest,
+};
 use proto::mumpb_grpc::MumClient;
 use std::collections::HashMap;
+use std::time::Duration;
 
 use errors::*;
 // use metrics::*;
@@ -13,14 +21,17 @@ pub struct RaftClients {
     env: Arc<Environment>,
     addrs: HashMap<u64, String>,
     conns: HashMap<String, MumClient>,
+    // deadline of the forwarded requests, as long as the proposals may wait
+    forward_timeout: Duration,
 }
 
 impl RaftClients {
-    pub fn new(env: Arc<Environment>) -> RaftClients {
+    pub fn new(env: Arc<Environment>, forward_timeout: Duration) -> RaftClients {
         RaftClients {
             env,
             addrs: HashMap::default(),
             conns: HashMap::default(),
+            forward_timeout: forward_timeout,
         }
     }
 
@@ -35,10 +46,13 @@ impl RaftClients {
         self.addrs.remove(&store_id);
     }
 
-    fn get_conn(&mut self, msg: &RaftMessage) -> Option<(&mut MumClient, &str)> {
-        let key = msg.get_message().get_to();
+    pub fn addr(&self, store_id: u64) -> Option<&str> {
+        self.addrs.get(&store_id).map(|addr| addr.as_str())
+    }
+
+    fn get_conn(&mut self, store_id: u64) -> Option<(&mut MumClient, &str)> {
         let env = &self.env;
-        match self.addrs.get(&key) {
+        match self.addrs.get(&store_id) {
             Some(v) => Some((
                 self.conns.entry(v.to_owned()).or_insert_with(|| {
                     let channel = ChannelBuilder::new(env.clone()).connect(v);
@@ -52,7 +66,7 @@ impl RaftClients {
     }
 
     pub fn send(&mut self, msg: RaftMessage) -> Result<()> {
-        match self.get_conn(&msg) {
+        match self.get_conn(msg.get_message().get_to()) {
             Some((client, addr)) => match client.raft_async(&msg) {
                 Ok(r) => {
                     let addr = addr.to_owned();
@@ -71,4 +85,80 @@ impl RaftClients {
 
         Ok(())
     }
+
+    /// Forwards a request to the peer with `call`, its reply is sent to `cb`
+    fn forward<Req, Resp, F>(
+        &mut self,
+        store_id: u64,
+        req: &Req,
+        cb: Sender<Result<Resp>>,
+        call: F,
+    ) where
+        Resp: Send + 'static,
+        F: FnOnce(&MumClient, &Req, CallOption) -> GrpcResult<ClientUnaryReceiver<Resp>>,
+    {
+        let opt = CallOption::default().timeout(self.forward_timeout);
+        match self.get_conn(store_id) {
+            Some((client, _)) => match call(client, req, opt) {
+                Ok(r) => client.spawn(r.then(move |res| {
+                    let _ = cb.send(res.map_err(|e| match e {
+                        // the leader hung or is partitioned away
+                        GrpcIoError::RpcFailure(ref status)
+                            if status.status == RpcStatusCode::DeadlineExceeded =>
+                        {
+                            Error::ProposalTimeout
+                        }
+                        e => Error::from(e),
+                    }));
+                    Ok(())
+                })),
+                Err(e) => {
+                    let _ = cb.send(Err(e.into()));
+                }
+            },
+            None => {
+                warn!("miss connections {}", store_id);
+                // the peer was removed in between, the client redirects by
+                // itself
+                let _ = cb.send(Err(Error::NotLeader {
+                    leader_id: store_id,
+                    addr: String::new(),
+                }));
+            }
+        }
+    }
+
+    pub fn forward_op(&mut self, store_id: u64, op: &OpRequest, cb: Callback) {
+        self.forward(store_id, op, cb, |c, r, opt| c.op_async_opt(r, opt))
+    }
+
+    pub fn forward_txn(&mut self, store_id: u64, txn: &TxnRequest, cb: TxnCallback) {
+        self.forward(store_id, txn, cb, |c, r, opt| c.txn_async_opt(r, opt))
+    }
+
+    pub fn forward_conf(&mut self, store_id: u64, conf: &ConfRequest, cb: ConfCallback) {
+        self.forward(store_id, conf, cb, |c, r, opt| c.conf_async_opt(r, opt))
+    }
+
+    pub fn forward_lease_grant(
+        &mut self,
+        store_id: u64,
+        req: &LeaseGrantRequest,
+        cb: LeaseGrantCallback,
+    ) {
+        self.forward(store_id, req, cb, |c, r, opt| {
+            c.lease_grant_async_opt(r, opt)
+        })
+    }
+
+    pub fn forward_lease_revoke(
+        &mut self,
+        store_id: u64,
+        req: &LeaseRevokeRequest,
+        cb: LeaseRevokeCallback,
+    ) {
+        self.forward(store_id, req, cb, |c, r, opt| {
+            c.lease_revoke_async_opt(r, opt)
+        })
+    }
 }
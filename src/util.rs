@@ -1,6 +1,6 @@
 use errors::*;
 use itertools::Itertools;
-use std::fs::DirBuilder;
+use std::fs::{DirBuilder, File};
 use std::path::{Path, PathBuf};
 use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
@@ -26,6 +26,13 @@ pub fn create_dir<P: AsRef<Path>>(p: P) -> Result<()> {
     Ok(())
 }
 
+/// Syncs the directory, which makes the creation, the renaming and the
+/// removal of its files durable
+pub fn sync_dir<P: AsRef<Path>>(dir: P) -> Result<()> {
+    File::open(dir)?.sync_all()?;
+    Ok(())
+}
+
 pub fn read_with_ext_and_sort<P: AsRef<Path>>(
     dir: P,
     ext: &str,
@@ -57,6 +64,19 @@ pub fn read_with_ext_and_sort<P: AsRef<Path>>(
         }))
 }
 
+/// Returns the temp files of the files with `ext`, named `{name}.{ext}.{tmp_ext}`,
+/// the temp files of other kinds may share the directory
+pub fn read_tmp_files<P: AsRef<Path>>(dir: P, ext: &str, tmp_ext: &str) -> Result<Vec<PathBuf>> {
+    Ok(read_with_ext_and_sort(dir, tmp_ext, false)?
+        .into_iter()
+        .filter(|path| {
+            path.file_stem()
+                .and_then(|stem| Path::new(stem).extension())
+                .map_or(false, |e| e == ext)
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,193 @@
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use crc::crc32;
+
+const MAGIC: &'static [u8; 8] = b"mumwal\0\0";
+// version of the segment format, bumped on any change of the layout of the
+// header or of the records
+pub const FORMAT_VERSION: u32 = 2;
+// magic, version, node id, cluster id, seq, first index, key id, padding and
+// crc
+pub const HEADER_SIZE: u64 = 56;
+// the first version had no key id nor padding
+const HEADER_SIZE_V1: u64 = 48;
+// magic and version, which tell the size of the rest
+pub const HEADER_PREFIX_SIZE: usize = 12;
+
+/// Header at the start of every segment, it identifies the format and the
+/// member the segment belongs to
+#[derive(Clone, Debug, PartialEq)]
+pub struct SegmentHeader {
+    pub version: u32,
+    pub node_id: u64,
+    pub cluster_id: u64,
+    pub seq: u64,
+    // index of the first raft entry the segment may hold
+    pub first_index: u64,
+    // key the records are encrypted with, 0 if they are not
+    pub key_id: u32,
+}
+
+impl SegmentHeader {
+    pub fn new(
+        node_id: u64,
+        cluster_id: u64,
+        seq: u64,
+        first_index: u64,
+        key_id: u32,
+    ) -> SegmentHeader {
+        SegmentHeader {
+            version: FORMAT_VERSION,
+            node_id: node_id,
+            cluster_id: cluster_id,
+            seq: seq,
+            first_index: first_index,
+            key_id: key_id,
+        }
+    }
+
+    /// Size of the header in the segment, the records follow it
+    pub fn size(&self) -> u64 {
+        size_of(self.version).unwrap()
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        assert_eq!(
+            self.version, FORMAT_VERSION,
+            "only the current format is written"
+        );
+        let mut buf = Vec::with_capacity(HEADER_SIZE as usize);
+        buf.extend_from_slice(MAGIC);
+        buf.write_u32::<LittleEndian>(self.version).unwrap();
+        buf.write_u64::<LittleEndian>(self.node_id).unwrap();
+        buf.write_u64::<LittleEndian>(self.cluster_id).unwrap();
+        buf.write_u64::<LittleEndian>(self.seq).unwrap();
+        buf.write_u64::<LittleEndian>(self.first_index).unwrap();
+        buf.write_u32::<LittleEndian>(self.key_id).unwrap();
+        buf.write_u32::<LittleEndian>(0).unwrap();
+        let crc = crc32::update(0, &crc32::CASTAGNOLI_TABLE, &buf);
+        buf.write_u32::<LittleEndian>(crc).unwrap();
+        buf
+    }
+
+    /// Whether the segment starting with `prefix` was written before the
+    /// header was introduced, it starts with the length of its first record
+    /// instead. `len` is the size of the segment
+    pub fn is_legacy(prefix: &[u8], len: u64) -> bool {
+        if len == 0 {
+            return true;
+        }
+        if prefix.len() < 8 || &prefix[..MAGIC.len()] == MAGIC {
+            return false;
+        }
+        let first = (&prefix[..8]).read_u64::<LittleEndian>().unwrap();
+        first > 0 && first <= len - 8
+    }
+
+    /// Size of the header starting with `prefix`, the first
+    /// `HEADER_PREFIX_SIZE` bytes of a segment
+    pub fn size_from_prefix(prefix: &[u8]) -> ::std::result::Result<u64, String> {
+        if prefix.len() < HEADER_PREFIX_SIZE {
+            return Err(format!("{} bytes, shorter than the header", prefix.len()));
+        }
+        if &prefix[..MAGIC.len()] != MAGIC {
+            return Err("not a wal segment, bad magic".to_owned());
+        }
+        let version = (&prefix[MAGIC.len()..]).read_u32::<LittleEndian>().unwrap();
+        size_of(version).ok_or_else(|| {
+            format!(
+                "format version {}, only up to {} is supported",
+                version, FORMAT_VERSION
+            )
+        })
+    }
+
+    /// Decodes the header, returns why it is invalid otherwise
+    pub fn decode(buf: &[u8]) -> ::std::result::Result<SegmentHeader, String> {
+        let size = SegmentHeader::size_from_prefix(buf)? as usize;
+        if buf.len() < size {
+            return Err(format!("{} bytes, shorter than the header", buf.len()));
+        }
+        let mut rdr = &buf[MAGIC.len()..size];
+        let version = rdr.read_u32::<LittleEndian>().unwrap();
+        let mut header = SegmentHeader {
+            version: version,
+            node_id: rdr.read_u64::<LittleEndian>().unwrap(),
+            cluster_id: rdr.read_u64::<LittleEndian>().unwrap(),
+            seq: rdr.read_u64::<LittleEndian>().unwrap(),
+            first_index: rdr.read_u64::<LittleEndian>().unwrap(),
+            key_id: 0,
+        };
+        if version > 1 {
+            header.key_id = rdr.read_u32::<LittleEndian>().unwrap();
+            rdr.read_u32::<LittleEndian>().unwrap();
+        }
+        let crc = rdr.read_u32::<LittleEndian>().unwrap();
+        if crc != crc32::update(0, &crc32::CASTAGNOLI_TABLE, &buf[..size - 4]) {
+            return Err("header crc mismatch".to_owned());
+        }
+        Ok(header)
+    }
+}
+
+fn size_of(version: u32) -> Option<u64> {
+    match version {
+        1 => Some(HEADER_SIZE_V1),
+        FORMAT_VERSION => Some(HEADER_SIZE),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_test() {
+        let header = SegmentHeader::new(1, 0xc1, 2, 100, 3);
+        let buf = header.encode();
+        assert_eq!(buf.len() as u64, HEADER_SIZE);
+        assert_eq!(SegmentHeader::decode(&buf), Ok(header));
+        assert!(SegmentHeader::decode(&buf[..20]).is_err());
+
+        let mut broken = buf.clone();
+        broken[0] = b'x';
+        assert!(SegmentHeader::decode(&broken)
+            .unwrap_err()
+            .contains("magic"));
+        let mut broken = buf.clone();
+        broken[8] = 9;
+        assert!(SegmentHeader::decode(&broken)
+            .unwrap_err()
+            .contains("version"));
+        let mut broken = buf.clone();
+        broken[20] ^= 1;
+        assert!(SegmentHeader::decode(&broken).unwrap_err().contains("crc"));
+    }
+
+    #[test]
+    fn legacy_test() {
+        let mut buf = vec![];
+        buf.write_u64::<LittleEndian>(20).unwrap();
+        assert!(SegmentHeader::is_legacy(&buf, 28));
+        assert!(!SegmentHeader::is_legacy(&buf, 27));
+        assert!(SegmentHeader::is_legacy(&[], 0));
+        let header = SegmentHeader::new(1, 0xc1, 2, 100, 0).encode();
+        assert!(!SegmentHeader::is_legacy(&header, HEADER_SIZE));
+    }
+
+    #[test]
+    fn header_v1_test() {
+        let mut buf = vec![];
+        buf.extend_from_slice(MAGIC);
+        buf.write_u32::<LittleEndian>(1).unwrap();
+        for v in &[1, 0xc1, 2, 100] {
+            buf.write_u64::<LittleEndian>(*v).unwrap();
+        }
+        let crc = crc32::update(0, &crc32::CASTAGNOLI_TABLE, &buf);
+        buf.write_u32::<LittleEndian>(crc).unwrap();
+        let header = SegmentHeader::decode(&buf).unwrap();
+        assert_eq!((header.version, header.key_id), (1, 0));
+        assert_eq!((header.seq, header.first_index), (2, 100));
+        assert_eq!(header.size(), HEADER_SIZE_V1);
+    }
+}
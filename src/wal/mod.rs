@@ -1,19 +1,30 @@
+mod header;
+mod pipeline;
+mod reader;
 mod record;
+pub mod tool;
 mod wal_file;
 
+use self::header::SegmentHeader;
+use self::pipeline::Pipeline;
+pub use self::reader::{Entries, Reader};
 use self::record::*;
 use self::wal_file::WalFile;
-use bincode::deserialize;
-use crc::crc32;
+use batch::GroupCommit;
+use bincode::{deserialize, serialize};
+use crypto::Keyring;
 use errors::*;
+use metrics::*;
 use protobuf::Message;
 use raft::eraftpb::{Entry, HardState};
 use std::path::{Path, PathBuf};
-use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use util::read_with_ext_and_sort;
 
 const WAL_EXT: &'static str = "wal";
-// const BUFFER_LEN: usize = 4 * 1000 * 1000;
+const SEGMENT_SIZE: u64 = 32 * 1000 * 1000;
+const BUFFER_SIZE: usize = 64 * 1024;
 
 // file.seq & raft.index
 type Index = (u64, u64);
@@ -21,61 +32,306 @@ type Index = (u64, u64);
 // raft.term & raft.index
 type RaftIndex = (u64, u64);
 
+/// When the appends raft asks to sync reach the disk
+#[derive(Clone, Debug, PartialEq)]
+pub enum SyncMode {
+    /// Every ready is synced before its effects are let out
+    Always,
+    /// The readies are synced in groups, their effects are held until then
+    Batch(GroupCommit),
+    /// Synced periodically, the appends acknowledged since the last sync
+    /// are lost if the machine crashes
+    Interval(Duration),
+    /// Never synced, only built for the tests
+    #[cfg(test)]
+    Never,
+}
+
+impl SyncMode {
+    // whether the appends must be synced before they are acknowledged
+    fn holds_acks(&self) -> bool {
+        match *self {
+            SyncMode::Always | SyncMode::Batch(_) => true,
+            SyncMode::Interval(_) => false,
+            #[cfg(test)]
+            SyncMode::Never => false,
+        }
+    }
+}
+
+/// Tunables of the wal, durability against throughput
+#[derive(Clone, Debug)]
+pub struct WalOptions {
+    /// Size the segments are preallocated to, and cut at
+    pub segment_size: u64,
+    pub sync: SyncMode,
+    /// Size of the write buffer of a segment
+    pub buffer_size: usize,
+    /// Records of this many bytes or more are compressed, none if not set
+    pub compress_above: Option<usize>,
+    /// Encrypts the records of the new segments with its active key, and
+    /// decrypts those of the older ones, none keeps them plaintext
+    pub keyring: Option<Arc<Keyring>>,
+}
+
+impl Default for WalOptions {
+    fn default() -> WalOptions {
+        WalOptions {
+            segment_size: SEGMENT_SIZE,
+            sync: SyncMode::Always,
+            buffer_size: BUFFER_SIZE,
+            compress_above: None,
+            keyring: None,
+        }
+    }
+}
+
+/// Location of a record in the wal
+#[derive(Clone, Debug, PartialEq)]
+pub struct Position {
+    // seq of the segment
+    pub seq: u64,
+    // offset in the segment
+    pub offset: u64,
+    // crc the record rolls on from
+    pub crc: u32,
+}
+
+/// Raft data replayed from the wal, in the order it was written
+pub enum Replayed {
+    Entry(Entry, Position),
+    // an applied snapshot (term, index), entries before it are obsolete
+    Snapshot(u64, u64),
+}
+
+/// Replays the wal lazily, the entries and snapshots after the start index
+/// are returned in the order they were written. A torn record at the end of
+/// the newest segment ends the replay, any other broken record fails it with
+/// its segment and offset
+pub struct Replay {
+    records: Reader,
+    start: RaftIndex,
+    // the last hard state replayed
+    state: HardState,
+    // index of the last entry
+    enti: u64,
+    // offset of the torn record and why it is broken
+    torn: Option<(u64, String)>,
+    done: bool,
+}
+
+impl Replay {
+    fn replay(&mut self, position: Position, record: Record) -> Result<Option<Replayed>> {
+        let records = &self.records;
+        let corrupted = |reason: String| records.corrupted(&position, reason);
+        match record.ty {
+            RecordType::EntryType => {
+                let mut entry = Entry::new();
+                entry
+                    .merge_from_bytes(&record.data)
+                    .map_err(|e| corrupted(e.to_string()))?;
+                self.enti = entry.index;
+                if entry.index > self.start.1 {
+                    return Ok(Some(Replayed::Entry(entry, position.clone())));
+                }
+            }
+            RecordType::StateType => {
+                self.state
+                    .merge_from_bytes(&record.data)
+                    .map_err(|e| corrupted(e.to_string()))?;
+            }
+            // the crc of every record is verified on read
+            RecordType::CrcType => (),
+            RecordType::IndexType => {
+                let idx: RaftIndex =
+                    deserialize(&record.data).map_err(|e| corrupted(e.to_string()))?;
+                if idx.1 == self.start.1 && idx.0 != self.start.0 {
+                    return Err(Error::SnapMissMatch);
+                }
+                if idx.1 > self.start.1 {
+                    return Ok(Some(Replayed::Snapshot(idx.0, idx.1)));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Iterator for Replay {
+    type Item = Result<Replayed>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let next = match self.records.next() {
+                Some(next) => next,
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            };
+            let (position, record) = match next {
+                Ok(r) => r,
+                Err(Error::WalTornWrite(_, offset, reason)) => {
+                    self.torn = Some((offset, reason));
+                    self.done = true;
+                    return None;
+                }
+                Err(e) => return Some(Err(e)),
+            };
+            match self.replay(position, record) {
+                Ok(Some(replayed)) => return Some(Ok(replayed)),
+                Ok(None) => (),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
 pub struct Wal {
     dir: PathBuf,
+    // the member the segments belong to
+    node_id: u64,
+    cluster_id: u64,
     state: HardState,
     last_wal_index: Index,
     start: RaftIndex,
     segments: Vec<WalFile>,
     enti: u64,
+    // crc of the last record, the next one rolls on from it
+    crc: u32,
+    // bytes appended since the last sync which must be synced
+    unsynced: u64,
+    // appended to since the last sync, whatever the sync mode
+    dirty: bool,
+    last_sync: Instant,
+    options: WalOptions,
+    pipeline: Pipeline,
 }
 
 impl Wal {
-    pub fn create<P: AsRef<Path>>(dir: P) -> Result<Wal> {
-        // create first wal file
-        let first_wal_path = new_wal_path(&dir, (0, 0));
-
-        Ok(Wal {
+    pub fn create<P: AsRef<Path>>(
+        dir: P,
+        node_id: u64,
+        cluster_id: u64,
+        options: WalOptions,
+    ) -> Result<Wal> {
+        let mut wal = Wal {
             dir: dir.as_ref().to_path_buf(),
+            node_id: node_id,
+            cluster_id: cluster_id,
             state: HardState::new(),
             last_wal_index: (0, 0),
             start: (0, 0),
-            segments: vec![WalFile::create(&first_wal_path)?],
+            segments: vec![],
             enti: 0,
-        })
+            crc: 0,
+            unsynced: 0,
+            dirty: false,
+            last_sync: Instant::now(),
+            pipeline: Pipeline::new(&dir, options.clone())?,
+            options: options,
+        };
+        // create first wal file
+        wal.new_segment((0, 0))?;
+        Ok(wal)
     }
 
+    /// Appends the state and entries, returns the positions of the entries.
+    /// The appends are not synced here but in the interval mode, so that
+    /// several of them share a sync
     pub fn insert(
         &mut self,
         state: Option<HardState>,
         entries: &[Entry],
         must_sync: bool,
-    ) -> Result<()> {
+    ) -> Result<Vec<Position>> {
         if state.is_none() && entries.len() == 0 {
-            return Ok(());
+            return Ok(vec![]);
         }
+        let offset = self.newest_mut().size()?;
+        let mut positions = Vec::with_capacity(entries.len());
         for entry in entries {
-            self.insert_entry(entry)?;
+            positions.push(self.insert_entry(entry)?);
         }
 
         self.insert_state(&state)?;
-        match self.newest_mut().check_cut()? {
-            false => {
-                if must_sync {
-                    self.sync()?;
-                }
+        if must_sync && self.options.sync.holds_acks() {
+            self.unsynced += self.newest_mut().size()? - offset;
+        }
+        // the full segment is synced before the cut
+        if self.newest_mut().check_cut()? {
+            self.cut()?;
+        }
+        self.maybe_sync(Instant::now())?;
+        Ok(positions)
+    }
+
+    /// Syncs in the interval mode once the interval has elapsed since the
+    /// last sync, if anything was appended
+    pub fn maybe_sync(&mut self, now: Instant) -> Result<()> {
+        if let SyncMode::Interval(interval) = self.options.sync {
+            if self.dirty && now >= self.last_sync + interval {
+                self.sync()?;
             }
-            true => self.cut()?,
         }
         Ok(())
     }
 
+    /// Returns the bytes appended since the last sync which must be synced
+    /// before the appends are acknowledged
+    pub fn unsynced(&self) -> u64 {
+        self.unsynced
+    }
+
+    /// Syncs the appends of the newest segment, the older ones were synced
+    /// before the cut. They are only flushed to the os in the never mode
+    pub fn sync(&mut self) -> Result<()> {
+        if self.unsynced > 0 {
+            WAL_SYNC_BATCH_HISTOGRAM.observe(self.unsynced as f64);
+        }
+        match self.options.sync {
+            #[cfg(test)]
+            SyncMode::Never => self.newest_mut().flush()?,
+            _ => self.newest_mut().sync()?,
+        }
+        self.unsynced = 0;
+        self.dirty = false;
+        self.last_sync = Instant::now();
+        Ok(())
+    }
+
+    /// Records that a snapshot at `raft_index` has been applied
+    pub fn insert_snapshot(&mut self, raft_index: RaftIndex) -> Result<()> {
+        self.append(RecordType::IndexType, serialize(&raft_index)?)?;
+        self.sync()
+    }
+
+    pub fn read_entry(&mut self, position: &Position) -> Result<Entry> {
+        let segment = self
+            .segments
+            .iter_mut()
+            .find(|segment| index_from_path(segment).0 == position.seq)
+            .ok_or_else(|| Error::MissFilePath(format!("wal segment {}", position.seq)))?;
+        let record = segment.read_record_at(position.offset, position.crc)?;
+        let mut entry = Entry::new();
+        entry.merge_from_bytes(&record.data)?;
+        Ok(entry)
+    }
+
     pub fn release_lock_to(&mut self, index: u64) -> Result<()> {
         split_at_index(&mut self.segments, index);
         Ok(())
     }
 
-    pub fn open_at<P: AsRef<Path>>(dir: P, raft_index: RaftIndex) -> Result<Wal> {
+    /// Opens the segments needed to replay from `raft_index`, those of
+    /// another member are refused
+    pub fn open_at<P: AsRef<Path>>(
+        dir: P,
+        raft_index: RaftIndex,
+        node_id: u64,
+        cluster_id: u64,
+        options: WalOptions,
+    ) -> Result<Wal> {
         if !dir.as_ref().exists() {
             return Err(Error::MissFilePath(dir.as_ref().to_string_lossy().into()));
         }
@@ -87,54 +343,91 @@ impl Wal {
 
         let mut segments = vec![];
         for wpath in wpaths {
-            segments.push(WalFile::open(&wpath)?);
+            let segment = WalFile::open(&wpath, &options)?;
+            if let Some(header) = segment.header() {
+                check_header(&wpath, header, node_id, cluster_id)?;
+            }
+            segments.push(segment);
         }
 
         Ok(Wal {
             dir: dir.as_ref().to_path_buf(),
+            node_id: node_id,
+            cluster_id: cluster_id,
             state: HardState::new(),
             start: raft_index,
             last_wal_index: last_wal_index,
             segments: segments,
             enti: 0,
+            // known once replayed
+            crc: 0,
+            unsynced: 0,
+            dirty: false,
+            last_sync: Instant::now(),
+            pipeline: Pipeline::new(&dir, options.clone())?,
+            options: options,
         })
     }
 
-    // todo iterator
-    pub fn read_all(&mut self) -> Result<(HardState, Vec<Entry>)> {
-        let mut ents = vec![];
-        let mut state = HardState::new();
-        for segment in &mut self.segments {
-            for record in segment.into_iter() {
-                match record.ty {
-                    RecordType::EntryType => {
-                        let mut entry = Entry::new();
-                        entry.merge_from_bytes(&record.data).unwrap();
-                        self.enti = entry.index;
-                        if entry.index > self.start.1 {
-                            ents.push(entry);
-                        }
-                    }
-                    RecordType::StateType => {
-                        state.merge_from_bytes(&record.data).unwrap();
-                    }
-                    RecordType::CrcType => {
-                        if record.crc != crc32::update(0, &crc32::CASTAGNOLI_TABLE, &record.data) {
-                            return Err(Error::CrcMissMatch);
-                        }
-                    }
-                    RecordType::IndexType => {
-                        let idx: Index = deserialize(&record.data).unwrap();
-                        if idx.1 == self.start.1 {
-                            if idx.0 != self.start.0 {
-                                return Err(Error::SnapMissMatch);
-                            }
-                        }
-                    }
-                }
+    /// Replays the records after the start index, `finish_replay` sets the
+    /// wal up for the appends once the replay is over
+    pub fn replay(&self) -> Replay {
+        let paths = self
+            .segments
+            .iter()
+            .map(|segment| segment.as_ref().to_path_buf())
+            .collect();
+        Replay {
+            records: Reader::new(paths, self.options.keyring.clone()),
+            start: self.start,
+            state: HardState::new(),
+            enti: 0,
+            torn: None,
+            done: false,
+        }
+    }
+
+    /// Truncates the torn record at the end of the newest segment, if any,
+    /// the appends go after the replayed records and their crc chain rolls
+    /// on. A segment written before the header is never appended to, a new
+    /// segment is cut after it. Returns the last hard state
+    pub fn finish_replay(&mut self, replay: Replay) -> Result<HardState> {
+        assert!(replay.done, "the replay is not over");
+        let (end, crc) = (replay.records.offset(), replay.records.crc());
+        match replay.torn {
+            Some((offset, reason)) => {
+                warn!(
+                    "truncating torn write in wal segment {:?} at offset {}: {}",
+                    self.newest().as_ref(),
+                    offset,
+                    reason
+                );
+                self.newest_mut().truncate(offset)?;
             }
+            None => self.newest_mut().set_end(end)?,
         }
-        Ok((state, ents))
+        self.crc = crc;
+        self.enti = replay.enti;
+        self.state = replay.state.clone();
+        if self.newest().header().is_none() {
+            info!(
+                "cutting the wal after the segment {:?} written before the header",
+                self.newest().as_ref()
+            );
+            self.cut()?;
+        }
+        Ok(replay.state)
+    }
+
+    /// Reads the entries from `index` on lazily, the appended ones included
+    pub fn entries(&mut self, index: u64) -> Result<Entries> {
+        self.newest_mut().flush()?;
+        let paths = self
+            .segments
+            .iter()
+            .map(|segment| segment.as_ref().to_path_buf())
+            .collect();
+        Ok(Reader::new(paths, self.options.keyring.clone()).entries(index))
     }
 
     fn insert_state(&mut self, state: &Option<HardState>) -> Result<()> {
@@ -142,19 +435,33 @@ impl Wal {
             return Ok(());
         }
         self.state = state.as_ref().unwrap().clone();
-        let record = Record::new(RecordType::StateType, self.state.write_to_bytes()?);
-        self.newest_mut().insert_record(record)?;
+        let data = self.state.write_to_bytes()?;
+        self.append(RecordType::StateType, data)?;
         Ok(())
     }
 
-    fn insert_entry(&mut self, entry: &Entry) -> Result<()> {
-        let record = Record::new(RecordType::EntryType, entry.write_to_bytes()?);
-        self.newest_mut().insert_record(record)?;
+    fn insert_entry(&mut self, entry: &Entry) -> Result<Position> {
+        let position = self.append(RecordType::EntryType, entry.write_to_bytes()?)?;
         self.enti = entry.index;
-        Ok(())
+        Ok(position)
+    }
+
+    /// Appends a record to the newest segment, its crc rolls on from the
+    /// last one
+    fn append(&mut self, ty: RecordType, data: Vec<u8>) -> Result<Position> {
+        let crc = self.crc;
+        let record = Record::new(ty, data, crc);
+        let next = record.crc;
+        let offset = self.newest_mut().insert_record(record)?;
+        self.crc = next;
+        self.dirty = true;
+        Ok(Position {
+            seq: self.last_wal_index.0,
+            offset: offset,
+            crc: crc,
+        })
     }
 
-    #[allow(dead_code)]
     #[inline]
     fn newest(&self) -> &WalFile {
         self.segments.last().unwrap()
@@ -165,18 +472,42 @@ impl Wal {
         self.segments.last_mut().unwrap()
     }
 
-    fn sync(&mut self) -> Result<()> {
-        self.newest_mut().sync()
+    fn cut(&mut self) -> Result<()> {
+        self.sync()?;
+        // the next segment starts with the next raft entry
+        let idx = (self.last_wal_index.0 + 1, self.enti + 1);
+        self.new_segment(idx)
     }
 
-    fn cut(&mut self) -> Result<()> {
+    /// Starts the segment `idx` from the spare one. Its header is synced
+    /// before it is renamed into place, so a crash never leaves a partial
+    /// segment under a valid name
+    fn new_segment(&mut self, idx: Index) -> Result<()> {
+        let mut segment = self.pipeline.take()?;
+        let key_id = self
+            .options
+            .keyring
+            .as_ref()
+            .map_or(0, |keyring| keyring.active_id());
+        segment.write_header(SegmentHeader::new(
+            self.node_id,
+            self.cluster_id,
+            idx.0,
+            idx.1,
+            key_id,
+        ))?;
+        self.segments.push(segment);
+        self.last_wal_index = idx;
+        // the segment starts by carrying over the crc of the previous one
+        self.append(RecordType::CrcType, vec![])?;
+        // and the hard state, the previous ones may be purged
+        if self.state != HardState::new() {
+            let data = self.state.write_to_bytes()?;
+            self.append(RecordType::StateType, data)?;
+        }
         self.sync()?;
-        let wpath = new_wal_path(
-            &self.dir,
-            (self.last_wal_index.0 + 1, self.last_wal_index.1 + 1),
-        );
-        self.segments.push(WalFile::create(&wpath)?);
-        Ok(())
+        let wpath = new_wal_path(&self.dir, idx);
+        self.newest_mut().rename(&wpath)
     }
 }
 
@@ -199,6 +530,52 @@ fn split_at_index<P: AsRef<Path>>(paths: &mut Vec<P>, index: u64) {
     paths.reverse();
 }
 
+/// Rejects a segment of another member, or whose header disagrees with its
+/// name
+fn check_header<P: AsRef<Path>>(
+    path: P,
+    header: &SegmentHeader,
+    node_id: u64,
+    cluster_id: u64,
+) -> Result<()> {
+    let path_str = path.as_ref().to_string_lossy().into_owned();
+    if header.cluster_id != cluster_id {
+        let reason = format!("cluster {}, want {}", header.cluster_id, cluster_id);
+        return Err(Error::WalForeignSegment(path_str, reason));
+    }
+    if header.node_id != node_id {
+        let reason = format!("node {}, want {}", header.node_id, node_id);
+        return Err(Error::WalForeignSegment(path_str, reason));
+    }
+    if (header.seq, header.first_index) != index_from_path(&path) {
+        let reason = format!(
+            "seq {} and first index {} disagree with the name",
+            header.seq, header.first_index
+        );
+        return Err(Error::WalHeader(path_str, reason));
+    }
+    Ok(())
+}
+
+/// Returns the segments, oldest first, which only hold entries before the
+/// segment `open_at(dir, index)` starts replaying from
+pub fn obsolete_segments<P: AsRef<Path>>(dir: P, index: u64) -> Result<Vec<PathBuf>> {
+    let mut paths = read_with_ext_and_sort(&dir, WAL_EXT, false)?;
+    let needed = paths
+        .iter()
+        .rposition(|path| index_from_path(path).1 <= index)
+        .unwrap_or(0);
+    paths.truncate(needed);
+    Ok(paths)
+}
+
+/// Index of the first entry of the oldest segment, the wal can't be
+/// replayed from an older index
+pub fn first_index<P: AsRef<Path>>(dir: P) -> Result<Option<u64>> {
+    let paths = read_with_ext_and_sort(&dir, WAL_EXT, false)?;
+    Ok(paths.first().map(|path| index_from_path(path).1))
+}
+
 pub fn wal_exists<P: AsRef<Path>>(path: P) -> bool {
     read_with_ext_and_sort(&path, WAL_EXT, false).unwrap().len() > 0
 }
@@ -234,8 +611,8 @@ fn index_from_path<P: AsRef<Path>>(p: P) -> Index {
         .expect(&err_msg);
     match &*stem.split('-').collect::<Vec<&str>>() {
         &[seq, index] => {
-            let seq = u64::from_str(seq).expect(&err_msg);
-            let index = u64::from_str(index).expect(&err_msg);
+            let seq = u64::from_str_radix(seq, 16).expect(&err_msg);
+            let index = u64::from_str_radix(index, 16).expect(&err_msg);
             return (seq, index);
         }
         _ => panic!("invalid format of file name"),
@@ -243,4 +620,337 @@ fn index_from_path<P: AsRef<Path>>(p: P) -> Index {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::header::HEADER_SIZE;
+    use super::*;
+    use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+    use std::fs::{self, File, OpenOptions};
+    use std::io::{Read, Seek, SeekFrom, Write};
+    use util::create_dir;
+
+    fn entry(index: u64) -> Entry {
+        let mut entry = Entry::new();
+        entry.set_index(index);
+        entry.set_term(1);
+        entry.set_data(vec![index as u8; 16]);
+        entry
+    }
+
+    /// Replays the wal, returns the number of entries replayed
+    fn replayed(wal: &mut Wal) -> Result<usize> {
+        let mut replay = wal.replay();
+        let mut n = 0;
+        for r in &mut replay {
+            if let Replayed::Entry(..) = r? {
+                n += 1;
+            }
+        }
+        wal.finish_replay(replay)?;
+        Ok(n)
+    }
+
+    fn new_wal(dir: &str, n: u64) -> Vec<Position> {
+        let _ = fs::remove_dir_all(dir);
+        create_dir(dir).unwrap();
+        let mut wal = Wal::create(dir, 1, 1, WalOptions::default()).unwrap();
+        let ents: Vec<Entry> = (1..n + 1).map(entry).collect();
+        wal.insert(None, &ents, true).unwrap()
+    }
+
+    #[test]
+    fn torn_write_test() {
+        let dir = "./file_tests/wal_torn";
+        let positions = new_wal(dir, 3);
+        let path = new_wal_path(dir, (0, 0));
+        let len = fs::metadata(&path).unwrap().len();
+        // a crash while appending the third entry, the preallocated zeros
+        // follow its length and the first bytes of the record
+        let mut f = OpenOptions::new().write(true).open(&path).unwrap();
+        f.seek(SeekFrom::Start(positions[2].offset + 10)).unwrap();
+        f.write_all(&[0; 32]).unwrap();
+        drop(f);
+
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 2);
+        assert_eq!(fs::metadata(&path).unwrap().len(), len);
+        wal.insert(None, &[entry(3)], true).unwrap();
+        drop(wal);
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 3);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unallocated_torn_write_test() {
+        let dir = "./file_tests/wal_unallocated_torn";
+        let positions = new_wal(dir, 2);
+        let path = new_wal_path(dir, (0, 0));
+        // a crash while appending the second entry to a segment which was not
+        // preallocated, the file ends within its nonzero payload
+        let mut f = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&path)
+            .unwrap();
+        f.seek(SeekFrom::Start(positions[1].offset)).unwrap();
+        let (len, _) = split_len(f.read_u64::<LittleEndian>().unwrap());
+        f.set_len(positions[1].offset + 8 + len - 4).unwrap();
+        drop(f);
+
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 1);
+        wal.insert(None, &[entry(2)], true).unwrap();
+        drop(wal);
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 2);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unsynced_test() {
+        let dir = "./file_tests/wal_unsynced";
+        new_wal(dir, 1);
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        replayed(&mut wal).unwrap();
+        wal.insert(None, &[entry(2)], false).unwrap();
+        assert_eq!(wal.unsynced(), 0);
+        wal.insert(None, &[entry(3)], true).unwrap();
+        let unsynced = wal.unsynced();
+        assert!(unsynced > 0);
+        wal.insert(None, &[entry(4)], true).unwrap();
+        assert_eq!(wal.unsynced(), 2 * unsynced);
+        wal.sync().unwrap();
+        assert_eq!(wal.unsynced(), 0);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn options_test() {
+        let dir = "./file_tests/wal_options";
+        let _ = fs::remove_dir_all(dir);
+        create_dir(dir).unwrap();
+        let options = WalOptions {
+            segment_size: 256,
+            sync: SyncMode::Interval(Duration::from_secs(60)),
+            buffer_size: 16,
+            compress_above: None,
+            keyring: None,
+        };
+        let mut wal = Wal::create(dir, 1, 1, options).unwrap();
+        // acknowledged without a sync, which waits for the interval
+        wal.insert(None, &[entry(1)], true).unwrap();
+        assert_eq!(wal.unsynced(), 0);
+        assert!(wal.dirty);
+        let now = Instant::now();
+        wal.maybe_sync(now).unwrap();
+        assert!(wal.dirty);
+        wal.maybe_sync(now + Duration::from_secs(60)).unwrap();
+        assert!(!wal.dirty);
+        // the small segments are cut sooner
+        for i in 2..20 {
+            wal.insert(None, &[entry(i)], true).unwrap();
+        }
+        assert!(wal.segments.len() > 2);
+        assert!(wal.segments[0].file_size().unwrap() < 512);
+        drop(wal);
+
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 19);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn never_sync_test() {
+        let dir = "./file_tests/wal_never_sync";
+        let _ = fs::remove_dir_all(dir);
+        create_dir(dir).unwrap();
+        let options = WalOptions {
+            sync: SyncMode::Never,
+            ..WalOptions::default()
+        };
+        let mut wal = Wal::create(dir, 1, 1, options).unwrap();
+        wal.insert(None, &[entry(1)], true).unwrap();
+        assert_eq!(wal.unsynced(), 0);
+        // only flushed to the os
+        wal.sync().unwrap();
+        drop(wal);
+
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn segment_chain_test() {
+        let dir = "./file_tests/wal_chain";
+        new_wal(dir, 1);
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        replayed(&mut wal).unwrap();
+        for i in 2..4 {
+            wal.cut().unwrap();
+            wal.insert(None, &[entry(i)], true).unwrap();
+        }
+        drop(wal);
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 3);
+        // the chain goes on after a restart
+        wal.insert(None, &[entry(4)], true).unwrap();
+        drop(wal);
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 4);
+        drop(wal);
+
+        // the records of the second segment replaced by the third one's
+        let paths = read_with_ext_and_sort(dir, WAL_EXT, false).unwrap();
+        let mut second = vec![];
+        File::open(&paths[1])
+            .unwrap()
+            .read_to_end(&mut second)
+            .unwrap();
+        let mut third = vec![];
+        File::open(&paths[2])
+            .unwrap()
+            .read_to_end(&mut third)
+            .unwrap();
+        let header = HEADER_SIZE as usize;
+        second.truncate(header);
+        second.extend_from_slice(&third[header..]);
+        File::create(&paths[1]).unwrap().write_all(&second).unwrap();
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        match replayed(&mut wal) {
+            Err(Error::WalCorrupted(path, offset, _)) => {
+                assert_eq!(Path::new(&path), paths[1].as_path());
+                assert_eq!(offset, HEADER_SIZE);
+            }
+            _ => panic!("expected broken chain"),
+        }
+        drop(wal);
+
+        // the header disagrees with the name
+        fs::copy(&paths[2], &paths[1]).unwrap();
+        match Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()) {
+            Err(Error::WalHeader(path, _)) => assert_eq!(Path::new(&path), paths[1].as_path()),
+            _ => panic!("expected invalid header"),
+        }
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn entries_test() {
+        let dir = "./file_tests/wal_entries";
+        new_wal(dir, 2);
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        replayed(&mut wal).unwrap();
+        wal.cut().unwrap();
+        wal.insert(None, &[entry(3), entry(4)], false).unwrap();
+        let read: Vec<u64> = wal
+            .entries(2)
+            .unwrap()
+            .map(|r| r.unwrap().0.index)
+            .collect();
+        assert_eq!(read, vec![2, 3, 4]);
+        drop(wal);
+
+        // the segments before the one holding the index are skipped
+        let mut reader = Reader::open_at(dir, 3, None).unwrap();
+        let (position, record) = reader.next().unwrap().unwrap();
+        assert_eq!((position.seq, record.ty), (1, RecordType::CrcType));
+        let (entry, position) = reader.entries(0).next().unwrap().unwrap();
+        assert_eq!((entry.index, position.seq), (3, 1));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn legacy_segment_test() {
+        let dir = "./file_tests/wal_legacy";
+        let _ = fs::remove_dir_all(dir);
+        create_dir(dir).unwrap();
+        // written before the header, the crc of every record is standalone
+        let path = new_wal_path(dir, (0, 0));
+        let mut data = vec![];
+        for i in 1..4 {
+            let record = Record::new(RecordType::EntryType, entry(i).write_to_bytes().unwrap(), 0);
+            let bytes = serialize(&record).unwrap();
+            data.write_u64::<LittleEndian>(bytes.len() as u64).unwrap();
+            data.extend_from_slice(&bytes);
+        }
+        fs::write(&path, &data).unwrap();
+
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 3);
+        // the appends go to a new segment
+        wal.insert(None, &[entry(4)], true).unwrap();
+        drop(wal);
+        assert_eq!(fs::read(&path).unwrap(), data);
+        assert!(new_wal_path(dir, (1, 4)).exists());
+
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 4);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn foreign_segment_test() {
+        let dir = "./file_tests/wal_foreign";
+        new_wal(dir, 1);
+        match Wal::open_at(dir, (0, 0), 2, 1, WalOptions::default()) {
+            Err(Error::WalForeignSegment(_, reason)) => assert!(reason.contains("node")),
+            _ => panic!("expected foreign segment"),
+        }
+        match Wal::open_at(dir, (0, 0), 1, 2, WalOptions::default()) {
+            Err(Error::WalForeignSegment(_, reason)) => assert!(reason.contains("cluster")),
+            _ => panic!("expected foreign segment"),
+        }
+        Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn encryption_test() {
+        let dir = "./file_tests/wal_encrypted";
+        let _ = fs::remove_dir_all(dir);
+        create_dir(dir).unwrap();
+        let key_path = Path::new(dir).join("keys");
+        fs::write(&key_path, format!("7 {}\n", "5a".repeat(32))).unwrap();
+        let options = WalOptions {
+            keyring: Some(Arc::new(Keyring::load(&key_path).unwrap())),
+            ..WalOptions::default()
+        };
+        let mut wal = Wal::create(dir, 1, 1, options.clone()).unwrap();
+        let mut secret = entry(1);
+        secret.set_data(b"plaintext secret".to_vec());
+        wal.insert(None, &[secret], true).unwrap();
+        drop(wal);
+
+        let mut data = vec![];
+        let path = new_wal_path(dir, (0, 0));
+        File::open(&path).unwrap().read_to_end(&mut data).unwrap();
+        assert!(!data.windows(16).any(|w| w == b"plaintext secret"));
+        match Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()) {
+            Err(Error::UnknownKey(7)) => (),
+            _ => panic!("expected unknown key"),
+        }
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, options).unwrap();
+        assert_eq!(replayed(&mut wal).unwrap(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn corruption_test() {
+        let dir = "./file_tests/wal_corrupted";
+        let positions = new_wal(dir, 3);
+        let path = new_wal_path(dir, (0, 0));
+        let mut data = vec![];
+        File::open(&path).unwrap().read_to_end(&mut data).unwrap();
+        // the last byte of the second entry
+        data[positions[2].offset as usize - 1] ^= 0xff;
+        File::create(&path).unwrap().write_all(&data).unwrap();
+
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        match replayed(&mut wal) {
+            Err(Error::WalCorrupted(_, offset, _)) => assert_eq!(offset, positions[1].offset),
+            _ => panic!("expected corruption"),
+        }
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
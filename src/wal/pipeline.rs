@@ -0,0 +1,82 @@
+use super::wal_file::WalFile;
+use super::{WalOptions, WAL_EXT};
+use errors::*;
+use std::fs;
+use std::path::Path;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::thread;
+use util::read_tmp_files;
+
+const TMP_EXT: &'static str = "tmp";
+
+/// Preallocates the segments in the background, a spare one waits for the
+/// next cut. The spares are `.wal.tmp` files, a cut renames one into place
+/// once its header is written
+pub struct Pipeline {
+    rx: Receiver<Result<WalFile>>,
+}
+
+impl Pipeline {
+    pub fn new<P: AsRef<Path>>(dir: P, options: WalOptions) -> Result<Pipeline> {
+        // the spares of a crashed member, never renamed into place
+        for path in read_tmp_files(&dir, WAL_EXT, TMP_EXT)? {
+            fs::remove_file(path)?;
+        }
+        let dir = dir.as_ref().to_path_buf();
+        // the spare is handed over as soon as it is taken, then the next one
+        // is allocated
+        let (tx, rx) = sync_channel(0);
+        thread::Builder::new()
+            .name("wal-pipeline".to_owned())
+            .spawn(move || {
+                for count in 0.. {
+                    let path = dir.join(format!("{}.{}.{}", count, WAL_EXT, TMP_EXT));
+                    if let Err(e) = tx.send(WalFile::create(&path, &options)) {
+                        // the wal is closed
+                        if let Ok(spare) = e.0 {
+                            drop(spare);
+                            let _ = fs::remove_file(&path);
+                        }
+                        return;
+                    }
+                }
+            })?;
+        Ok(Pipeline { rx: rx })
+    }
+
+    /// Takes the spare segment, waits for it if it is not allocated yet
+    pub fn take(&self) -> Result<WalFile> {
+        self.rx
+            .recv()
+            .map_err(|_| Error::UnboundReceiverError("wal pipeline".to_owned()))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use util::create_dir;
+
+    #[test]
+    fn pipeline_test() {
+        let dir = "./file_tests/wal_pipeline";
+        create_dir(dir).unwrap();
+        fs::File::create(Path::new(dir).join("7.wal.tmp")).unwrap();
+        // the temp file of a snapshot saved to the same directory
+        let snap_tmp = Path::new(dir).join("1.snap.tmp");
+        fs::File::create(&snap_tmp).unwrap();
+        let pipeline = Pipeline::new(dir, WalOptions::default()).unwrap();
+        let mut spare = pipeline.take().unwrap();
+        assert_eq!(spare.as_ref(), Path::new(dir).join("0.wal.tmp").as_path());
+        assert_eq!(spare.size().unwrap(), 0);
+        spare.rename(Path::new(dir).join("0.wal")).unwrap();
+        drop(pipeline);
+        drop(spare);
+        // the leftover spare is removed, the next one as well once the
+        // pipeline is dropped
+        let paths = read_tmp_files(dir, WAL_EXT, TMP_EXT).unwrap();
+        assert!(paths.iter().all(|p| p.file_name().unwrap() == "1.wal.tmp"));
+        assert!(snap_tmp.exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
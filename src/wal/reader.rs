@@ -0,0 +1,178 @@
+use super::record::*;
+use super::wal_file::{read_segment, WalFileIterator};
+use super::{split_at_index, Position, WAL_EXT};
+use crypto::Keyring;
+use errors::*;
+use protobuf::Message;
+use raft::eraftpb::Entry;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use util::read_with_ext_and_sort;
+
+/// Reads the records of the segments in order, lazily and one segment at a
+/// time. The crc chain is verified across the segments, only the newest one
+/// may end with a torn write, which is returned as `WalTornWrite`. The
+/// iteration stops after the first error
+pub struct Reader {
+    // the segments left, oldest first
+    paths: VecDeque<PathBuf>,
+    // seq of the segment read and its records
+    segment: Option<(u64, WalFileIterator<File>)>,
+    // crc the next segment carries over, unknown before the first one
+    chain: Option<u32>,
+    // decrypts the records of the encrypted segments
+    keyring: Option<Arc<Keyring>>,
+    failed: bool,
+}
+
+impl Reader {
+    pub fn new(paths: Vec<PathBuf>, keyring: Option<Arc<Keyring>>) -> Reader {
+        Reader {
+            paths: paths.into_iter().collect(),
+            segment: None,
+            chain: None,
+            keyring: keyring,
+            failed: false,
+        }
+    }
+
+    /// Reads the segments of the wal in `dir` which hold the entries from
+    /// `index` on, the segments are not locked
+    pub fn open_at<P: AsRef<Path>>(
+        dir: P,
+        index: u64,
+        keyring: Option<Arc<Keyring>>,
+    ) -> Result<Reader> {
+        let mut paths = read_with_ext_and_sort(&dir, WAL_EXT, false)?;
+        if paths.is_empty() {
+            return Err(Error::MissFilePath(dir.as_ref().to_string_lossy().into()));
+        }
+        split_at_index(&mut paths, index);
+        Ok(Reader::new(paths, keyring))
+    }
+
+    /// Offset of the next record in the segment read, the end of the records
+    /// of the newest segment once the iteration is over
+    pub fn offset(&self) -> u64 {
+        self.segment
+            .as_ref()
+            .map_or(0, |&(_, ref iter)| iter.offset())
+    }
+
+    /// Crc the next record rolls on from
+    pub fn crc(&self) -> u32 {
+        match self.segment {
+            Some((_, ref iter)) => iter.crc(),
+            None => self.chain.unwrap_or(0),
+        }
+    }
+
+    /// Error of an undecodable record of the segment read
+    pub fn corrupted(&self, position: &Position, reason: String) -> Error {
+        let path = self
+            .segment
+            .as_ref()
+            .map_or(String::new(), |&(_, ref iter)| {
+                iter.path().to_string_lossy().into_owned()
+            });
+        Error::WalCorrupted(path, position.offset, reason)
+    }
+
+    /// The entries from `index` on, in the order they were written. An
+    /// entry overwritten by a newer leader is returned before the entry
+    /// replacing it
+    pub fn entries(self, index: u64) -> Entries {
+        Entries {
+            records: self,
+            index: index,
+        }
+    }
+
+    fn read(&mut self) -> Option<Result<(Position, Record)>> {
+        loop {
+            if self.segment.is_none() {
+                let path = self.paths.pop_front()?;
+                match read_segment(&path, self.chain, self.keyring.as_ref()) {
+                    Ok((seq, mut iter)) => {
+                        iter.set_newest(self.paths.is_empty());
+                        self.segment = Some((seq, iter));
+                    }
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+            let newest = self.paths.is_empty();
+            let (next, crc) = {
+                let &mut (seq, ref mut iter) = self.segment.as_mut().unwrap();
+                let position = Position {
+                    seq: seq,
+                    offset: iter.offset(),
+                    crc: iter.crc(),
+                };
+                let next = iter.next().map(|r| r.map(|record| (position, record)));
+                (next, iter.crc())
+            };
+            match next {
+                Some(Err(Error::WalTornWrite(path, offset, reason))) => {
+                    // only the newest segment may be cut short by a crash,
+                    // the older ones were synced before the cut
+                    if newest {
+                        return Some(Err(Error::WalTornWrite(path, offset, reason)));
+                    }
+                    return Some(Err(Error::WalCorrupted(path, offset, reason)));
+                }
+                Some(r) => return Some(r),
+                None if newest => return None,
+                None => {
+                    self.chain = Some(crc);
+                    self.segment = None;
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for Reader {
+    type Item = Result<(Position, Record)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let next = self.read();
+        if let Some(Err(_)) = next {
+            self.failed = true;
+        }
+        next
+    }
+}
+
+/// Reads the entries from a raft index on, lazily
+pub struct Entries {
+    records: Reader,
+    index: u64,
+}
+
+impl Iterator for Entries {
+    type Item = Result<(Entry, Position)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (position, record) = match self.records.next()? {
+                Ok(r) => r,
+                Err(e) => return Some(Err(e)),
+            };
+            if record.ty != RecordType::EntryType {
+                continue;
+            }
+            let mut entry = Entry::new();
+            if let Err(e) = entry.merge_from_bytes(&record.data) {
+                return Some(Err(self.records.corrupted(&position, e.to_string())));
+            }
+            if entry.index >= self.index {
+                return Some(Ok((entry, position)));
+            }
+        }
+    }
+}
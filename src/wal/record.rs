@@ -1,4 +1,19 @@
+use super::header::SegmentHeader;
+use bincode::{deserialize, serialize};
+use byteorder::{LittleEndian, WriteBytesExt};
 use crc::crc32;
+use crypto::Keyring;
+use errors::*;
+use metrics::*;
+use std::sync::Arc;
+use zstd;
+
+// the top bit of the length word of a record marks its bytes compressed,
+// the records of older segments never have it set
+const COMPRESSED: u64 = 1 << 63;
+// fast, most of the gain is on repetitive values
+const COMPRESSION_LEVEL: i32 = 1;
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub enum RecordType {
     EntryType,
@@ -15,11 +30,133 @@ pub struct Record {
 }
 
 impl Record {
-    pub fn new(ty: RecordType, data: Vec<u8>) -> Record {
+    /// Creates a record whose crc rolls on from `crc`, the one of the record
+    /// written before it. A `CrcType` record has no data, so its crc is the
+    /// one it carries over
+    pub fn new(ty: RecordType, data: Vec<u8>, crc: u32) -> Record {
         Record {
             ty: ty,
-            crc: crc32::update(0, &crc32::CASTAGNOLI_TABLE, &data),
+            crc: crc32::update(crc, &crc32::CASTAGNOLI_TABLE, &data),
             data: data,
         }
     }
+
+    /// Whether the data matches the crc rolled on from `crc`
+    pub fn is_valid(&self, crc: u32) -> bool {
+        self.crc == crc32::update(crc, &crc32::CASTAGNOLI_TABLE, &self.data)
+    }
+}
+
+/// The key the records of a segment are encrypted with. A record is bound to
+/// its segment and offset, it can't be moved elsewhere unnoticed
+#[derive(Clone)]
+pub struct SegmentKey {
+    keyring: Arc<Keyring>,
+    key_id: u32,
+    seq: u64,
+}
+
+impl SegmentKey {
+    /// The key of the segment, none if its records are plaintext
+    pub fn new(
+        keyring: Option<&Arc<Keyring>>,
+        header: &SegmentHeader,
+    ) -> Result<Option<SegmentKey>> {
+        if header.key_id == 0 {
+            return Ok(None);
+        }
+        match keyring {
+            Some(keyring) if keyring.has_key(header.key_id) => Ok(Some(SegmentKey {
+                keyring: keyring.clone(),
+                key_id: header.key_id,
+                seq: header.seq,
+            })),
+            _ => Err(Error::UnknownKey(header.key_id)),
+        }
+    }
+
+    fn ad(&self, offset: u64) -> Vec<u8> {
+        let mut ad = Vec::with_capacity(16);
+        ad.write_u64::<LittleEndian>(self.seq).unwrap();
+        ad.write_u64::<LittleEndian>(offset).unwrap();
+        ad
+    }
+}
+
+/// Serializes the record, compressed if it takes `compress_above` bytes or
+/// more and compression shrinks it, then encrypted if the segment has a key.
+/// Returns the length word written at `offset` before the bytes, and the
+/// bytes
+pub fn encode(
+    record: &Record,
+    compress_above: Option<usize>,
+    key: Option<&SegmentKey>,
+    offset: u64,
+) -> Result<(u64, Vec<u8>)> {
+    let (bytes, compressed) = compress(serialize(record)?, compress_above)?;
+    let bytes = match key {
+        Some(key) => key.keyring.seal(key.key_id, &key.ad(offset), &bytes)?,
+        None => bytes,
+    };
+    let flag = if compressed { COMPRESSED } else { 0 };
+    Ok((bytes.len() as u64 | flag, bytes))
+}
+
+fn compress(bytes: Vec<u8>, compress_above: Option<usize>) -> Result<(Vec<u8>, bool)> {
+    match compress_above {
+        Some(threshold) if bytes.len() >= threshold => {
+            let compressed = zstd::encode_all(bytes.as_slice(), COMPRESSION_LEVEL)?;
+            WAL_COMPRESSION_BYTES_COUNTER_VEC
+                .with_label_values(&["raw"])
+                .inc_by(bytes.len() as i64);
+            WAL_COMPRESSION_RATIO_HISTOGRAM.observe(compressed.len() as f64 / bytes.len() as f64);
+            if compressed.len() < bytes.len() {
+                WAL_COMPRESSION_BYTES_COUNTER_VEC
+                    .with_label_values(&["stored"])
+                    .inc_by(compressed.len() as i64);
+                return Ok((compressed, true));
+            }
+            WAL_COMPRESSION_BYTES_COUNTER_VEC
+                .with_label_values(&["stored"])
+                .inc_by(bytes.len() as i64);
+            Ok((bytes, false))
+        }
+        _ => Ok((bytes, false)),
+    }
+}
+
+/// Splits the length word into the length of the bytes which follow it and
+/// whether they are compressed
+pub fn split_len(word: u64) -> (u64, bool) {
+    (word & !COMPRESSED, word & COMPRESSED != 0)
+}
+
+/// Deserializes the bytes of the record at `offset`, returns why they can't
+/// be otherwise. The crc is left to the caller
+pub fn decode(
+    bytes: &[u8],
+    compressed: bool,
+    key: Option<&SegmentKey>,
+    offset: u64,
+) -> ::std::result::Result<Record, String> {
+    let decrypted;
+    let bytes = match key {
+        Some(key) => {
+            decrypted = key
+                .keyring
+                .open(key.key_id, &key.ad(offset), bytes)
+                .map_err(|e| format!("undecryptable record: {}", e))?;
+            decrypted.as_slice()
+        }
+        None => bytes,
+    };
+    let decompressed;
+    let bytes = if compressed {
+        decompressed =
+            zstd::decode_all(bytes).map_err(|e| format!("undecompressable record: {}", e))?;
+        decompressed.as_slice()
+    } else {
+        bytes
+    };
+    deserialize(bytes).map_err(|e| format!("undecodable record: {}", e))
 }
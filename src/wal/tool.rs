@@ -0,0 +1,298 @@
+pub use super::header::SegmentHeader;
+use super::reader::Reader;
+use super::record::*;
+use super::wal_file::{read_segment_header, WalFile};
+use super::{index_from_path, Position, WalOptions, WAL_EXT};
+use bincode::deserialize;
+use crypto::Keyring;
+use errors::*;
+use protobuf::Message;
+use raft::eraftpb::{Entry, HardState};
+use std::cmp;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use util::{read_with_ext_and_sort, sync_dir};
+
+/// A segment of the wal directory
+pub struct SegmentInfo {
+    pub path: PathBuf,
+    // seq and first index, from the name
+    pub seq: u64,
+    pub first_index: u64,
+    /// Size of the file, the preallocated space included
+    pub size: u64,
+    /// None for a segment written before the header was introduced
+    pub header: Result<Option<SegmentHeader>>,
+}
+
+/// Lists the segments, oldest first
+pub fn segments<P: AsRef<Path>>(dir: P) -> Result<Vec<SegmentInfo>> {
+    let mut segments = vec![];
+    for path in read_with_ext_and_sort(&dir, WAL_EXT, false)? {
+        let (seq, first_index) = index_from_path(&path);
+        segments.push(SegmentInfo {
+            size: fs::metadata(&path)?.len(),
+            header: read_segment_header(&path),
+            path: path,
+            seq: seq,
+            first_index: first_index,
+        });
+    }
+    Ok(segments)
+}
+
+/// Decoded data of a record
+pub enum Content {
+    Entry(Entry),
+    State(HardState),
+    // carries the crc of the previous segment over
+    Crc,
+    // an applied snapshot (term, index)
+    Snapshot(u64, u64),
+}
+
+pub struct RecordDump {
+    pub position: Position,
+    pub crc: u32,
+    pub content: Content,
+}
+
+/// Reads the records of the wal decoded, their crcs verified. The iteration
+/// stops after the first error
+pub struct Records {
+    reader: Reader,
+}
+
+/// Reads the records of every segment, or of the segments holding the
+/// entries from `index` on. The keyring decrypts the encrypted segments
+pub fn records<P: AsRef<Path>>(
+    dir: P,
+    index: Option<u64>,
+    keyring: Option<Arc<Keyring>>,
+) -> Result<Records> {
+    let reader = match index {
+        Some(index) => Reader::open_at(dir, index, keyring)?,
+        None => Reader::new(read_with_ext_and_sort(&dir, WAL_EXT, false)?, keyring),
+    };
+    Ok(Records { reader: reader })
+}
+
+impl Records {
+    fn decode(&self, position: Position, record: Record) -> Result<RecordDump> {
+        let content = {
+            let reader = &self.reader;
+            let corrupted = |reason: String| reader.corrupted(&position, reason);
+            match record.ty {
+                RecordType::EntryType => {
+                    let mut entry = Entry::new();
+                    entry
+                        .merge_from_bytes(&record.data)
+                        .map_err(|e| corrupted(e.to_string()))?;
+                    Content::Entry(entry)
+                }
+                RecordType::StateType => {
+                    let mut state = HardState::new();
+                    state
+                        .merge_from_bytes(&record.data)
+                        .map_err(|e| corrupted(e.to_string()))?;
+                    Content::State(state)
+                }
+                RecordType::CrcType => Content::Crc,
+                RecordType::IndexType => {
+                    let (term, index): (u64, u64) =
+                        deserialize(&record.data).map_err(|e| corrupted(e.to_string()))?;
+                    Content::Snapshot(term, index)
+                }
+            }
+        };
+        Ok(RecordDump {
+            position: position,
+            crc: record.crc,
+            content: content,
+        })
+    }
+}
+
+impl Iterator for Records {
+    type Item = Result<RecordDump>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (position, record) = match self.reader.next()? {
+            Ok(r) => r,
+            Err(e) => return Some(Err(e)),
+        };
+        Some(self.decode(position, record))
+    }
+}
+
+/// What `truncate_after` cuts, or would cut in a dry run
+#[derive(Debug)]
+pub struct Truncation {
+    /// The segment cut, and the offset of its first record dropped
+    pub path: PathBuf,
+    pub offset: u64,
+    /// The newer segments, removed entirely
+    pub removed: Vec<PathBuf>,
+    /// Entries dropped, up to the first broken record if any
+    pub entries: usize,
+    /// The hard state written at the cut, its commit is at most the index
+    pub state: Option<HardState>,
+}
+
+/// Drops every record from the first entry after `index` on, the newest hard
+/// state takes their place with its commit clamped to `index`. An entry at
+/// `index` or before which overwrote an older one after the cut is dropped
+/// as well. Returns `None` if no entry is after `index`. The segments are
+/// locked first, so the wal of a running member is refused
+pub fn truncate_after<P: AsRef<Path>>(
+    dir: P,
+    index: u64,
+    dry_run: bool,
+    keyring: Option<Arc<Keyring>>,
+) -> Result<Option<Truncation>> {
+    let paths = read_with_ext_and_sort(&dir, WAL_EXT, false)?;
+    let mut state = None;
+    let mut cut: Option<Position> = None;
+    let mut entries = 0;
+    let records = Records {
+        reader: Reader::new(paths.clone(), keyring.clone()),
+    };
+    for r in records {
+        let dump = match r {
+            Ok(dump) => dump,
+            // the member truncates a torn write itself
+            Err(Error::WalTornWrite(..)) => break,
+            // the records after the cut are dropped, broken or not
+            Err(_) if cut.is_some() => break,
+            Err(e) => return Err(e),
+        };
+        match dump.content {
+            Content::Entry(ref entry) if entry.index > index => {
+                if cut.is_none() {
+                    cut = Some(dump.position.clone());
+                }
+                entries += 1;
+            }
+            // the term and vote must not go back
+            Content::State(ref hs) => state = Some(hs.clone()),
+            _ => (),
+        }
+    }
+    let cut = match cut {
+        Some(cut) => cut,
+        None => return Ok(None),
+    };
+
+    let path = paths
+        .iter()
+        .find(|path| index_from_path(path).0 == cut.seq)
+        .unwrap()
+        .clone();
+    let removed = paths
+        .iter()
+        .filter(|path| index_from_path(path).0 > cut.seq)
+        .cloned()
+        .collect();
+    let truncation = Truncation {
+        path: path,
+        offset: cut.offset,
+        removed: removed,
+        entries: entries,
+        state: state.map(|mut hs: HardState| {
+            let commit = cmp::min(hs.get_commit(), index);
+            hs.set_commit(commit);
+            hs
+        }),
+    };
+    if dry_run {
+        return Ok(Some(truncation));
+    }
+
+    let options = WalOptions {
+        keyring: keyring,
+        ..WalOptions::default()
+    };
+    let mut segment = WalFile::open(&truncation.path, &options)?;
+    let mut locked = vec![];
+    for path in &truncation.removed {
+        locked.push(WalFile::open(path, &options)?);
+    }
+    // newest first, a crash leaves the older segments whole
+    for path in truncation.removed.iter().rev() {
+        fs::remove_file(path)?;
+    }
+    drop(locked);
+    segment.truncate(cut.offset)?;
+    if let Some(ref hs) = truncation.state {
+        let record = Record::new(RecordType::StateType, hs.write_to_bytes()?, cut.crc);
+        segment.insert_record(record)?;
+    }
+    segment.sync()?;
+    sync_dir(&dir)?;
+    Ok(Some(truncation))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Replayed, Wal};
+    use super::*;
+    use util::create_dir;
+
+    fn entry(index: u64) -> Entry {
+        let mut entry = Entry::new();
+        entry.set_index(index);
+        entry.set_term(1);
+        entry
+    }
+
+    #[test]
+    fn truncate_after_test() {
+        let dir = "./file_tests/wal_tool";
+        let _ = fs::remove_dir_all(dir);
+        create_dir(dir).unwrap();
+        let mut wal = Wal::create(dir, 1, 1, WalOptions::default()).unwrap();
+        let mut hs = HardState::new();
+        hs.set_term(1);
+        hs.set_commit(2);
+        wal.insert(Some(hs.clone()), &[entry(1), entry(2)], true)
+            .unwrap();
+        wal.cut().unwrap();
+        hs.set_commit(4);
+        wal.insert(Some(hs), &[entry(3), entry(4)], true).unwrap();
+        wal.sync().unwrap();
+        // the segments are locked by the wal
+        assert!(truncate_after(dir, 1, false, None).is_err());
+        drop(wal);
+
+        let listed = segments(dir).unwrap();
+        assert_eq!(listed.len(), 2);
+        assert_eq!((listed[1].seq, listed[1].first_index), (1, 3));
+        let header = listed[1].header.as_ref().unwrap();
+        assert_eq!(header.as_ref().unwrap().node_id, 1);
+        let count = |r: Records| r.map(|r| r.unwrap()).count();
+        assert_eq!(count(records(dir, None, None).unwrap()), 9);
+        assert_eq!(count(records(dir, Some(3), None).unwrap()), 5);
+
+        assert!(truncate_after(dir, 4, false, None).unwrap().is_none());
+        let dry = truncate_after(dir, 1, true, None).unwrap().unwrap();
+        assert_eq!((dry.entries, dry.removed.len()), (3, 1));
+        assert_eq!(dry.state.as_ref().unwrap().get_commit(), 1);
+        assert_eq!(segments(dir).unwrap().len(), 2);
+
+        truncate_after(dir, 1, false, None).unwrap().unwrap();
+        assert_eq!(segments(dir).unwrap().len(), 1);
+        let mut wal = Wal::open_at(dir, (0, 0), 1, 1, WalOptions::default()).unwrap();
+        let mut replay = wal.replay();
+        let mut indexes = vec![];
+        for r in &mut replay {
+            if let Replayed::Entry(entry, _) = r.unwrap() {
+                indexes.push(entry.index);
+            }
+        }
+        assert_eq!(indexes, vec![1]);
+        let hs = wal.finish_replay(replay).unwrap();
+        assert_eq!((hs.get_term(), hs.get_commit()), (1, 1));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,18 +1,38 @@
+use super::header::*;
 use super::record::*;
-use bincode::{deserialize, serialize, serialized_size};
+use super::{index_from_path, WalOptions};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use crypto::Keyring;
 use errors::*;
 use fs2::FileExt;
-use std::fs::{File, OpenOptions};
+use metrics::*;
+use std::cmp;
+use std::fs::{self, File, OpenOptions};
 use std::io::{BufReader, BufWriter};
 use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
-
-const SEGMENT_SIZE: u64 = 32 * 1000 * 1000;
+use std::sync::Arc;
+use util::sync_dir;
 
 pub struct WalFile {
     path: PathBuf,
     fd: BufWriter<File>,
+    // offset of the next record
+    offset: u64,
+    // a read moved the file cursor, the next write seeks back to the offset
+    moved: bool,
+    // none until written to a new segment, or for a segment written before
+    // the header was introduced
+    header: Option<SegmentHeader>,
+    // written before the header, its records have standalone crcs
+    legacy: bool,
+    // preallocated size, the segment is cut once it is reached
+    segment_size: u64,
+    // records this large or larger are compressed
+    compress_above: Option<usize>,
+    keyring: Option<Arc<Keyring>>,
+    // the key the records are encrypted with, known from the header
+    key: Option<SegmentKey>,
 }
 
 impl AsRef<Path> for WalFile {
@@ -22,56 +42,174 @@ impl AsRef<Path> for WalFile {
 }
 
 impl WalFile {
-    pub fn create<P: AsRef<Path>>(wal_file_path: P) -> Result<WalFile> {
+    /// Creates an empty segment. Its space is preallocated, which fails early
+    /// if the disk is full and spares the appends from growing the file
+    pub fn create<P: AsRef<Path>>(wal_file_path: P, options: &WalOptions) -> Result<WalFile> {
         let wal_file = OpenOptions::new()
             .read(true)
             .write(true)
             .create(true)
-            .append(true)
             .open(&wal_file_path)?;
-        // check the size of disk
-        //wal_file.allocate(SEGMENT_SIZE as u64)?;
         wal_file.try_lock_exclusive()?;
+        wal_file.set_len(0)?;
+        wal_file.allocate(options.segment_size)?;
 
         Ok(WalFile {
-            fd: BufWriter::new(wal_file),
+            fd: BufWriter::with_capacity(options.buffer_size, wal_file),
             path: wal_file_path.as_ref().to_path_buf(),
+            offset: 0,
+            moved: false,
+            header: None,
+            legacy: false,
+            segment_size: options.segment_size,
+            compress_above: options.compress_above,
+            keyring: options.keyring.clone(),
+            key: None,
         })
     }
 
-    pub fn open<P: AsRef<Path>>(wal_file_path: P) -> Result<WalFile> {
-        let wal_file = OpenOptions::new()
+    /// Opens a segment and validates its header, the appends go after the
+    /// records once the segment has been iterated over and its end set. A
+    /// segment written before the header has none
+    pub fn open<P: AsRef<Path>>(wal_file_path: P, options: &WalOptions) -> Result<WalFile> {
+        let mut wal_file = OpenOptions::new()
             .read(true)
             .write(true)
-            .append(true)
-            .create(true)
             .open(&wal_file_path)?;
         wal_file.try_lock_exclusive()?;
+        let header = read_header(&mut wal_file, &wal_file_path)?;
+        let key = match header {
+            Some(ref header) => SegmentKey::new(options.keyring.as_ref(), header)?,
+            None => None,
+        };
         Ok(WalFile {
-            fd: BufWriter::new(wal_file),
+            fd: BufWriter::with_capacity(options.buffer_size, wal_file),
             path: wal_file_path.as_ref().to_path_buf(),
+            // known once replayed
+            offset: 0,
+            moved: true,
+            legacy: header.is_none(),
+            header: header,
+            segment_size: options.segment_size,
+            compress_above: options.compress_above,
+            keyring: options.keyring.clone(),
+            key: key,
         })
     }
 
-    pub fn insert_record(&mut self, r: Record) -> Result<()> {
-        self.write_len(serialized_size(&r)?)?;
-        self.fd.write_all(serialize(&r)?.as_slice())?;
+    /// Writes the header of a new segment, before any record. The records
+    /// are encrypted with the key of the header, if any
+    pub fn write_header(&mut self, header: SegmentHeader) -> Result<()> {
+        assert_eq!(self.offset, 0, "header written after the records");
+        self.key = SegmentKey::new(self.keyring.as_ref(), &header)?;
+        self.fd.write_all(&header.encode())?;
+        self.offset = header.size();
+        self.header = Some(header);
         Ok(())
     }
 
-    pub fn sync(&mut self) -> Result<()> {
+    pub fn header(&self) -> Option<&SegmentHeader> {
+        self.header.as_ref()
+    }
+
+    // offset of the first record
+    fn start(&self) -> u64 {
+        self.header.as_ref().map_or(0, |header| header.size())
+    }
+
+    /// Moves the segment to `path`, the directory is synced so that the
+    /// rename survives a crash
+    pub fn rename<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        fs::rename(&self.path, &path)?;
+        self.path = path.as_ref().to_path_buf();
+        let dir = self
+            .path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        sync_dir(dir)
+    }
+
+    /// Appends the record, returns its offset in the file
+    pub fn insert_record(&mut self, r: Record) -> Result<u64> {
+        let offset = self.offset;
+        let (word, bytes) = encode(&r, self.compress_above, self.key.as_ref(), offset)?;
+        self.write_len(word)?;
+        self.fd.write_all(&bytes)?;
+        self.offset += 8 + bytes.len() as u64;
+        Ok(offset)
+    }
+
+    /// Reads the record at `offset`, whose crc rolls on from `crc`
+    pub fn read_record_at(&mut self, offset: u64, crc: u32) -> Result<Record> {
+        // the record may still sit in the write buffer
         self.fd.flush()?;
+        self.moved = true;
+        let mut fd = self.fd.get_ref();
+        fd.seek(SeekFrom::Start(offset))?;
+        let (len, compressed) = split_len(fd.read_u64::<LittleEndian>()?);
+        let mut buffer = vec![0; len as usize];
+        fd.read_exact(&mut buffer)?;
+        let record = decode(&buffer, compressed, self.key.as_ref(), offset)
+            .map_err(|reason| self.corrupted(offset, &reason))?;
+        if !record.is_valid(crc) {
+            return Err(self.corrupted(offset, "crc mismatch"));
+        }
+        Ok(record)
+    }
+
+    /// Sets where the records end, the next one is appended there
+    pub fn set_end(&mut self, offset: u64) -> Result<()> {
+        self.fd.seek(SeekFrom::Start(offset))?;
+        self.offset = offset;
+        self.moved = false;
+        Ok(())
+    }
+
+    /// Drops everything from `offset` on, used to discard a torn record. The
+    /// space is preallocated again
+    pub fn truncate(&mut self, offset: u64) -> Result<()> {
+        self.fd.flush()?;
+        self.fd.get_ref().set_len(offset)?;
+        self.fd
+            .get_ref()
+            .allocate(cmp::max(offset, self.segment_size))?;
         self.fd.get_ref().sync_all()?;
+        self.set_end(offset)
+    }
+
+    fn corrupted(&self, offset: u64, reason: &str) -> Error {
+        Error::WalCorrupted(
+            self.path.to_string_lossy().into_owned(),
+            offset,
+            reason.to_owned(),
+        )
+    }
+
+    /// Hands the buffered records over to the file, for the readers of the
+    /// segment
+    pub fn flush(&mut self) -> Result<()> {
+        self.fd.flush()?;
+        Ok(())
+    }
+
+    pub fn sync(&mut self) -> Result<()> {
+        self.fd.flush()?;
+        let timer = WAL_FSYNC_HISTOGRAM.start_timer();
+        // the size is synced along with the data, the other metadata is not
+        // needed to read the records back
+        self.fd.get_ref().sync_data()?;
+        timer.observe_duration();
         Ok(())
     }
 
     pub fn size(&mut self) -> Result<u64> {
-        Ok(self.fd.seek(SeekFrom::Current(0))?)
+        Ok(self.offset)
     }
 
     pub fn check_cut(&mut self) -> Result<bool> {
         let size = self.size()?;
-        if size >= SEGMENT_SIZE {
+        if size >= self.segment_size {
             return Ok(true);
         }
         Ok(false)
@@ -82,96 +220,414 @@ impl WalFile {
     }
 
     fn write_len(&mut self, len: u64) -> Result<()> {
+        if self.moved {
+            let offset = self.offset;
+            self.set_end(offset)?;
+        }
         self.fd.write_u64::<LittleEndian>(len)?;
         Ok(())
     }
-}
 
-impl<'a> IntoIterator for &'a mut WalFile {
-    type Item = Record;
-    type IntoIter = WalFileIterator<'a>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        // create our iterator
+    /// Iterates over the records, `crc` is the one the previous segment
+    /// ended with, the `CrcType` record at the start must carry it over
+    pub fn iter(&mut self, crc: Option<u32>) -> WalFileIterator<&File> {
+        // the records may still sit in the write buffer
+        self.fd.flush().unwrap();
+        self.moved = true;
+        let path = self.path.clone();
+        let start = self.start();
+        let key = self.key.clone();
+        let legacy = self.legacy;
         let fd = self.fd.get_mut();
-        fd.seek(SeekFrom::Start(0)).unwrap();
+        let len = fd.metadata().unwrap().len();
+        fd.seek(SeekFrom::Start(start)).unwrap();
         WalFileIterator {
             fd: BufReader::new(fd),
+            path: path,
+            len: len,
+            offset: start,
+            crc: crc,
+            key: key,
+            legacy: legacy,
+            newest: true,
+            failed: false,
         }
     }
 }
 
-pub struct WalFileIterator<'a> {
-    fd: BufReader<&'a File>,
+impl<'a> IntoIterator for &'a mut WalFile {
+    type Item = Result<Record>;
+    type IntoIter = WalFileIterator<&'a File>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter(None)
+    }
+}
+
+/// Opens the segment for reading only, without locking it, and iterates over
+/// its records as `WalFile::iter` does. The key of an encrypted segment must
+/// be in the keyring. Returns the seq of the segment along with the iterator
+pub fn read_segment<P: AsRef<Path>>(
+    path: P,
+    crc: Option<u32>,
+    keyring: Option<&Arc<Keyring>>,
+) -> Result<(u64, WalFileIterator<File>)> {
+    let mut fd = File::open(&path)?;
+    let header = read_header(&mut fd, &path)?;
+    let len = fd.metadata()?.len();
+    let (seq, offset, key) = match header {
+        Some(ref header) => (header.seq, header.size(), SegmentKey::new(keyring, header)?),
+        None => (index_from_path(&path).0, 0, None),
+    };
+    let iter = WalFileIterator {
+        fd: BufReader::new(fd),
+        path: path.as_ref().to_path_buf(),
+        len: len,
+        offset: offset,
+        crc: crc,
+        key: key,
+        legacy: header.is_none(),
+        newest: false,
+        failed: false,
+    };
+    Ok((seq, iter))
+}
+
+/// Reads the header of the segment, without locking it. None if the segment
+/// was written before the header was introduced
+pub fn read_segment_header<P: AsRef<Path>>(path: P) -> Result<Option<SegmentHeader>> {
+    read_header(&mut File::open(&path)?, &path)
+}
+
+// leaves the file cursor at the first record
+fn read_header<P: AsRef<Path>>(fd: &mut File, path: P) -> Result<Option<SegmentHeader>> {
+    let invalid =
+        |reason: String| Error::WalHeader(path.as_ref().to_string_lossy().into_owned(), reason);
+    let len = fd.metadata()?.len();
+    let mut buf = vec![0; HEADER_PREFIX_SIZE];
+    let n = cmp::min(len, HEADER_PREFIX_SIZE as u64) as usize;
+    fd.read_exact(&mut buf[..n])
+        .map_err(|e| invalid(format!("unreadable header: {}", e)))?;
+    if SegmentHeader::is_legacy(&buf[..n], len) {
+        fd.seek(SeekFrom::Start(0))?;
+        return Ok(None);
+    }
+    if n < HEADER_PREFIX_SIZE {
+        return Err(invalid(format!("{} bytes, shorter than the header", n)));
+    }
+    let size = SegmentHeader::size_from_prefix(&buf).map_err(&invalid)?;
+    buf.resize(size as usize, 0);
+    fd.read_exact(&mut buf[HEADER_PREFIX_SIZE..])
+        .map_err(|e| invalid(format!("unreadable header: {}", e)))?;
+    SegmentHeader::decode(&buf).map(Some).map_err(invalid)
+}
+
+/// Reads the records of a segment in order, up to the zeroed space
+/// preallocated after them. A record which can't be read is returned as
+/// `WalTornWrite` if nothing but zeros follows it, since a crash while
+/// appending leaves a partial record at the end, or `WalCorrupted` otherwise.
+/// The crc of every record rolls on from the one before it, so a missing,
+/// reordered or swapped record breaks the chain as a bit flip does. The
+/// iteration stops after the first error.
+pub struct WalFileIterator<R> {
+    fd: BufReader<R>,
+    path: PathBuf,
+    // end of the records, the size of the file until the zeros are reached
+    len: u64,
+    offset: u64,
+    // crc of the last record read
+    crc: Option<u32>,
+    key: Option<SegmentKey>,
+    // the records have standalone crcs, which roll on from 0
+    legacy: bool,
+    // the segment appended last, which a crash may have cut short
+    newest: bool,
+    failed: bool,
+}
+
+enum ReadRecord {
+    // the record and its size in the file
+    Valid(Record, u64),
+    // why the record is broken, and whether it is the last one
+    Broken(String, bool),
+    // the preallocated space after the records
+    End,
 }
 
-impl<'a> WalFileIterator<'a> {
-    fn read_len(&mut self) -> Result<u64> {
-        let len = self.fd.read_u64::<LittleEndian>()?;
-        Ok(len)
+impl<R: Read> WalFileIterator<R> {
+    /// Offset of the record returned by the next call of `next`, the end of
+    /// the records once the iteration is over
+    pub fn offset(&self) -> u64 {
+        self.offset
     }
 
-    fn read_record(&mut self) -> Result<Record> {
-        let len = self.read_len()?;
+    /// Crc the record returned by the next call of `next` rolls on from
+    pub fn crc(&self) -> u32 {
+        if self.legacy {
+            return 0;
+        }
+        self.crc.unwrap_or(0)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Marks the segment as the one appended last
+    pub fn set_newest(&mut self, newest: bool) {
+        self.newest = newest;
+    }
+
+    fn read_record(&mut self) -> Result<ReadRecord> {
+        let remaining = self.len - self.offset;
+        if remaining < 8 {
+            let reason = format!("{} bytes left for the length", remaining);
+            return Ok(ReadRecord::Broken(reason, true));
+        }
+        let word = self.fd.read_u64::<LittleEndian>()?;
+        if word == 0 {
+            return Ok(ReadRecord::End);
+        }
+        let (len, compressed) = split_len(word);
+        if len > remaining - 8 {
+            // a crash while appending to the newest segment leaves a partial
+            // record after the length, whether the segment was preallocated
+            // or not. Within an older one the length was torn only if nothing
+            // was written after it
+            let reason = format!("length {} overruns the segment", len);
+            let torn = self.newest || self.zeroed_rest()?;
+            return Ok(ReadRecord::Broken(reason, torn));
+        }
         let mut buffer = vec![0; len as usize];
-        self.fd.read(&mut buffer)?;
-        let record: Record = deserialize(&buffer)?;
+        self.fd.read_exact(&mut buffer)?;
+        let offset = self.offset;
+        match self.decode(&buffer, compressed, offset) {
+            Ok(record) => {
+                self.crc = Some(record.crc);
+                Ok(ReadRecord::Valid(record, 8 + len))
+            }
+            Err(reason) => Ok(ReadRecord::Broken(reason, self.zeroed_rest()?)),
+        }
+    }
+
+    fn decode(
+        &self,
+        buffer: &[u8],
+        compressed: bool,
+        offset: u64,
+    ) -> ::std::result::Result<Record, String> {
+        let record = decode(buffer, compressed, self.key.as_ref(), offset)?;
+        if record.ty == RecordType::CrcType && !self.legacy {
+            match self.crc {
+                Some(crc) if crc != record.crc => {
+                    return Err(format!("crc {} carried over, want {}", record.crc, crc));
+                }
+                _ => (),
+            }
+        } else if !record.is_valid(self.crc()) {
+            return Err("crc mismatch".to_owned());
+        }
         Ok(record)
     }
+
+    // whether nothing but zeros follows the record just read
+    fn zeroed_rest(&mut self) -> Result<bool> {
+        let mut buffer = [0; 4096];
+        loop {
+            let n = self.fd.read(&mut buffer)?;
+            if n == 0 {
+                return Ok(true);
+            }
+            if buffer[..n].iter().any(|b| *b != 0) {
+                return Ok(false);
+            }
+        }
+    }
 }
 
-impl<'a> Iterator for WalFileIterator<'a> {
-    type Item = Record;
+impl<R: Read> Iterator for WalFileIterator<R> {
+    type Item = Result<Record>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.read_record() {
-            Ok(record) => {
+        if self.failed || self.offset == self.len {
+            return None;
+        }
+        let path = self.path.to_string_lossy().into_owned();
+        let err = match self.read_record() {
+            Ok(ReadRecord::Valid(record, size)) => {
                 debug!("read record {:?}", record);
-                Some(record)
+                self.offset += size;
+                return Some(Ok(record));
             }
-            Err(err) => {
-                warn!("read record from file error {}", err);
-                None
+            Ok(ReadRecord::End) => {
+                self.len = self.offset;
+                return None;
             }
-        }
+            Ok(ReadRecord::Broken(reason, true)) => Error::WalTornWrite(path, self.offset, reason),
+            Ok(ReadRecord::Broken(reason, false)) => Error::WalCorrupted(path, self.offset, reason),
+            Err(e) => e,
+        };
+        self.failed = true;
+        Some(Err(err))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bincode::serialize;
+
+    /// Records whose crcs roll on from 0
+    fn chained(data: Vec<Vec<u8>>) -> Vec<Record> {
+        let mut crc = 0;
+        data.into_iter()
+            .map(|data| {
+                let r = Record::new(RecordType::EntryType, data, crc);
+                crc = r.crc;
+                r
+            })
+            .collect()
+    }
 
     #[test]
     fn insert_test() {
         let wal_path = "./file_tests/wal/test.wal";
-        let mut wal_file = WalFile::create(wal_path).unwrap();
+        let mut wal_file = WalFile::create(wal_path, &WalOptions::default()).unwrap();
         wal_file.fd.get_ref().set_len(0).unwrap();
-        let r = Record::new(RecordType::EntryType, vec![1, 2, 3, 4, 5]);
-        wal_file.insert_record(r.clone()).unwrap();
-        let r = Record::new(RecordType::EntryType, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0]);
-        wal_file.insert_record(r.clone()).unwrap();
-        let r = Record::new(
-            RecordType::EntryType,
+        wal_file.offset = 0;
+        let records = chained(vec![
+            vec![1, 2, 3, 4, 5],
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0],
             vec![
                 1, 2, 3, 4, 5, 6, 7, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0,
             ],
-        );
-        wal_file.insert_record(r.clone()).unwrap();
+        ]);
+        for r in &records {
+            wal_file.insert_record(r.clone()).unwrap();
+        }
         wal_file.sync().unwrap();
-        let mut iter = wal_file.into_iter();
-        println!("{:?}", iter.next().unwrap());
-        println!("{:?}", iter.next().unwrap());
-        println!("{:?}", iter.next().unwrap());
-        // let rr = wal_file.into_iter().next().unwrap();
-        // assert_eq!(r, rr);
-        // let r = Record::new(RecordType::EntryType, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0]);
-        // wal_file.insert_record(r.clone()).unwrap();
-        // wal_file.sync().unwrap();
-        // wal_file.into_iter().next().unwrap();
-        // let rr = wal_file.into_iter().next().unwrap();
-        // assert_eq!(r, rr);
+        let read: Vec<Record> = wal_file.into_iter().map(|r| r.unwrap()).collect();
+        assert_eq!(read, records);
     }
 
     #[test]
     fn iter_test() {}
+
+    #[test]
+    fn crc_chain_test() {
+        let wal_path = "./file_tests/crc_chain.wal";
+        let mut wal_file = WalFile::create(wal_path, &WalOptions::default()).unwrap();
+        let crc = Record::new(RecordType::CrcType, vec![], 7);
+        let r1 = Record::new(RecordType::EntryType, vec![1], crc.crc);
+        let r2 = Record::new(RecordType::EntryType, vec![2], r1.crc);
+        wal_file.insert_record(crc).unwrap();
+        // r2 misses r1
+        let o2 = wal_file.insert_record(r2.clone()).unwrap();
+        wal_file.insert_record(r1).unwrap();
+        {
+            let mut iter = wal_file.iter(Some(7));
+            iter.next().unwrap().unwrap();
+            match iter.next() {
+                Some(Err(Error::WalCorrupted(_, offset, _))) => assert_eq!(offset, o2),
+                _ => panic!("expected broken chain"),
+            }
+        }
+        // the previous segment ended with another crc
+        match wal_file.iter(Some(8)).next() {
+            Some(Err(Error::WalCorrupted(_, 0, _))) => (),
+            _ => panic!("expected broken chain"),
+        }
+        ::std::fs::remove_file(wal_path).unwrap();
+    }
+
+    #[test]
+    fn broken_record_test() {
+        let wal_path = "./file_tests/broken.wal";
+        let mut wal_file = WalFile::create(wal_path, &WalOptions::default()).unwrap();
+        let r = Record::new(RecordType::EntryType, vec![1, 2, 3], 0);
+        wal_file.insert_record(r.clone()).unwrap();
+        let mut broken = Record::new(RecordType::EntryType, vec![1, 2, 3], r.crc);
+        broken.crc += 1;
+        let o2 = wal_file.insert_record(broken.clone()).unwrap();
+        wal_file.insert_record(broken).unwrap();
+        {
+            let mut iter = wal_file.into_iter();
+            assert_eq!(iter.next().unwrap().unwrap(), r);
+            match iter.next() {
+                Some(Err(Error::WalCorrupted(_, offset, _))) => assert_eq!(offset, o2),
+                _ => panic!("expected corruption"),
+            }
+            assert!(iter.next().is_none());
+        }
+        // a partial record at the end is a torn write
+        wal_file.truncate(o2).unwrap();
+        wal_file.write_len(100).unwrap();
+        {
+            let mut iter = wal_file.into_iter();
+            iter.next().unwrap().unwrap();
+            match iter.next() {
+                Some(Err(Error::WalTornWrite(_, offset, _))) => assert_eq!(offset, o2),
+                _ => panic!("expected torn write"),
+            }
+        }
+        // a flipped bit in a length followed by records is a corruption
+        wal_file.truncate(o2).unwrap();
+        wal_file.write_len(1 << 40).unwrap();
+        wal_file.fd.write_all(&[1; 16]).unwrap();
+        let mut iter = wal_file.into_iter();
+        iter.next().unwrap().unwrap();
+        match iter.next() {
+            Some(Err(Error::WalCorrupted(_, offset, _))) => assert_eq!(offset, o2),
+            _ => panic!("expected corruption"),
+        }
+        ::std::fs::remove_file(wal_path).unwrap();
+    }
+
+    #[test]
+    fn read_record_at_test() {
+        let wal_path = "./file_tests/wal/read_at.wal";
+        let mut wal_file = WalFile::create(wal_path, &WalOptions::default()).unwrap();
+        let r1 = Record::new(RecordType::EntryType, vec![1, 2, 3], 0);
+        let r2 = Record::new(RecordType::StateType, vec![4, 5, 6, 7], r1.crc);
+        let o1 = wal_file.insert_record(r1.clone()).unwrap();
+        let o2 = wal_file.insert_record(r2.clone()).unwrap();
+        assert_eq!(o1, 0);
+        assert_eq!(wal_file.read_record_at(o2, r1.crc).unwrap(), r2);
+        assert_eq!(wal_file.read_record_at(o1, 0).unwrap(), r1);
+        assert!(wal_file.read_record_at(o2, 0).is_err());
+        let mut iter = wal_file.into_iter();
+        assert_eq!(iter.offset(), o1);
+        iter.next().unwrap().unwrap();
+        assert_eq!(iter.offset(), o2);
+        assert_eq!(iter.crc(), r1.crc);
+        ::std::fs::remove_file(wal_path).unwrap();
+    }
+
+    #[test]
+    fn compression_test() {
+        let wal_path = "./file_tests/wal/compressed.wal";
+        let options = WalOptions {
+            compress_above: Some(64),
+            ..WalOptions::default()
+        };
+        let mut wal_file = WalFile::create(wal_path, &options).unwrap();
+        let records = chained(vec![vec![7; 4096], vec![1, 2, 3], vec![8; 4096]]);
+        let mut offsets = vec![];
+        for r in &records {
+            offsets.push(wal_file.insert_record(r.clone()).unwrap());
+        }
+        // only the large records are compressed
+        assert!(offsets[1] < 1024);
+        assert_eq!(
+            offsets[2] - offsets[1],
+            8 + serialize(&records[1]).unwrap().len() as u64
+        );
+        assert_eq!(
+            wal_file.read_record_at(offsets[2], records[1].crc).unwrap(),
+            records[2]
+        );
+        let read: Vec<Record> = wal_file.into_iter().map(|r| r.unwrap()).collect();
+        assert_eq!(read, records);
+        ::std::fs::remove_file(wal_path).unwrap();
+    }
 }
@@ -0,0 +1,295 @@
+use errors::*;
+use futures::sync::mpsc::UnboundedSender;
+use kv::Store;
+use proto::mumpb::{Event, WatchRequest, WatchResponse};
+use std::cell::Cell;
+use std::ops::Bound;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::time::{Duration, Instant};
+
+// how often the watchers asking for it are told the store revision, the
+// idle ones are sent an empty response as often to find the cancelled ones
+const PROGRESS_INTERVAL_SECS: u64 = 60;
+
+pub type WatchSender = UnboundedSender<WatchResponse>;
+
+/// Picked by the server, to drop the watcher once its stream is closed
+pub type WatchId = u64;
+
+static NEXT_ID: AtomicUsize = ATOMIC_USIZE_INIT;
+
+pub fn next_id() -> WatchId {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed) as WatchId
+}
+
+struct Watcher {
+    id: WatchId,
+    key: Vec<u8>,
+    end_key: Vec<u8>,
+    progress_notify: bool,
+    tx: WatchSender,
+    // sent events since the last progress round
+    active: Cell<bool>,
+}
+
+impl Watcher {
+    fn range(&self) -> (Bound<&[u8]>, Bound<&[u8]>) {
+        let start = Bound::Included(self.key.as_slice());
+        match self.end_key.as_slice() {
+            [] => (start, start),
+            [0] => (start, Bound::Unbounded),
+            end => (start, Bound::Excluded(end)),
+        }
+    }
+
+    fn contains(&self, key: &[u8]) -> bool {
+        match self.end_key.as_slice() {
+            [] => key == self.key.as_slice(),
+            [0] => key >= self.key.as_slice(),
+            end => key >= self.key.as_slice() && key < end,
+        }
+    }
+
+    /// Returns false if the client has gone away
+    fn send(&self, events: Vec<Event>, revision: u64) -> bool {
+        let mut resp = WatchResponse::new();
+        resp.mut_header().set_revision(revision);
+        resp.set_events(events.into());
+        self.tx.unbounded_send(resp).is_ok()
+    }
+
+    /// Sends the events since the start revision, returns false if the
+    /// watcher is done with
+    fn catch_up(&self, store: &Store, start: u64) -> bool {
+        let revision = store.revision();
+        match store.events(self.range(), start) {
+            Ok(events) => events.is_empty() || self.send(events, revision),
+            Err(Error::Compacted(_, compacted)) => {
+                let mut resp = WatchResponse::new();
+                resp.mut_header().set_revision(revision);
+                resp.set_compact_revision(compacted);
+                // dropping the sender ends the stream
+                let _ = self.tx.unbounded_send(resp);
+                false
+            }
+            Err(e) => {
+                error!("failed to replay events since {}: {}", start, e);
+                false
+            }
+        }
+    }
+}
+
+/// Watchers of the key changes, fed by the raft loop once the writes are
+/// applied to the store
+pub struct WatchHub {
+    watchers: Vec<Watcher>,
+    last_progress: Instant,
+}
+
+impl WatchHub {
+    pub fn new() -> WatchHub {
+        WatchHub {
+            watchers: vec![],
+            last_progress: Instant::now(),
+        }
+    }
+
+    /// Registers a watcher, after sending it the events since the start
+    /// revision
+    pub fn watch(&mut self, id: WatchId, mut req: WatchRequest, tx: WatchSender, store: &Store) {
+        let watcher = Watcher {
+            id: id,
+            key: req.take_key(),
+            end_key: req.take_end_key(),
+            progress_notify: req.get_progress_notify(),
+            tx: tx,
+            active: Cell::new(false),
+        };
+        let revision = store.revision();
+        let mut created = WatchResponse::new();
+        created.mut_header().set_revision(revision);
+        created.set_created(true);
+        if watcher.tx.unbounded_send(created).is_err() {
+            return;
+        }
+
+        let start = req.get_start_revision();
+        if start > 0 && start <= revision && !watcher.catch_up(store, start) {
+            return;
+        }
+        self.watchers.push(watcher);
+    }
+
+    /// Drops the watcher once its stream is closed
+    pub fn unwatch(&mut self, id: WatchId) {
+        self.watchers.retain(|w| w.id != id);
+    }
+
+    /// Sends the events the installed snapshot skipped, the watchers are
+    /// told the store is compacted if it has not kept them
+    pub fn on_snapshot(&mut self, start: u64, store: &Store) {
+        self.watchers.retain(|w| w.catch_up(store, start));
+    }
+
+    /// Sends the events applied at `revision` to the watchers of their keys
+    pub fn notify(&mut self, events: &[Event], revision: u64) {
+        if events.is_empty() {
+            return;
+        }
+        self.watchers.retain(|w| {
+            let events: Vec<Event> = events
+                .iter()
+                .filter(|e| w.contains(e.get_kv().get_key()))
+                .cloned()
+                .collect();
+            if events.is_empty() {
+                return true;
+            }
+            w.active.set(true);
+            w.send(events, revision)
+        });
+    }
+
+    /// Tells the watchers which asked for it the store revision, once in a
+    /// while. The idle ones are told too, a cancelled stream is only closed
+    /// by a failed write.
+    pub fn progress(&mut self, now: Instant, revision: u64) {
+        if now < self.last_progress + Duration::from_secs(PROGRESS_INTERVAL_SECS) {
+            return;
+        }
+        self.last_progress = now;
+        self.watchers.retain(|w| {
+            let idle = !w.active.replace(false);
+            !(w.progress_notify || idle) || w.send(vec![], revision)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::sync::mpsc::unbounded;
+    use futures::{Future, Stream};
+    use proto::mumpb::EventType;
+
+    fn event(key: &[u8], revision: u64) -> Event {
+        let mut e = Event::new();
+        e.set_field_type(EventType::Delete);
+        e.mut_kv().set_key(key.to_vec());
+        e.mut_kv().set_mod_revision(revision);
+        e
+    }
+
+    fn watch_req(key: &[u8], end_key: &[u8], start: u64) -> WatchRequest {
+        let mut req = WatchRequest::new();
+        req.set_key(key.to_vec());
+        req.set_end_key(end_key.to_vec());
+        req.set_start_revision(start);
+        req
+    }
+
+    #[test]
+    fn notify_test() {
+        let mut hub = WatchHub::new();
+        let store = Store::new();
+        let (tx1, rx1) = unbounded();
+        let (tx2, rx2) = unbounded();
+        hub.watch(1, watch_req(b"a", b"", 0), tx1, &store);
+        hub.watch(2, watch_req(b"b", b"\0", 0), tx2, &store);
+
+        hub.notify(&[event(b"a", 1)], 1);
+        hub.notify(&[event(b"b", 2), event(b"c", 2)], 2);
+        drop(hub);
+
+        let resps = rx1.collect().wait().unwrap();
+        assert!(resps[0].get_created());
+        assert_eq!(resps.len(), 2);
+        assert_eq!(resps[1].get_events()[0].get_kv().get_key(), b"a");
+        let resps = rx2.collect().wait().unwrap();
+        assert_eq!(resps.len(), 2);
+        assert_eq!(resps[1].get_events().len(), 2);
+    }
+
+    #[test]
+    fn replay_test() {
+        let mut hub = WatchHub::new();
+        let mut store = Store::new();
+        store.set(b"a".to_vec(), b"1".to_vec(), 1);
+        store.set(b"a".to_vec(), b"2".to_vec(), 2);
+        store.set(b"a".to_vec(), b"3".to_vec(), 3);
+
+        let (tx, rx) = unbounded();
+        hub.watch(0, watch_req(b"a", b"", 2), tx, &store);
+        store.compact(2).unwrap();
+        let (tx, compacted_rx) = unbounded();
+        hub.watch(0, watch_req(b"a", b"", 1), tx, &store);
+        drop(hub);
+
+        let resps = rx.collect().wait().unwrap();
+        assert_eq!(resps[1].get_events().len(), 2);
+        assert_eq!(resps[1].get_header().get_revision(), 3);
+        let resps = compacted_rx.collect().wait().unwrap();
+        assert_eq!(resps.len(), 2);
+        assert_eq!(resps[1].get_compact_revision(), 2);
+    }
+
+    #[test]
+    fn cancel_test() {
+        let mut hub = WatchHub::new();
+        let (tx, rx) = unbounded();
+        hub.watch(0, watch_req(b"a", b"", 0), tx, &Store::new());
+        drop(rx);
+        hub.notify(&[event(b"a", 1)], 1);
+        assert!(hub.watchers.is_empty());
+    }
+
+    #[test]
+    fn unwatch_test() {
+        let mut hub = WatchHub::new();
+        let store = Store::new();
+        let (tx1, _rx1) = unbounded();
+        let (tx2, rx2) = unbounded();
+        hub.watch(1, watch_req(b"a", b"", 0), tx1, &store);
+        hub.watch(2, watch_req(b"b", b"", 0), tx2, &store);
+        hub.unwatch(1);
+        assert_eq!(hub.watchers.len(), 1);
+
+        // an idle watcher is sent an empty response, the closed one is dropped
+        drop(rx2);
+        let now = hub.last_progress + Duration::from_secs(PROGRESS_INTERVAL_SECS);
+        hub.progress(now, 1);
+        assert!(hub.watchers.is_empty());
+    }
+
+    #[test]
+    fn snapshot_test() {
+        let mut hub = WatchHub::new();
+        let mut store = Store::new();
+        let (tx1, rx1) = unbounded();
+        let (tx2, rx2) = unbounded();
+        hub.watch(1, watch_req(b"a", b"", 0), tx1, &store);
+        hub.watch(2, watch_req(b"b", b"", 0), tx2, &store);
+
+        // as if snapshots were installed at the revision 3, then at 4 with
+        // the store compacted
+        store.set(b"a".to_vec(), b"1".to_vec(), 1);
+        store.set(b"b".to_vec(), b"1".to_vec(), 2);
+        store.set(b"b".to_vec(), b"2".to_vec(), 3);
+        store.compact(1).unwrap();
+        hub.on_snapshot(2, &store);
+        assert_eq!(hub.watchers.len(), 2);
+        store.set(b"a".to_vec(), b"2".to_vec(), 4);
+        store.compact(3).unwrap();
+        hub.on_snapshot(3, &store);
+        assert!(hub.watchers.is_empty());
+
+        let resps = rx1.collect().wait().unwrap();
+        assert_eq!(resps.len(), 2);
+        assert_eq!(resps[1].get_compact_revision(), 3);
+        let resps = rx2.collect().wait().unwrap();
+        assert_eq!(resps.len(), 3);
+        assert_eq!(resps[1].get_events().len(), 2);
+        assert_eq!(resps[2].get_compact_revision(), 3);
+    }
+}